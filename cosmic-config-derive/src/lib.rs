@@ -2,7 +2,7 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{self};
 
-#[proc_macro_derive(CosmicConfigEntry, attributes(version, id))]
+#[proc_macro_derive(CosmicConfigEntry, attributes(version, id, config))]
 pub fn cosmic_config_entry_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
@@ -12,6 +12,58 @@ pub fn cosmic_config_entry_derive(input: TokenStream) -> TokenStream {
     impl_cosmic_config_entry_macro(&ast)
 }
 
+/// Options set on a field with `#[config(...)]`
+#[derive(Default)]
+struct FieldAttrs {
+    /// Exclude the field from the stored config
+    skip: bool,
+    /// Key to store the field as, instead of the field name
+    rename: Option<String>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut field_attrs = Self::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("config"))
+        {
+            match attr.parse_meta() {
+                Ok(syn::Meta::List(list)) => {
+                    for nested in list.nested.iter() {
+                        match nested {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path))
+                                if path.is_ident("skip") =>
+                            {
+                                field_attrs.skip = true;
+                            }
+                            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                                path,
+                                lit: syn::Lit::Str(lit_str),
+                                ..
+                            })) if path.is_ident("rename") => {
+                                field_attrs.rename = Some(lit_str.value());
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    nested,
+                                    "unsupported config attribute, expected `skip` or `rename = \"...\"`",
+                                ))
+                            }
+                        }
+                    }
+                }
+                Ok(_) => {
+                    return Err(syn::Error::new_spanned(attr, "expected #[config(...)]"));
+                }
+                Err(why) => return Err(why),
+            }
+        }
+        Ok(field_attrs)
+    }
+}
+
 fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
     let attributes = &ast.attrs;
     let version = attributes
@@ -42,34 +94,53 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
         _ => unimplemented!("Only structs are supported"),
     };
 
-    let write_each_config_field = fields.iter().map(|field| {
+    // Skip fields that are not stored in the config, and get the key of the rest
+    let mut stored_fields = Vec::with_capacity(fields.len());
+    for field in fields {
+        let field_attrs = match FieldAttrs::parse(field) {
+            Ok(field_attrs) => field_attrs,
+            Err(why) => return why.to_compile_error().into(),
+        };
+        if field_attrs.skip {
+            continue;
+        }
+        let key = field_attrs
+            .rename
+            .unwrap_or_else(|| field.ident.as_ref().unwrap().to_string());
+        stored_fields.push((field, key));
+    }
+    let fields = stored_fields;
+
+    let keys = fields.iter().map(|(_, key)| key);
+
+    let write_each_config_field = fields.iter().map(|(field, key)| {
         let field_name = &field.ident;
         quote! {
-            cosmic_config::ConfigSet::set(&tx, stringify!(#field_name), &self.#field_name)?;
+            cosmic_config::ConfigSet::set(&tx, #key, &self.#field_name)?;
         }
     });
 
-    let get_each_config_field = fields.iter().map(|field| {
+    let get_each_config_field = fields.iter().map(|(field, key)| {
         let field_name = &field.ident;
         let field_type = &field.ty;
         quote! {
-            match cosmic_config::ConfigGet::get::<#field_type>(config, stringify!(#field_name)) {
+            match cosmic_config::ConfigGet::get::<#field_type>(config, #key) {
                 Ok(#field_name) => default.#field_name = #field_name,
                 Err(why) if matches!(why, cosmic_config::Error::NoConfigDirectory) => (),
-                Err(e) => errors.push(e),
+                Err(e) => errors.push((#key, e)),
             }
         }
     });
 
-    let update_each_config_field = fields.iter().map(|field| {
+    let update_each_config_field = fields.iter().map(|(field, key)| {
         let field_name = &field.ident;
         let field_type = &field.ty;
         quote! {
-            stringify!(#field_name) => {
-                match cosmic_config::ConfigGet::get::<#field_type>(config, stringify!(#field_name)) {
+            #key => {
+                match cosmic_config::ConfigGet::get::<#field_type>(config, #key) {
                     Ok(value) => {
                         if self.#field_name != value {
-                            keys.push(stringify!(#field_name));
+                            keys.push(#key);
                         }
                         self.#field_name = value;
                     },
@@ -81,7 +152,7 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
         }
     });
 
-    let setters = fields.iter().filter_map(|field| {
+    let setters = fields.iter().filter_map(|(field, key)| {
         let field_name = &field.ident.as_ref()?;
         let field_type = &field.ty;
         let setter_name = quote::format_ident!("set_{}", field_name);
@@ -93,7 +164,7 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
             pub fn #setter_name(&mut self, config: &cosmic_config::Config, value: #field_type) -> Result<bool, cosmic_config::Error> {
                 if self.#field_name != value {
                     self.#field_name = value;
-                    cosmic_config::ConfigSet::set(config, stringify!(#field_name), &self.#field_name)?;
+                    cosmic_config::ConfigSet::set(config, #key, &self.#field_name)?;
                     Ok(true)
                 } else {
                     Ok(false)
@@ -105,6 +176,7 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
     let gen = quote! {
         impl CosmicConfigEntry for #name {
             const VERSION: u64 = #version;
+            const KEYS: &'static [&'static str] = &[#(#keys),*];
 
             fn write_entry(&self, config: &cosmic_config::Config) -> Result<(), cosmic_config::Error> {
                 let tx = config.transaction();
@@ -113,16 +185,22 @@ fn impl_cosmic_config_entry_macro(ast: &syn::DeriveInput) -> TokenStream {
             }
 
             fn get_entry(config: &cosmic_config::Config) -> Result<Self, (Vec<cosmic_config::Error>, Self)> {
+                let (entry, errors) = Self::load_or_report(config);
+
+                if errors.is_empty() {
+                    Ok(entry)
+                } else {
+                    Err((errors.into_iter().map(|(_, e)| e).collect(), entry))
+                }
+            }
+
+            fn load_or_report(config: &cosmic_config::Config) -> (Self, Vec<(&'static str, cosmic_config::Error)>) {
                 let mut default = Self::default();
                 let mut errors = Vec::new();
 
                 #(#get_each_config_field)*
 
-                if errors.is_empty() {
-                    Ok(default)
-                } else {
-                    Err((errors, default))
-                }
+                (default, errors)
             }
 
             fn update_keys<T: AsRef<str>>(&mut self, config: &cosmic_config::Config, changed_keys: &[T]) -> (Vec<cosmic_config::Error>, Vec<&'static str>){
@@ -2,10 +2,33 @@
 
 use calloop::channel;
 
-use crate::{Config, Error};
+use crate::{Change, Config, Error};
 
+/// Watches a config for changes on a calloop event loop.
+///
+/// As with [`Config::watch`], the callback receives the config and the keys which changed,
+/// along with the shared data of the event loop. The watch lasts until the source is removed
+/// from the loop with the returned token.
+pub fn watch<Data, F>(
+    config: &Config,
+    handle: &calloop::LoopHandle<'_, Data>,
+    mut callback: F,
+) -> Result<calloop::RegistrationToken, Error>
+where
+    F: FnMut(&Config, &[Change], &mut Data) + 'static,
+{
+    let source = ConfigWatchSource::new(config)?;
+
+    handle
+        .insert_source(source, move |(config, keys), (), data| {
+            callback(&config, &keys, data);
+        })
+        .map_err(|err| Error::Calloop(err.error))
+}
+
+/// An event source which yields the config and its changed keys whenever it changes.
 pub struct ConfigWatchSource {
-    channel: channel::Channel<(Config, Vec<String>)>,
+    channel: channel::Channel<(Config, Vec<Change>)>,
     _watcher: notify::RecommendedWatcher,
 }
 
@@ -20,7 +43,7 @@ impl ConfigWatchSource {
 }
 
 impl calloop::EventSource for ConfigWatchSource {
-    type Event = (Config, Vec<String>);
+    type Event = (Config, Vec<Change>);
     type Metadata = ();
     type Ret = ();
     type Error = calloop::channel::ChannelError;
@@ -8,6 +8,36 @@ use iced_futures::{
     stream, Subscription,
 };
 
+/// Object path of the signal which announces that keys of a config have changed.
+pub const CHANGED_PATH: &str = "/com/system76/CosmicConfig";
+
+/// Interface of the signal which announces that keys of a config have changed.
+pub const CHANGED_INTERFACE: &str = "com.system76.CosmicConfig";
+
+/// Member name of the signal which announces that keys of a config have changed.
+pub const CHANGED_MEMBER: &str = "Changed";
+
+/// Broadcasts on the session bus that `keys` of the config `name` at `version` have changed.
+///
+/// Processes listening with [`changed_subscription`] reload these keys, without relying on
+/// a filesystem watch, which may be unreliable on network filesystems.
+pub async fn emit_changed(
+    connection: &zbus::Connection,
+    name: &str,
+    version: u64,
+    keys: &[&str],
+) -> zbus::Result<()> {
+    connection
+        .emit_signal(
+            None::<zbus::names::BusName<'_>>,
+            CHANGED_PATH,
+            CHANGED_INTERFACE,
+            CHANGED_MEMBER,
+            &(name, version, keys),
+        )
+        .await
+}
+
 pub async fn settings_daemon_proxy() -> zbus::Result<CosmicSettingsDaemonProxy<'static>> {
     let conn = zbus::Connection::session().await?;
     CosmicSettingsDaemonProxy::new(&conn).await
@@ -214,3 +244,91 @@ fn watcher_stream<T: CosmicConfigEntry + Send + Sync + Default + 'static + Clone
         }
     })
 }
+
+/// Listens on the session bus for changes broadcast by [`emit_changed`].
+///
+/// This complements the filesystem watch of [`crate::Config::watch`] for environments where
+/// it is unreliable.
+pub fn changed_subscription<T: CosmicConfigEntry + Send + Sync + Default + 'static + Clone>(
+    config_id: &'static str,
+    is_state: bool,
+) -> iced_futures::Subscription<Update<T>> {
+    let id = (std::any::TypeId::of::<T>(), config_id, is_state);
+    Subscription::run_with_id(id, changed_stream(config_id, is_state))
+}
+
+fn changed_stream<T: CosmicConfigEntry + Send + Sync + Default + 'static + Clone>(
+    config_id: &'static str,
+    is_state: bool,
+) -> impl Stream<Item = Update<T>> {
+    stream::channel(5, move |mut tx| async move {
+        let version = T::VERSION;
+
+        let Ok(cosmic_config) = (if is_state {
+            crate::Config::new_state(config_id, version)
+        } else {
+            crate::Config::new(config_id, version)
+        }) else {
+            pending::<()>().await;
+            unreachable!();
+        };
+
+        let messages = async {
+            let connection = zbus::Connection::session().await?;
+            let rule = zbus::MatchRule::builder()
+                .msg_type(zbus::message::Type::Signal)
+                .path(CHANGED_PATH)?
+                .interface(CHANGED_INTERFACE)?
+                .member(CHANGED_MEMBER)?
+                .build();
+            zbus::MessageStream::for_match_rule(rule, &connection, None).await
+        };
+
+        let mut messages = match messages.await {
+            Ok(messages) => messages,
+            Err(why) => {
+                tracing::error!(?why, "Failed to listen for changes to {config_id}");
+                pending::<()>().await;
+                unreachable!();
+            }
+        };
+
+        let mut config = match T::get_entry(&cosmic_config) {
+            Ok(config) => config,
+            Err((_errors, default)) => default,
+        };
+
+        while let Some(message) = messages.next().await {
+            let Ok(message) = message else {
+                continue;
+            };
+
+            let Ok((name, changed_version, keys)) =
+                message.body().deserialize::<(String, u64, Vec<String>)>()
+            else {
+                continue;
+            };
+
+            if name != config_id || changed_version != version {
+                continue;
+            }
+
+            let (errors, keys) = config.update_keys(&cosmic_config, &keys);
+            if !keys.is_empty() {
+                if let Err(err) = tx
+                    .send(Update {
+                        errors,
+                        keys,
+                        config: config.clone(),
+                    })
+                    .await
+                {
+                    tracing::error!("Failed to send config update: {err}");
+                }
+            }
+        }
+
+        pending::<()>().await;
+        unreachable!();
+    })
+}
@@ -35,7 +35,11 @@ pub enum Error {
     Notify(notify::Error),
     Ron(ron::Error),
     RonSpanned(ron::error::SpannedError),
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
     GetKey(String, std::io::Error),
+    #[cfg(feature = "calloop")]
+    Calloop(::calloop::Error),
 }
 
 impl fmt::Display for Error {
@@ -48,13 +52,28 @@ impl fmt::Display for Error {
             Self::Notify(err) => err.fmt(f),
             Self::Ron(err) => err.fmt(f),
             Self::RonSpanned(err) => err.fmt(f),
+            #[cfg(feature = "json")]
+            Self::Json(err) => err.fmt(f),
             Self::GetKey(key, err) => write!(f, "failed to get key '{}': {}", key, err),
+            #[cfg(feature = "calloop")]
+            Self::Calloop(err) => err.fmt(f),
         }
     }
 }
 
 impl std::error::Error for Error {}
 
+impl Error {
+    /// Whether the error is caused by the key or config directory not existing
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::NoConfigDirectory => true,
+            Self::GetKey(_, err) => err.kind() == std::io::ErrorKind::NotFound,
+            _ => false,
+        }
+    }
+}
+
 impl From<atomicwrites::Error<std::io::Error>> for Error {
     fn from(f: atomicwrites::Error<std::io::Error>) -> Self {
         Self::AtomicWrites(f)
@@ -85,20 +104,120 @@ impl From<ron::error::SpannedError> for Error {
     }
 }
 
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for Error {
+    fn from(f: serde_json::Error) -> Self {
+        Self::Json(f)
+    }
+}
+
 pub trait ConfigGet {
     /// Get a configuration value
     fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error>;
+
+    /// Get a configuration value, or `default` if it has not been set
+    ///
+    /// Parse and IO errors other than the key not being found are still returned.
+    fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> Result<T, Error> {
+        match self.get(key) {
+            Ok(value) => Ok(value),
+            Err(why) if why.is_not_found() => Ok(default),
+            Err(why) => Err(why),
+        }
+    }
+
+    /// Get a configuration value, or [`Default::default`] if it has not been set
+    ///
+    /// Parse and IO errors other than the key not being found are still returned.
+    fn get_or_default<T: DeserializeOwned + Default>(&self, key: &str) -> Result<T, Error> {
+        match self.get(key) {
+            Ok(value) => Ok(value),
+            Err(why) if why.is_not_found() => Ok(T::default()),
+            Err(why) => Err(why),
+        }
+    }
 }
 
 pub trait ConfigSet {
     /// Set a configuration value
     fn set<T: Serialize>(&self, key: &str, value: T) -> Result<(), Error>;
+
+    /// Remove a configuration value, falling back to the system default
+    fn remove(&self, key: &str) -> Result<(), Error>;
+}
+
+/// A change to a key, reported by [`Config::watch`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Change {
+    /// The key was written
+    Set(String),
+    /// The key was removed
+    Removed(String),
+}
+
+impl Change {
+    /// Get the key that changed
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Set(key) | Self::Removed(key) => key,
+        }
+    }
+}
+
+impl AsRef<str> for Change {
+    fn as_ref(&self) -> &str {
+        self.key()
+    }
+}
+
+/// Serialization format used to store config values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Format {
+    #[default]
+    Ron,
+    #[cfg(feature = "json")]
+    Json,
+}
+
+impl Format {
+    fn serialize<T: Serialize>(
+        self,
+        value: &T,
+        ron_pretty: Option<&ron::ser::PrettyConfig>,
+    ) -> Result<String, Error> {
+        match self {
+            Self::Ron => match ron_pretty {
+                Some(pretty) => Ok(ron::ser::to_string_pretty(value, pretty.clone())?),
+                None => Ok(ron::ser::to_string(value)?),
+            },
+            #[cfg(feature = "json")]
+            Self::Json => Ok(serde_json::to_string_pretty(value)?),
+        }
+    }
+
+    fn deserialize<T: DeserializeOwned>(self, data: &str) -> Result<T, Error> {
+        match self {
+            Self::Ron => Ok(ron::from_str(data)?),
+            #[cfg(feature = "json")]
+            Self::Json => Ok(serde_json::from_str(data)?),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct Config {
     system_path: Option<PathBuf>,
     user_path: Option<PathBuf>,
+    format: Format,
+    /// Formatting of RON values when written, where `None` writes them compactly
+    ron_pretty: Option<ron::ser::PrettyConfig>,
+    version: u64,
+}
+
+/// Check if the current process is running inside a Flatpak sandbox
+#[cfg(unix)]
+fn is_flatpak() -> bool {
+    std::env::var_os("FLATPAK_ID").is_some() || Path::new("/.flatpak-info").exists()
 }
 
 /// Check that the name is relative and doesn't contain . or ..
@@ -114,6 +233,20 @@ fn sanitize_name(name: &str) -> Result<&Path, Error> {
     }
 }
 
+/// Check that the key is a single, non-empty path component
+fn sanitize_key(key: &str) -> Result<&Path, Error> {
+    let path = Path::new(key);
+    let mut components = path.components();
+    if matches!(components.next(), Some(std::path::Component::Normal(_)))
+        && components.next().is_none()
+        && !key.contains(std::path::is_separator)
+    {
+        Ok(path)
+    } else {
+        Err(Error::InvalidName(key.to_owned()))
+    }
+}
+
 impl Config {
     /// Get the config for the libcosmic toolkit
     pub fn libcosmic() -> Result<Self, Error> {
@@ -136,12 +269,16 @@ impl Config {
         Ok(Self {
             system_path,
             user_path: None,
+            format: Format::default(),
+            ron_pretty: Some(ron::ser::PrettyConfig::new()),
+            version,
         })
     }
 
     /// Get config for the given application name and config version
+    ///
+    /// Sandboxed applications should use [`Config::flatpak`] to access the host configuration.
     // Use folder at XDG config/name for config storage, return Config if successful
-    //TODO: fallbacks for flatpak (HOST_XDG_CONFIG_HOME, xdg-desktop settings proxy)
     pub fn new(name: &str, version: u64) -> Result<Self, Error> {
         // Look for [name]/v[version]
         let path = sanitize_name(name)?.join(format!("v{}", version));
@@ -170,9 +307,107 @@ impl Config {
         Ok(Self {
             system_path,
             user_path: Some(user_path),
+            format: Format::default(),
+            ron_pretty: Some(ron::ser::PrettyConfig::new()),
+            version,
         })
     }
 
+    /// Get config for the given application name and config version, using the host's
+    /// configuration when running inside a Flatpak sandbox.
+    ///
+    /// The user path is taken from `HOST_XDG_CONFIG_HOME`, and system defaults are searched for
+    /// in the host's data directories exposed under `/run/host` before the sandbox ones. Outside
+    /// of a sandbox this behaves like [`Config::new`].
+    ///
+    /// The user configuration directory is created when a key is first written to it.
+    #[cfg(unix)]
+    pub fn flatpak(name: &str, version: u64) -> Result<Self, Error> {
+        if !is_flatpak() {
+            return Self::new(name, version);
+        }
+
+        // Look for [name]/v[version]
+        let path = sanitize_name(name)?.join(format!("v{}", version));
+
+        // Search the host data directories for defaults before the sandbox ones
+        let system_path = match ["/run/host/usr/share", "/run/host/share"]
+            .iter()
+            .map(|data_dir| Path::new(data_dir).join("cosmic").join(&path))
+            .find(|system_path| system_path.is_dir())
+        {
+            Some(system_path) => Some(system_path),
+            None => xdg::BaseDirectories::with_prefix("cosmic")
+                .map_err(std::io::Error::from)?
+                .find_data_file(&path),
+        };
+
+        // Use the host configuration directory, if it is exposed to the sandbox
+        let config_home = std::env::var_os("HOST_XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute() && dir.is_dir())
+            .or_else(dirs::config_dir)
+            .ok_or(Error::NoConfigDirectory)?;
+
+        Ok(Self {
+            system_path,
+            user_path: Some(config_home.join("cosmic").join(path)),
+            format: Format::default(),
+            ron_pretty: Some(ron::ser::PrettyConfig::new()),
+            version,
+        })
+    }
+
+    /// Get config for the given application name and config version, stored in the given format
+    pub fn new_with_format(name: &str, version: u64, format: Format) -> Result<Self, Error> {
+        let mut config = Self::new(name, version)?;
+        config.format = format;
+        Ok(config)
+    }
+
+    /// Get config for the given application name and config version, migrating data from an
+    /// older config version.
+    ///
+    /// `f` is called with the old and new config only when the new config has no keys yet and
+    /// the old config exists, giving the application a place to transform values between schema
+    /// revisions.
+    pub fn migrate<F>(name: &str, old_version: u64, new_version: u64, f: F) -> Result<Self, Error>
+    where
+        F: FnOnce(&Config, &Config) -> Result<(), Error>,
+    {
+        let config = Self::new(name, new_version)?;
+        let Some(user_path) = config.user_path.as_ref() else {
+            return Ok(config);
+        };
+
+        let old_user_path = user_path.with_file_name(format!("v{}", old_version));
+        if old_user_path.is_dir() && fs::read_dir(user_path)?.next().is_none() {
+            let old_config = Self::new(name, old_version)?;
+            f(&old_config, &config)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Get the version of the config
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Get the serialization format used to store config values
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Set how RON values are formatted when written, or `None` to write them compactly
+    ///
+    /// Values are read the same way regardless of how they were written.
+    #[must_use]
+    pub fn with_ron_pretty(mut self, ron_pretty: Option<ron::ser::PrettyConfig>) -> Self {
+        self.ron_pretty = ron_pretty;
+        self
+    }
+
     /// Get config for the given application name and config version and custom path.
     pub fn with_custom_path(name: &str, version: u64, custom_path: PathBuf) -> Result<Self, Error> {
         // Look for [name]/v[version]
@@ -188,6 +423,9 @@ impl Config {
         Ok(Self {
             system_path: None,
             user_path: Some(user_path),
+            format: Format::default(),
+            ron_pretty: Some(ron::ser::PrettyConfig::new()),
+            version,
         })
     }
 
@@ -212,6 +450,9 @@ impl Config {
         Ok(Self {
             system_path: None,
             user_path: Some(user_path),
+            format: Format::default(),
+            ron_pretty: Some(ron::ser::PrettyConfig::new()),
+            version,
         })
     }
 
@@ -231,16 +472,39 @@ impl Config {
     // Argument is an array of all keys that changed in that specific transaction
     //TODO: simplify F requirements
     where
-        F: Fn(&Self, &[String]) + Send + Sync + 'static,
+        F: Fn(&Self, &[Change]) + Send + Sync + 'static,
+    {
+        self.watch_with_errors(f, |_err| {})
+    }
+
+    /// Watch keys for changes, calling `on_error` when the watch encounters an error.
+    ///
+    /// If the config directory is removed, `on_error` receives [`Error::NoConfigDirectory`], and
+    /// the directory is watched again once it reappears.
+    pub fn watch_with_errors<F, E>(
+        &self,
+        f: F,
+        on_error: E,
+    ) -> Result<notify::RecommendedWatcher, Error>
+    where
+        F: Fn(&Self, &[Change]) + Send + Sync + 'static,
+        E: Fn(Error) + Send + Sync + 'static,
     {
         let watch_config = self.clone();
         let Some(user_path) = self.user_path.as_ref() else {
             return Err(Error::NoConfigDirectory);
         };
+        // Watch the parent recursively, so the config directory is watched again if it is
+        // removed and recreated
+        let Some(parent_path) = user_path.parent() else {
+            return Err(Error::NoConfigDirectory);
+        };
+        // The config directory may not have been written to yet
+        fs::create_dir_all(parent_path)?;
         let user_path_clone = user_path.clone();
         let mut watcher =
             notify::recommended_watcher(move |event_res: Result<notify::Event, notify::Error>| {
-                match &event_res {
+                match event_res {
                     Ok(event) => {
                         match &event.kind {
                             EventKind::Access(_) | EventKind::Modify(ModifyKind::Metadata(_)) => {
@@ -252,18 +516,37 @@ impl Config {
 
                         let mut keys = Vec::new();
                         for path in &event.paths {
-                            match path.strip_prefix(&user_path_clone) {
-                                Ok(key_path) => {
-                                    if let Some(key) = key_path.to_str() {
-                                        // Skip any .atomicwrite temporary files
-                                        if key.starts_with(".atomicwrite") {
-                                            continue;
-                                        }
-                                        keys.push(key.to_string());
-                                    }
+                            // Ignore other config versions
+                            let Ok(key_path) = path.strip_prefix(&user_path_clone) else {
+                                continue;
+                            };
+
+                            if key_path.as_os_str().is_empty() {
+                                if !user_path_clone.is_dir() {
+                                    on_error(Error::NoConfigDirectory);
                                 }
-                                Err(_err) => {
-                                    //TODO: handle errors
+                                continue;
+                            }
+
+                            // Ignore anything nested below the config directory
+                            if key_path.components().count() != 1 {
+                                continue;
+                            }
+
+                            if let Some(key) = key_path.to_str() {
+                                // Skip any .atomicwrite temporary files
+                                if key.starts_with(".atomicwrite") {
+                                    continue;
+                                }
+                                // Atomic writes rename over the key, so the key
+                                // still existing means it was set
+                                let change = if path.exists() {
+                                    Change::Set(key.to_string())
+                                } else {
+                                    Change::Removed(key.to_string())
+                                };
+                                if !keys.contains(&change) {
+                                    keys.push(change);
                                 }
                             }
                         }
@@ -271,34 +554,43 @@ impl Config {
                             f(&watch_config, &keys);
                         }
                     }
-                    Err(_err) => {
-                        //TODO: handle errors
+                    Err(err) => {
+                        on_error(Error::Notify(err));
                     }
                 }
             })?;
-        watcher.watch(user_path, notify::RecursiveMode::NonRecursive)?;
+        watcher.watch(parent_path, notify::RecursiveMode::Recursive)?;
         Ok(watcher)
     }
 
+    /// Whether the user has overridden the value of a key, without reading it.
+    pub fn has_local(&self, key: &str) -> bool {
+        self.key_path(key).is_ok_and(|path| path.is_file())
+    }
+
+    /// Whether the system provides a default value for a key, without reading it.
+    pub fn has_system_default(&self, key: &str) -> bool {
+        self.default_path(key).is_ok_and(|path| path.is_file())
+    }
+
     fn default_path(&self, key: &str) -> Result<PathBuf, Error> {
         let Some(system_path) = self.system_path.as_ref() else {
             return Err(Error::NoConfigDirectory);
         };
 
-        Ok(system_path.join(sanitize_name(key)?))
+        Ok(system_path.join(sanitize_key(key)?))
     }
 
     fn key_path(&self, key: &str) -> Result<PathBuf, Error> {
         let Some(user_path) = self.user_path.as_ref() else {
             return Err(Error::NoConfigDirectory);
         };
-        Ok(user_path.join(sanitize_name(key)?))
+        Ok(user_path.join(sanitize_key(key)?))
     }
 }
 
 // Getting any setting is available on a Config object
 impl ConfigGet for Config {
-    //TODO: check for transaction
     fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error> {
         // If key path exists
         let key_path = self.key_path(key);
@@ -314,8 +606,7 @@ impl ConfigGet for Config {
                     .map_err(|err| Error::GetKey(key.to_string(), err))?
             }
         };
-        let t = ron::from_str(&data)?;
-        Ok(t)
+        self.format.deserialize(&data)
     }
 }
 
@@ -327,55 +618,284 @@ impl ConfigSet for Config {
         tx.set(key, value)?;
         tx.commit()
     }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        // Wrap up single key removals in a transaction
+        let tx = self.transaction();
+        tx.remove(key)?;
+        tx.commit()
+    }
+}
+
+// Async variants of getting and setting, which run file IO on a blocking thread
+#[cfg(feature = "tokio")]
+impl Config {
+    /// Get a configuration value without blocking the executor
+    pub async fn get_async<T: DeserializeOwned + Send + 'static>(
+        &self,
+        key: &str,
+    ) -> Result<T, Error> {
+        let config = self.clone();
+        let key = key.to_owned();
+        tokio::task::spawn_blocking(move || config.get(&key))
+            .await
+            .map_err(|err| Error::Io(std::io::Error::new(std::io::ErrorKind::Other, err)))?
+    }
+
+    /// Set a configuration value without blocking the executor
+    pub async fn set_async<T: Serialize>(&self, key: &str, value: T) -> Result<(), Error> {
+        let key_path = self.key_path(key)?;
+        let data = self.format.serialize(&value, self.ron_pretty.as_ref())?;
+        self.commit_async(key_path, Some(data)).await
+    }
+
+    /// Remove a configuration value without blocking the executor
+    pub async fn remove_async(&self, key: &str) -> Result<(), Error> {
+        let key_path = self.key_path(key)?;
+        self.commit_async(key_path, None).await
+    }
+
+    async fn commit_async(&self, key_path: PathBuf, data: Option<String>) -> Result<(), Error> {
+        let config = self.clone();
+        tokio::task::spawn_blocking(move || {
+            let tx = config.transaction();
+            tx.queue(key_path, data)?;
+            tx.commit()
+        })
+        .await
+        .map_err(|err| Error::Io(std::io::Error::new(std::io::ErrorKind::Other, err)))?
+    }
 }
 
 #[must_use = "Config transaction must be committed"]
 pub struct ConfigTransaction<'a> {
     config: &'a Config,
     //TODO: use map?
-    updates: Mutex<Vec<(PathBuf, String)>>,
+    updates: Mutex<Vec<PendingUpdate>>,
 }
 
-impl<'a> ConfigTransaction<'a> {
-    /// Apply all pending changes from ConfigTransaction
-    //TODO: apply all changes at once
-    pub fn commit(self) -> Result<(), Error> {
-        let mut updates = self.updates.lock().unwrap();
-        for (key_path, data) in updates.drain(..) {
+/// A pending change to a key in a [`ConfigTransaction`]
+struct PendingUpdate {
+    key_path: PathBuf,
+    /// New contents of the key, where `None` removes the key
+    data: Option<String>,
+    /// Contents of the key when the update was queued, used for rollback
+    previous: Option<Vec<u8>>,
+}
+
+impl PendingUpdate {
+    fn new(key_path: PathBuf, data: Option<String>) -> Result<Self, Error> {
+        let previous = match fs::read(&key_path) {
+            Ok(previous) => Some(previous),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self {
+            key_path,
+            data,
+            previous,
+        })
+    }
+
+    fn apply(&self) -> Result<(), Error> {
+        write_key(&self.key_path, self.data.as_ref().map(String::as_bytes))
+    }
+
+    fn rollback(&self) -> Result<(), Error> {
+        write_key(&self.key_path, self.previous.as_deref())
+    }
+}
+
+/// Atomically write the contents of a key, or remove it if `data` is `None`
+fn write_key(key_path: &Path, data: Option<&[u8]>) -> Result<(), Error> {
+    match data {
+        Some(data) => {
+            // Create the config directory on first write, if it was not created up front
+            if let Some(parent) = key_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
             atomicwrites::AtomicFile::new(
                 key_path,
                 atomicwrites::OverwriteBehavior::AllowOverwrite,
             )
-            .write(|file| file.write_all(data.as_bytes()))?;
+            .write(|file| file.write_all(data))?;
+        }
+        None => match fs::remove_file(key_path) {
+            Ok(()) => {}
+            // Nothing to remove, the system default is already in use
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        },
+    }
+    Ok(())
+}
+
+impl<'a> ConfigTransaction<'a> {
+    /// Queue a change to a key, replacing any pending change to the same key
+    fn queue(&self, key_path: PathBuf, data: Option<String>) -> Result<(), Error> {
+        let mut updates = self.updates.lock().unwrap();
+        if let Some(update) = updates.iter_mut().find(|u| u.key_path == key_path) {
+            // Keep the contents from before the transaction for rollback
+            update.data = data;
+        } else {
+            updates.push(PendingUpdate::new(key_path, data)?);
         }
         Ok(())
     }
+
+    /// The change to a key queued in this transaction, where `Some(None)` removes the key
+    fn pending(&self, key_path: &Path) -> Option<Option<String>> {
+        self.updates
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.key_path == key_path)
+            .map(|u| u.data.clone())
+    }
+
+    /// Move the value of a key to another key, replacing any value it had
+    ///
+    /// The value is taken from a change to `from` already queued in this
+    /// transaction, the user's override, or the system default, in that order.
+    /// Only the user's override of `from` is removed.
+    pub fn rename(&self, from: &str, to: &str) -> Result<(), Error> {
+        let from_path = self.config.key_path(from)?;
+        let to_path = self.config.key_path(to)?;
+        if from_path == to_path {
+            return Ok(());
+        }
+
+        let data = match self.pending(&from_path) {
+            Some(Some(data)) => data,
+            Some(None) => {
+                return Err(Error::GetKey(
+                    from.to_string(),
+                    std::io::ErrorKind::NotFound.into(),
+                ))
+            }
+            None => {
+                let path = if from_path.is_file() {
+                    from_path.clone()
+                } else {
+                    self.config.default_path(from)?
+                };
+                fs::read_to_string(path).map_err(|err| Error::GetKey(from.to_string(), err))?
+            }
+        };
+
+        self.queue(to_path, Some(data))?;
+        self.queue(from_path, None)
+    }
+
+    /// Apply all pending changes from ConfigTransaction
+    ///
+    /// If any change fails to apply, the changes already applied by this
+    /// transaction are rolled back to their previous contents.
+    pub fn commit(self) -> Result<(), Error> {
+        let updates = std::mem::take(&mut *self.updates.lock().unwrap());
+        for (i, update) in updates.iter().enumerate() {
+            if let Err(err) = update.apply() {
+                for applied in updates[..i].iter().rev() {
+                    if let Err(rollback_err) = applied.rollback() {
+                        tracing::error!(
+                            "failed to roll back {:?}: {}",
+                            applied.key_path,
+                            rollback_err
+                        );
+                    }
+                }
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Getting a setting within a transaction reads through its queued changes
+impl<'a> ConfigGet for ConfigTransaction<'a> {
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error> {
+        let pending = self
+            .config
+            .key_path(key)
+            .ok()
+            .and_then(|key_path| self.pending(&key_path));
+
+        match pending {
+            Some(Some(data)) => self.config.format.deserialize(&data),
+            // A removed key falls back to the system default
+            Some(None) => {
+                let default_path = self.config.default_path(key)?;
+                let data = fs::read_to_string(default_path)
+                    .map_err(|err| Error::GetKey(key.to_string(), err))?;
+                self.config.format.deserialize(&data)
+            }
+            None => self.config.get(key),
+        }
+    }
 }
 
 // Setting any setting in this way will do one transaction for all settings
 // when commit finishes that transaction
 impl<'a> ConfigSet for ConfigTransaction<'a> {
     fn set<T: Serialize>(&self, key: &str, value: T) -> Result<(), Error> {
-        //TODO: sanitize key (no slashes, cannot be . or ..)
+        // Validate the key before queueing, so a bad key never reaches commit
         let key_path = self.config.key_path(key)?;
-        let data = ron::ser::to_string_pretty(&value, ron::ser::PrettyConfig::new())?;
-        //TODO: replace duplicates?
-        {
-            let mut updates = self.updates.lock().unwrap();
-            updates.push((key_path, data));
-        }
-        Ok(())
+        let data = self
+            .config
+            .format
+            .serialize(&value, self.config.ron_pretty.as_ref())?;
+        self.queue(key_path, Some(data))
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        let key_path = self.config.key_path(key)?;
+        self.queue(key_path, None)
     }
 }
 
+/// The key which [`CosmicConfigEntry::load_or_report`] pairs with errors that it cannot attribute
+/// to a key.
+pub const UNKNOWN_KEY: &str = "<unknown>";
+
 pub trait CosmicConfigEntry
 where
     Self: Sized,
 {
     const VERSION: u64;
+    /// The keys of the entry's fields, as they are stored in the config
+    ///
+    /// Generated by the derive macro. Empty unless given by a manual implementation.
+    const KEYS: &'static [&'static str] = &[];
 
     fn write_entry(&self, config: &Config) -> Result<(), crate::Error>;
     fn get_entry(config: &Config) -> Result<Self, (Vec<crate::Error>, Self)>;
+    /// Loads the entry, recovering from errors, and pairs each error with the key which failed
+    ///
+    /// Keys which failed to load keep their default value. The derive macro pairs every error
+    /// with its key. Otherwise, errors are paired with the key they name, if it is one of
+    /// [`Self::KEYS`], or else with [`UNKNOWN_KEY`].
+    fn load_or_report(config: &Config) -> (Self, Vec<(&'static str, crate::Error)>) {
+        let (entry, errors) = match Self::get_entry(config) {
+            Ok(entry) => return (entry, Vec::new()),
+            Err((errors, entry)) => (entry, errors),
+        };
+
+        let errors = errors
+            .into_iter()
+            .map(|error| {
+                let key = match &error {
+                    Error::GetKey(key, _) => {
+                        Self::KEYS.iter().find(|known| **known == key.as_str())
+                    }
+                    _ => None,
+                };
+
+                (key.copied().unwrap_or(UNKNOWN_KEY), error)
+            })
+            .collect();
+
+        (entry, errors)
+    }
     /// Returns the keys that were updated
     fn update_keys<T: AsRef<str>>(
         &mut self,
@@ -390,3 +910,138 @@ pub struct Update<T> {
     pub keys: Vec<&'static str>,
     pub config: T,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config(name: &str) -> Config {
+        let path = std::env::temp_dir().join(format!(
+            "cosmic-config-test-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&path);
+        Config::with_custom_path("com.system76.CosmicConfigTest", 1, path).unwrap()
+    }
+
+    #[test]
+    fn transaction_deduplicates_keys() {
+        let config = test_config("dedup");
+        let tx = config.transaction();
+        tx.set("foo", 1u32).unwrap();
+        tx.set("foo", 2u32).unwrap();
+        tx.set("foo", 3u32).unwrap();
+        assert_eq!(tx.updates.lock().unwrap().len(), 1);
+        tx.commit().unwrap();
+
+        assert_eq!(config.get::<u32>("foo").unwrap(), 3);
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        let config = test_config("invalid-keys");
+        let tx = config.transaction();
+        for key in ["", ".", "..", "a/b", "../escape", "/absolute", "trailing/"] {
+            assert!(
+                matches!(tx.set(key, 1u32), Err(Error::InvalidName(_))),
+                "set accepted {key:?}"
+            );
+            assert!(
+                matches!(tx.remove(key), Err(Error::InvalidName(_))),
+                "remove accepted {key:?}"
+            );
+        }
+        assert!(tx.updates.lock().unwrap().is_empty());
+        tx.commit().unwrap();
+    }
+
+    #[test]
+    fn transaction_renames_key() {
+        let config = test_config("rename");
+        config.set("old", 5u32).unwrap();
+
+        let tx = config.transaction();
+        tx.rename("old", "new").unwrap();
+        assert!(config.has_local("old"));
+        tx.commit().unwrap();
+
+        assert!(!config.has_local("old"));
+        assert_eq!(config.get::<u32>("new").unwrap(), 5);
+
+        let tx = config.transaction();
+        assert!(tx.rename("missing", "new").unwrap_err().is_not_found());
+        assert!(matches!(
+            tx.rename("new", "../escape"),
+            Err(Error::InvalidName(_))
+        ));
+    }
+
+    #[test]
+    fn transaction_reads_pending_writes() {
+        let config = test_config("read-through");
+        config.set("count", 1u32).unwrap();
+        config.set("other", 2u32).unwrap();
+
+        let tx = config.transaction();
+        let count = tx.get::<u32>("count").unwrap();
+        tx.set("count", count + 1).unwrap();
+        tx.remove("other").unwrap();
+
+        assert_eq!(tx.get::<u32>("count").unwrap(), 2);
+        assert!(tx.get::<u32>("other").unwrap_err().is_not_found());
+        assert_eq!(config.get::<u32>("count").unwrap(), 1);
+        tx.commit().unwrap();
+
+        assert_eq!(config.get::<u32>("count").unwrap(), 2);
+    }
+
+    #[test]
+    fn compact_ron_is_readable() {
+        let config = test_config("compact").with_ron_pretty(None);
+        config.set("list", vec![1u32, 2, 3]).unwrap();
+
+        let data = fs::read_to_string(config.key_path("list").unwrap()).unwrap();
+        assert_eq!(data, "[1,2,3]");
+        assert_eq!(config.get::<Vec<u32>>("list").unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn get_or_default_missing_key() {
+        let config = test_config("get-or");
+        assert_eq!(config.get_or("missing", 7u32).unwrap(), 7);
+        assert_eq!(config.get_or_default::<u32>("missing").unwrap(), 0);
+
+        config.set("invalid", "not a number").unwrap();
+        assert!(config.get_or_default::<u32>("invalid").is_err());
+    }
+
+    #[cfg(feature = "macro")]
+    #[test]
+    fn load_or_report_pairs_errors_with_keys() {
+        use crate as cosmic_config;
+        use crate::cosmic_config_derive::CosmicConfigEntry;
+
+        #[derive(CosmicConfigEntry, Debug, Default, PartialEq)]
+        struct Entry {
+            valid: u32,
+            #[config(rename = "renamed")]
+            invalid: u32,
+        }
+
+        let config = test_config("load-or-report");
+        config.set("valid", 5u32).unwrap();
+        config.set("renamed", "not a number").unwrap();
+
+        let (entry, errors) = Entry::load_or_report(&config);
+        assert_eq!(
+            entry,
+            Entry {
+                valid: 5,
+                invalid: 0
+            }
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "renamed");
+    }
+}
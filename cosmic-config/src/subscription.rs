@@ -3,11 +3,11 @@ use iced_futures::{futures::channel::mpsc, stream};
 use notify::RecommendedWatcher;
 use std::{borrow::Cow, hash::Hash};
 
-use crate::{Config, CosmicConfigEntry};
+use crate::{Change, Config, CosmicConfigEntry};
 
 pub enum ConfigState<T> {
     Init(Cow<'static, str>, u64, bool),
-    Waiting(T, RecommendedWatcher, mpsc::Receiver<Vec<String>>, Config),
+    Waiting(T, RecommendedWatcher, mpsc::Receiver<Vec<Change>>, Config),
     Failed,
 }
 
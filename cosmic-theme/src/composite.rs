@@ -25,3 +25,18 @@ fn alpha_over(a: f32, b: f32) -> f32 {
 fn c_over(a: f32, b: f32, a_alpha: f32, b_alpha: f32, o_alpha: f32) -> f32 {
     a * a_alpha + b * b_alpha * (1.0 - a_alpha) / o_alpha
 }
+
+/// linear interpolation from `a` to `b` on non-linear srgba, where `t` is clamped to `0.0..=1.0`
+pub fn lerp<A: Into<Srgba>, B: Into<Srgba>>(a: A, b: B, t: f32) -> Srgba {
+    let a = a.into();
+    let b = b.into();
+    let t = t.max(0.0).min(1.0);
+    let c_lerp = |a: f32, b: f32| a + (b - a) * t;
+
+    Srgba::new(
+        c_lerp(a.red, b.red),
+        c_lerp(a.green, b.green),
+        c_lerp(a.blue, b.blue),
+        c_lerp(a.alpha, b.alpha),
+    )
+}
@@ -1,3 +1,4 @@
+use crate::composite::lerp;
 use lazy_static::lazy_static;
 use palette::Srgba;
 use serde::{Deserialize, Serialize};
@@ -74,6 +75,18 @@ impl CosmicPalette {
             CosmicPalette::Light(_) | CosmicPalette::Dark(_) => false,
         }
     }
+
+    /// convert the palette to the high contrast variant of its mode
+    pub fn into_high_contrast(self) -> Self {
+        match self {
+            CosmicPalette::Dark(p) | CosmicPalette::HighContrastDark(p) => {
+                CosmicPalette::HighContrastDark(p)
+            }
+            CosmicPalette::Light(p) | CosmicPalette::HighContrastLight(p) => {
+                CosmicPalette::HighContrastLight(p)
+            }
+        }
+    }
 }
 
 impl Default for CosmicPalette {
@@ -162,6 +175,76 @@ pub struct CosmicPaletteInner {
     pub ext_indigo: Srgba,
 }
 
+impl CosmicPaletteInner {
+    /// Move the neutrals halfway towards the ends of their range, for more contrast between them
+    pub(crate) fn spread_neutrals(&mut self) {
+        let (start, end) = (self.neutral_0, self.neutral_10);
+
+        for neutral in [
+            &mut self.neutral_1,
+            &mut self.neutral_2,
+            &mut self.neutral_3,
+            &mut self.neutral_4,
+        ] {
+            *neutral = lerp(*neutral, start, 0.5);
+        }
+
+        for neutral in [
+            &mut self.neutral_6,
+            &mut self.neutral_7,
+            &mut self.neutral_8,
+            &mut self.neutral_9,
+        ] {
+            *neutral = lerp(*neutral, end, 0.5);
+        }
+    }
+
+    /// Linearly interpolate the colors of two palettes, where `t` is in `0.0..=1.0`
+    ///
+    /// The name is taken from whichever palette `t` is nearest to.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            name: if t < 0.5 {
+                a.name.clone()
+            } else {
+                b.name.clone()
+            },
+            bright_red: lerp(a.bright_red, b.bright_red, t),
+            bright_green: lerp(a.bright_green, b.bright_green, t),
+            bright_orange: lerp(a.bright_orange, b.bright_orange, t),
+            gray_1: lerp(a.gray_1, b.gray_1, t),
+            gray_2: lerp(a.gray_2, b.gray_2, t),
+            neutral_0: lerp(a.neutral_0, b.neutral_0, t),
+            neutral_1: lerp(a.neutral_1, b.neutral_1, t),
+            neutral_2: lerp(a.neutral_2, b.neutral_2, t),
+            neutral_3: lerp(a.neutral_3, b.neutral_3, t),
+            neutral_4: lerp(a.neutral_4, b.neutral_4, t),
+            neutral_5: lerp(a.neutral_5, b.neutral_5, t),
+            neutral_6: lerp(a.neutral_6, b.neutral_6, t),
+            neutral_7: lerp(a.neutral_7, b.neutral_7, t),
+            neutral_8: lerp(a.neutral_8, b.neutral_8, t),
+            neutral_9: lerp(a.neutral_9, b.neutral_9, t),
+            neutral_10: lerp(a.neutral_10, b.neutral_10, t),
+            accent_blue: lerp(a.accent_blue, b.accent_blue, t),
+            accent_indigo: lerp(a.accent_indigo, b.accent_indigo, t),
+            accent_purple: lerp(a.accent_purple, b.accent_purple, t),
+            accent_pink: lerp(a.accent_pink, b.accent_pink, t),
+            accent_red: lerp(a.accent_red, b.accent_red, t),
+            accent_orange: lerp(a.accent_orange, b.accent_orange, t),
+            accent_yellow: lerp(a.accent_yellow, b.accent_yellow, t),
+            accent_green: lerp(a.accent_green, b.accent_green, t),
+            accent_warm_grey: lerp(a.accent_warm_grey, b.accent_warm_grey, t),
+            ext_warm_grey: lerp(a.ext_warm_grey, b.ext_warm_grey, t),
+            ext_orange: lerp(a.ext_orange, b.ext_orange, t),
+            ext_yellow: lerp(a.ext_yellow, b.ext_yellow, t),
+            ext_blue: lerp(a.ext_blue, b.ext_blue, t),
+            ext_purple: lerp(a.ext_purple, b.ext_purple, t),
+            ext_pink: lerp(a.ext_pink, b.ext_pink, t),
+            ext_indigo: lerp(a.ext_indigo, b.ext_indigo, t),
+        }
+    }
+}
+
 impl CosmicPalette {
     /// name of the palette
     pub fn name(&self) -> &str {
@@ -1,7 +1,7 @@
 use palette::Srgba;
 use serde::{Deserialize, Serialize};
 
-use crate::composite::over;
+use crate::composite::{lerp, over};
 
 /// Theme Container colors of a theme, can be a theme background container, primary container, or secondary container
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
@@ -39,6 +39,17 @@ impl Container {
             small_widget,
         }
     }
+
+    /// Linearly interpolate the colors of two containers, where `t` is in `0.0..=1.0`
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            base: lerp(a.base, b.base, t),
+            component: Component::lerp(&a.component, &b.component, t),
+            divider: lerp(a.divider, b.divider, t),
+            on: lerp(a.on, b.on, t),
+            small_widget: lerp(a.small_widget, b.small_widget, t),
+        }
+    }
 }
 
 /// The colors for a widget of the Cosmic theme
@@ -76,6 +87,24 @@ pub struct Component {
 #[allow(clippy::must_use_candidate)]
 #[allow(clippy::doc_markdown)]
 impl Component {
+    /// Linearly interpolate the colors of two components, where `t` is in `0.0..=1.0`
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            base: lerp(a.base, b.base, t),
+            hover: lerp(a.hover, b.hover, t),
+            pressed: lerp(a.pressed, b.pressed, t),
+            selected: lerp(a.selected, b.selected, t),
+            selected_text: lerp(a.selected_text, b.selected_text, t),
+            focus: lerp(a.focus, b.focus, t),
+            divider: lerp(a.divider, b.divider, t),
+            on: lerp(a.on, b.on, t),
+            disabled: lerp(a.disabled, b.disabled, t),
+            on_disabled: lerp(a.on_disabled, b.on_disabled, t),
+            border: lerp(a.border, b.border, t),
+            disabled_border: lerp(a.disabled_border, b.disabled_border, t),
+        }
+    }
+
     /// get @hover_state_color
     pub fn hover_state_color(&self) -> Srgba {
         self.hover
@@ -1,5 +1,5 @@
 use crate::{
-    composite::over,
+    composite::{lerp, over},
     steps::{color_index, get_surface_color, get_text, steps},
     Component, Container, CornerRadii, CosmicPalette, CosmicPaletteInner, Spacing, ThemeMode,
     DARK_PALETTE, LIGHT_PALETTE, NAME,
@@ -153,6 +153,47 @@ impl Theme {
         todo!();
     }
 
+    /// Linearly interpolate the colors of two themes, where `t` is in `0.0..=1.0`
+    ///
+    /// This may be used to animate the transition between themes by driving `t` from `0.0`
+    /// to `1.0`. Properties which are not colors, such as spacing and corner radii, are
+    /// taken from whichever theme `t` is nearest to.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let nearest = if t < 0.5 { a } else { b };
+
+        Self {
+            name: nearest.name.clone(),
+            background: Container::lerp(&a.background, &b.background, t),
+            primary: Container::lerp(&a.primary, &b.primary, t),
+            secondary: Container::lerp(&a.secondary, &b.secondary, t),
+            accent: Component::lerp(&a.accent, &b.accent, t),
+            success: Component::lerp(&a.success, &b.success, t),
+            destructive: Component::lerp(&a.destructive, &b.destructive, t),
+            warning: Component::lerp(&a.warning, &b.warning, t),
+            accent_button: Component::lerp(&a.accent_button, &b.accent_button, t),
+            success_button: Component::lerp(&a.success_button, &b.success_button, t),
+            destructive_button: Component::lerp(&a.destructive_button, &b.destructive_button, t),
+            warning_button: Component::lerp(&a.warning_button, &b.warning_button, t),
+            icon_button: Component::lerp(&a.icon_button, &b.icon_button, t),
+            link_button: Component::lerp(&a.link_button, &b.link_button, t),
+            text_button: Component::lerp(&a.text_button, &b.text_button, t),
+            button: Component::lerp(&a.button, &b.button, t),
+            palette: CosmicPaletteInner::lerp(&a.palette, &b.palette, t),
+            spacing: nearest.spacing,
+            corner_radii: nearest.corner_radii,
+            is_dark: nearest.is_dark,
+            is_high_contrast: nearest.is_high_contrast,
+            gaps: nearest.gaps,
+            active_hint: nearest.active_hint,
+            window_hint: match (a.window_hint, b.window_hint) {
+                (Some(a), Some(b)) => Some(lerp(a, b, t).color),
+                _ => nearest.window_hint,
+            },
+            is_frosted: nearest.is_frosted,
+            shade: lerp(a.shade, b.shade, t),
+        }
+    }
+
     // TODO convenient getter functions for each named color variable
     #[must_use]
     #[allow(clippy::doc_markdown)]
@@ -730,9 +771,97 @@ impl ThemeBuilder {
         self
     }
 
-    #[allow(clippy::too_many_lines)]
+    /// apply a neutral tint to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn neutral_tint_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.neutral_tint(parse_hex(hex)?.color))
+    }
+
+    /// apply a text tint to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn text_tint_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.text_tint(parse_hex(hex)?.color))
+    }
+
+    /// apply a background color to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn bg_color_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.bg_color(parse_hex(hex)?))
+    }
+
+    /// apply a primary container background color to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn primary_container_bg_hex(
+        self,
+        hex: &str,
+    ) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.primary_container_bg(parse_hex(hex)?))
+    }
+
+    /// apply a accent color to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn accent_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.accent(parse_hex(hex)?.color))
+    }
+
+    /// apply a success color to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn success_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.success(parse_hex(hex)?.color))
+    }
+
+    /// apply a warning color to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn warning_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.warning(parse_hex(hex)?.color))
+    }
+
+    /// apply a destructive color to the palette, parsed from a hex string such as `#5B2E8C`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid color.
+    pub fn destructive_hex(self, hex: &str) -> Result<Self, csscolorparser::ParseColorError> {
+        Ok(self.destructive(parse_hex(hex)?.color))
+    }
+
+    /// build the high contrast variant of the theme, from the same accent and tints
+    ///
+    /// The neutrals are spread further apart, and dividers are drawn with more contrast, so
+    /// that a custom theme has an accessible high contrast variant.
+    pub fn build_high_contrast(mut self) -> Theme {
+        self.palette = self.palette.into_high_contrast();
+        self.build_with(true)
+    }
+
     /// build the theme
     pub fn build(self) -> Theme {
+        self.build_with(false)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn build_with(self, spread_neutrals: bool) -> Theme {
         let Self {
             mut palette,
             spacing,
@@ -801,7 +930,15 @@ impl ThemeBuilder {
             p.neutral_10 = neutral_steps_arr[10];
         }
 
-        let p_ref = palette.as_ref();
+        // Colors are derived from a copy with its neutrals spread further apart, which also
+        // strengthens borders. The theme keeps the palette it was built from, so that rebuilding
+        // from it does not spread them again.
+        let mut derived = palette.clone();
+        if spread_neutrals {
+            derived.as_mut().spread_neutrals();
+        }
+
+        let p_ref = derived.as_ref();
 
         let neutral_steps = steps(
             neutral_tint.unwrap_or(Rgb::new(0.0, 0.0, 0.0)),
@@ -1114,3 +1251,10 @@ impl ThemeBuilder {
         Config::new(LIGHT_THEME_BUILDER_ID, Self::VERSION)
     }
 }
+
+/// parse a hex color string, such as `#5B2E8C` or `#5B2E8C80`
+#[allow(clippy::cast_possible_truncation)]
+fn parse_hex(hex: &str) -> Result<Srgba, csscolorparser::ParseColorError> {
+    let c = csscolorparser::parse(hex)?;
+    Ok(Srgba::new(c.r as f32, c.g as f32, c.b as f32, c.a as f32))
+}
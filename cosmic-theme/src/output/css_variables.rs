@@ -0,0 +1,90 @@
+use std::fmt::Write;
+
+use palette::Srgba;
+
+use crate::Theme;
+
+use super::to_rgba;
+
+impl Theme {
+    #[must_use]
+    /// turn the theme into CSS custom properties, declared on `:root`
+    ///
+    /// Properties are always emitted in the same order, so the output is stable for a theme.
+    pub fn as_css_variables(&self) -> String {
+        let mut css = String::from("/* GENERATED BY COSMIC */\n");
+        css.push_str(&self.css_variables_block(":root", ""));
+        css
+    }
+
+    #[must_use]
+    /// turn a light and dark theme into CSS custom properties, declared on `:root`
+    ///
+    /// The light theme is declared by default, and the dark theme overrides it when
+    /// `prefers-color-scheme` is `dark`.
+    pub fn as_css_variables_with_dark(light: &Theme, dark: &Theme) -> String {
+        let mut css = String::from("/* GENERATED BY COSMIC */\n");
+        css.push_str(&light.css_variables_block(":root", ""));
+        css.push_str("\n@media (prefers-color-scheme: dark) {\n");
+        css.push_str(&dark.css_variables_block(":root", "    "));
+        css.push_str("}\n");
+        css
+    }
+
+    fn css_variables_block(&self, selector: &str, indent: &str) -> String {
+        let Self {
+            background,
+            primary,
+            secondary,
+            accent,
+            success,
+            warning,
+            destructive,
+            palette,
+            ..
+        } = self;
+
+        let mut variables: Vec<(String, Srgba)> = vec![
+            ("bg".into(), background.base),
+            ("fg".into(), background.on),
+            ("primary-bg".into(), primary.base),
+            ("primary-fg".into(), primary.on),
+            ("secondary-bg".into(), secondary.base),
+            ("secondary-fg".into(), secondary.on),
+            ("accent".into(), accent.base),
+            ("accent-fg".into(), accent.on),
+            ("success".into(), success.base),
+            ("success-fg".into(), success.on),
+            ("warning".into(), warning.base),
+            ("warning-fg".into(), warning.on),
+            ("destructive".into(), destructive.base),
+            ("destructive-fg".into(), destructive.on),
+        ];
+
+        let neutrals = [
+            palette.neutral_0,
+            palette.neutral_1,
+            palette.neutral_2,
+            palette.neutral_3,
+            palette.neutral_4,
+            palette.neutral_5,
+            palette.neutral_6,
+            palette.neutral_7,
+            palette.neutral_8,
+            palette.neutral_9,
+            palette.neutral_10,
+        ];
+
+        for (i, c) in neutrals.into_iter().enumerate() {
+            variables.push((format!("neutral-{i}"), c));
+        }
+
+        let mut css = format!("{indent}{selector} {{\n");
+        for (name, c) in variables {
+            let _ = writeln!(css, "{indent}    --cosmic-{name}: {};", to_rgba(c));
+        }
+        css.push_str(indent);
+        css.push_str("}\n");
+        css
+    }
+}
@@ -3,6 +3,9 @@ use thiserror::Error;
 
 use crate::Theme;
 
+/// Module for outputting the Cosmic theme palette as CSS custom properties
+pub mod css_variables;
+
 /// Module for outputting the Cosmic gtk4 theme type as CSS
 pub mod gtk4_output;
 
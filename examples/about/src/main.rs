@@ -29,6 +29,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 pub enum Message {
     ToggleAbout,
     Open(String),
+    Copy(String),
 }
 
 /// The [`App`] stores application-specific state.
@@ -76,6 +77,11 @@ impl cosmic::Application for App {
                 ("Website", "https://system76.com/cosmic"),
                 ("Repository", "https://github.com/pop-os/libcosmic"),
                 ("Support", "https://github.com/pop-os/libcosmic/issues"),
+            ])
+            .debug_info([
+                ("OS", std::env::consts::OS),
+                ("Architecture", std::env::consts::ARCH),
+                ("Renderer", "wgpu"),
             ]);
 
         let mut app = App {
@@ -102,8 +108,14 @@ impl cosmic::Application for App {
     }
 
     fn context_drawer(&self) -> Option<ContextDrawer<Self::Message>> {
-        self.show_about
-            .then(|| context_drawer::about(&self.about, Message::Open, Message::ToggleAbout))
+        self.show_about.then(|| {
+            context_drawer::about_with_copy(
+                &self.about,
+                Message::Open,
+                Message::Copy,
+                Message::ToggleAbout,
+            )
+        })
     }
 
     /// Handle application events here.
@@ -117,6 +129,7 @@ impl cosmic::Application for App {
                 Ok(_) => (),
                 Err(err) => tracing::error!("Failed to open URL: {err}"),
             },
+            Message::Copy(text) => return iced::clipboard::write(text),
         }
         Task::none()
     }
@@ -161,6 +161,7 @@ impl cosmic::Application for App {
                     Some(Message::ToggleHide),
                     self.hidden
                 )
+                .strength_meter(password_strength)
                 .on_input(Message::Input1),
                 cosmic::widget::text_input::text_input("", &self.input_1).on_input(Message::Input1),
                 cosmic::widget::text_input::search_input("", &self.input_2)
@@ -201,3 +202,24 @@ where
         }
     }
 }
+
+/// Rates a password by its length and the variety of its characters.
+fn password_strength(password: &str) -> cosmic::widget::text_input::Strength {
+    use cosmic::widget::text_input::Strength;
+
+    let classes = [
+        password.chars().any(char::is_lowercase),
+        password.chars().any(char::is_uppercase),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|&class| class)
+    .count();
+
+    match (password.chars().count(), classes) {
+        (12.., 3..) => Strength::Strong,
+        (8.., 2..) => Strength::Fair,
+        _ => Strength::Weak,
+    }
+}
@@ -3,9 +3,9 @@
 
 //! Calendar widget example
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use cosmic::app::{Core, Settings, Task};
-use cosmic::widget::calendar::CalendarModel;
+use cosmic::widget::calendar::{locale_first_day_of_week, CalendarModel};
 use cosmic::{executor, iced, ApplicationExt, Element};
 
 /// Runs application with these settings
@@ -56,7 +56,7 @@ impl cosmic::Application for App {
     fn init(core: Core, _input: Self::Flags) -> (Self, Task<Self::Message>) {
         let mut app = App {
             core,
-            calendar_model: CalendarModel::now(),
+            calendar_model: CalendarModel::now().range_selection(true),
         };
 
         let command = app.update_title();
@@ -68,7 +68,7 @@ impl cosmic::Application for App {
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
         match message {
             Message::DateSelected(date) => {
-                self.calendar_model.selected = date;
+                self.calendar_model.select(date);
             }
             Message::PrevMonth => {
                 self.calendar_model.show_prev_month();
@@ -78,7 +78,10 @@ impl cosmic::Application for App {
             }
         }
 
-        println!("Date selected: {:?}", &self.calendar_model.selected);
+        match self.calendar_model.range() {
+            Some((start, end)) => println!("Range selected: {start:?} to {end:?}"),
+            None => println!("Date selected: {:?}", &self.calendar_model.selected),
+        }
 
         Task::none()
     }
@@ -92,7 +95,11 @@ impl cosmic::Application for App {
             |date| Message::DateSelected(date),
             || Message::PrevMonth,
             || Message::NextMonth,
-        );
+        )
+        .first_day_of_week(locale_first_day_of_week())
+        .show_week_numbers(true)
+        // Weekends are unavailable.
+        .is_enabled(|date| date.weekday().num_days_from_monday() < 5);
 
         content = content.push(calendar);
 
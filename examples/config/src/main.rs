@@ -1,14 +1,19 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-use cosmic_config::{Config, ConfigGet, ConfigSet};
+use cosmic_config::{Change, Config, ConfigGet, ConfigSet};
 
 fn test_config(config: Config) {
     let watcher = config
         .watch(|config, keys| {
             println!("Changed: {:?}", keys);
-            for key in keys.iter() {
-                println!(" - {} = {:?}", key, config.get::<ron::Value>(key));
+            for change in keys.iter() {
+                match change {
+                    Change::Set(key) => {
+                        println!(" - {} = {:?}", key, config.get::<ron::Value>(key));
+                    }
+                    Change::Removed(key) => println!(" - {} removed", key),
+                }
             }
         })
         .unwrap();
@@ -32,6 +32,13 @@ pub enum Message {
     ShowWindowMenu,
     ToggleHideContent,
     WindowNew,
+    SortBy(SortBy),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortBy {
+    Name,
+    Modified,
 }
 
 /// The [`App`] stores application-specific state.
@@ -40,6 +47,7 @@ pub struct App {
     button_label: String,
     show_context: bool,
     hide_content: bool,
+    sort_by: SortBy,
 }
 
 /// Implement [`cosmic::Application`] to integrate with COSMIC.
@@ -71,6 +79,7 @@ impl cosmic::Application for App {
             button_label: String::from("Right click me"),
             hide_content: false,
             show_context: false,
+            sort_by: SortBy::Name,
         };
 
         app.set_header_title("COSMIC Context Menu Demo".into());
@@ -87,6 +96,10 @@ impl cosmic::Application for App {
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
         self.button_label = format!("Clicked {message:?}");
 
+        if let Message::SortBy(sort_by) = message {
+            self.sort_by = sort_by;
+        }
+
         Task::none()
     }
 
@@ -116,12 +129,27 @@ impl App {
                 menu::Item::Divider,
                 menu::Item::Folder(
                     "View",
-                    vec![menu::Item::CheckBox(
-                        "Hide content",
-                        None,
-                        self.hide_content,
-                        ContextMenuAction::ToggleHideContent,
-                    )],
+                    vec![
+                        menu::Item::CheckBox(
+                            "Hide content",
+                            None,
+                            self.hide_content,
+                            ContextMenuAction::ToggleHideContent,
+                        ),
+                        menu::Item::Divider,
+                        menu::Item::Radio(
+                            "Sort by name",
+                            None,
+                            self.sort_by == SortBy::Name,
+                            ContextMenuAction::SortBy(SortBy::Name),
+                        ),
+                        menu::Item::Radio(
+                            "Sort by modified",
+                            None,
+                            self.sort_by == SortBy::Modified,
+                            ContextMenuAction::SortBy(SortBy::Modified),
+                        ),
+                    ],
                 ),
                 menu::Item::Divider,
                 menu::Item::Button("Quit", None, ContextMenuAction::WindowClose),
@@ -135,6 +163,7 @@ pub enum ContextMenuAction {
     WindowClose,
     ToggleHideContent,
     WindowNew,
+    SortBy(SortBy),
 }
 
 impl menu::Action for ContextMenuAction {
@@ -144,6 +173,7 @@ impl menu::Action for ContextMenuAction {
             ContextMenuAction::WindowClose => Message::WindowClose,
             ContextMenuAction::ToggleHideContent => Message::ToggleHideContent,
             ContextMenuAction::WindowNew => Message::WindowNew,
+            ContextMenuAction::SortBy(sort_by) => Message::SortBy(*sort_by),
         }
     }
 }
@@ -3,13 +3,13 @@ use std::{cell::RefCell, rc::Rc};
 use apply::Apply;
 use cosmic::{
     cosmic_theme,
-    iced::widget::{checkbox, column, progress_bar, radio, slider, text},
+    iced::widget::{checkbox, column, radio, slider, text},
     iced::{Alignment, Length},
     iced_core::id,
     theme::ThemeType,
     widget::{
         button, color_picker::ColorPickerUpdate, dropdown, icon, layer_container as container,
-        segmented_button, segmented_control, settings, spin_button, tab_bar, toggler,
+        progress_bar, segmented_button, segmented_control, settings, spin_button, tab_bar, toggler,
         ColorPickerModel,
     },
     Element,
@@ -299,8 +299,13 @@ impl State {
                         .add(settings::item(
                             "Progress",
                             progress_bar(0.0..=100.0, self.slider_value)
-                                .width(Length::Fixed(250.0))
-                                .height(Length::Fixed(4.0)),
+                                .width(Length::Fixed(250.0)),
+                        ))
+                        .add(settings::item(
+                            "Indeterminate Progress",
+                            progress_bar(0.0..=100.0, 0.0)
+                                .indeterminate()
+                                .width(Length::Fixed(250.0)),
                         ))
                         .add(settings::item_row(vec![checkbox(
                             "Checkbox",
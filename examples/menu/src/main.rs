@@ -8,13 +8,14 @@ use std::{env, process};
 
 use cosmic::app::{Core, Settings, Task};
 use cosmic::iced::window;
+use cosmic::iced::{keyboard, Subscription};
 use cosmic::iced_core::alignment::{Horizontal, Vertical};
-use cosmic::iced_core::keyboard::Key;
+use cosmic::iced_core::keyboard::{Key, Modifiers};
 use cosmic::iced_core::{Length, Size};
+use cosmic::widget::menu;
 use cosmic::widget::menu::action::MenuAction;
-use cosmic::widget::menu::key_bind::KeyBind;
 use cosmic::widget::menu::key_bind::Modifier;
-use cosmic::widget::menu::{self, ItemHeight, ItemWidth};
+use cosmic::widget::menu::key_bind::{KeyBind, KeySequence, Shortcut};
 use cosmic::{executor, Element};
 
 /// Runs application with these settings
@@ -43,17 +44,28 @@ pub enum Message {
     WindowClose,
     WindowNew,
     ToggleHideContent,
+    SortBy(SortBy),
+    Key(Modifiers, Key),
 }
 
 /// The [`App`] stores application-specific state.
 pub struct App {
     core: Core,
     config: Config,
-    key_binds: HashMap<KeyBind, Action>,
+    key_binds: HashMap<Shortcut, Action>,
+    key_sequence: KeySequence,
 }
 
 pub struct Config {
     hide_content: bool,
+    sort_by: SortBy,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortBy {
+    Name,
+    Modified,
+    Size,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -61,6 +73,7 @@ pub enum Action {
     WindowClose,
     ToggleHideContent,
     WindowNew,
+    SortBy(SortBy),
 }
 
 impl MenuAction for Action {
@@ -70,6 +83,7 @@ impl MenuAction for Action {
             Action::WindowClose => Message::WindowClose,
             Action::ToggleHideContent => Message::ToggleHideContent,
             Action::WindowNew => Message::WindowNew,
+            Action::SortBy(sort_by) => Message::SortBy(*sort_by),
         }
     }
 }
@@ -102,8 +116,10 @@ impl cosmic::Application for App {
             core,
             config: Config {
                 hide_content: false,
+                sort_by: SortBy::Name,
             },
             key_binds: key_binds(),
+            key_sequence: KeySequence::default(),
         };
 
         (app, Task::none())
@@ -113,6 +129,10 @@ impl cosmic::Application for App {
         vec![menu_bar(&self.config, &self.key_binds)]
     }
 
+    fn subscription(&self) -> Subscription<Self::Message> {
+        keyboard::on_key_press(|key, modifiers| Some(Message::Key(modifiers, key)))
+    }
+
     /// Handle application events here.
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
         match message {
@@ -131,6 +151,13 @@ impl cosmic::Application for App {
                 }
             },
             Message::ToggleHideContent => self.config.hide_content = !self.config.hide_content,
+            Message::SortBy(sort_by) => self.config.sort_by = sort_by,
+            Message::Key(modifiers, key) => {
+                if let Some(action) = self.key_sequence.matches(&self.key_binds, modifiers, &key) {
+                    let message = action.message();
+                    return cosmic::Application::update(self, message);
+                }
+            }
         }
         Task::none()
     }
@@ -153,52 +180,76 @@ impl cosmic::Application for App {
     }
 }
 
-pub fn menu_bar<'a>(config: &Config, key_binds: &HashMap<KeyBind, Action>) -> Element<'a, Message> {
-    menu::bar(vec![menu::Tree::with_children(
-        menu::root("File"),
-        menu::items(
-            key_binds,
-            vec![
-                menu::Item::Button(
-                    "New window",
-                    Some(cosmic::widget::icon::from_name("screenshot-window-symbolic").into()),
-                    Action::WindowNew,
-                ),
-                menu::Item::Divider,
-                menu::Item::Folder(
-                    "View",
-                    vec![menu::Item::CheckBox(
-                        "Hide content",
+pub fn menu_bar<'a>(
+    config: &Config,
+    key_binds: &'a HashMap<Shortcut, Action>,
+) -> Element<'a, Message> {
+    // Menus which do not fit in the window are collapsed into a trailing menu.
+    menu::responsive_bar(
+        key_binds,
+        vec![
+            (
+                "File",
+                vec![
+                    menu::Item::Button(
+                        "&New window",
+                        Some(cosmic::widget::icon::from_name("screenshot-window-symbolic").into()),
+                        Action::WindowNew,
+                    ),
+                    menu::Item::Divider,
+                    menu::Item::Button(
+                        "&Quit",
+                        Some(cosmic::widget::icon::from_name("window-close-symbolic").into()),
+                        Action::WindowClose,
+                    ),
+                ],
+            ),
+            (
+                "View",
+                vec![
+                    menu::Item::CheckBox(
+                        "&Hide content",
                         Some(cosmic::widget::icon::from_name("view-conceal-symbolic").into()),
                         config.hide_content,
                         Action::ToggleHideContent,
-                    )],
-                ),
-                menu::Item::Divider,
-                menu::Item::Button(
-                    "Quit",
-                    Some(cosmic::widget::icon::from_name("window-close-symbolic").into()),
-                    Action::WindowClose,
-                ),
-            ],
-        ),
-    )])
-    .item_height(ItemHeight::Dynamic(40))
-    .item_width(ItemWidth::Uniform(240))
-    .spacing(4.0)
-    .into()
+                    ),
+                    menu::Item::Divider,
+                    menu::Item::Folder(
+                        "&Sort by",
+                        vec![
+                            menu::Item::Radio(
+                                "&Name",
+                                None,
+                                config.sort_by == SortBy::Name,
+                                Action::SortBy(SortBy::Name),
+                            ),
+                            menu::Item::Radio(
+                                "&Modified",
+                                None,
+                                config.sort_by == SortBy::Modified,
+                                Action::SortBy(SortBy::Modified),
+                            ),
+                            menu::Item::Radio(
+                                "S&ize",
+                                None,
+                                config.sort_by == SortBy::Size,
+                                Action::SortBy(SortBy::Size),
+                            ),
+                        ],
+                    ),
+                ],
+            ),
+        ],
+    )
 }
 
-pub fn key_binds() -> HashMap<KeyBind, Action> {
+pub fn key_binds() -> HashMap<Shortcut, Action> {
     let mut key_binds = HashMap::new();
 
     macro_rules! bind {
         ([$($modifier:ident),* $(,)?], $key:expr, $action:ident) => {{
             key_binds.insert(
-                KeyBind {
-                    modifiers: vec![$(Modifier::$modifier),*],
-                    key: $key,
-                },
+                Shortcut::Key(KeyBind::new(vec![$(Modifier::$modifier),*], $key)),
                 Action::$action,
             );
         }};
@@ -207,5 +258,14 @@ pub fn key_binds() -> HashMap<KeyBind, Action> {
     bind!([Ctrl], Key::Character("w".into()), WindowClose);
     bind!([Ctrl, Shift], Key::Character("n".into()), WindowNew);
 
+    // Sequences are displayed as `Ctrl + K, H` in the menu.
+    key_binds.insert(
+        KeyBind::new(vec![], Key::Character("h".into())).with_prefix(KeyBind::new(
+            vec![Modifier::Ctrl],
+            Key::Character("k".into()),
+        )),
+        Action::ToggleHideContent,
+    );
+
     key_binds
 }
@@ -1,7 +1,5 @@
-use std::collections::HashMap;
-
 use cosmic::{
-    app::Core,
+    app::{Core, Windows},
     iced::{self, event, window},
     iced_core::{id, Alignment, Length, Point},
     iced_widget::{column, container, scrollable, text},
@@ -19,7 +17,7 @@ pub enum Message {
 }
 pub struct MultiWindow {
     core: Core,
-    windows: HashMap<window::Id, Window>,
+    windows: Windows<Window>,
 }
 
 pub struct Window {
@@ -43,16 +41,16 @@ impl cosmic::Application for MultiWindow {
     }
 
     fn init(core: Core, _input: Self::Flags) -> (Self, cosmic::app::Task<Self::Message>) {
-        let windows = MultiWindow {
-            windows: HashMap::from([(
-                core.main_window_id().unwrap(),
-                Window {
-                    input_id: id::Id::new("main"),
-                    input_value: String::new(),
-                },
-            )]),
-            core,
-        };
+        let mut windows = Windows::new();
+        windows.insert(
+            core.main_window_id().unwrap(),
+            Window {
+                input_id: id::Id::new("main"),
+                input_value: String::new(),
+            },
+        );
+
+        let windows = MultiWindow { windows, core };
 
         (windows, cosmic::app::Task::none())
     }
@@ -79,17 +77,17 @@ impl cosmic::Application for MultiWindow {
         message: Self::Message,
     ) -> iced::Task<cosmic::app::Message<Self::Message>> {
         match message {
-            Message::CloseWindow(id) => window::close(id),
+            Message::CloseWindow(id) => self.core.close(Some(id)),
             Message::WindowClosed(id) => {
-                self.windows.remove(&id);
+                self.windows.remove(id);
                 Task::none()
             }
             Message::WindowOpened(id, ..) => {
-                if let Some(window) = self.windows.get(&id) {
+                let focus_window = self.core.focus(Some(id));
+                let focus_input = self.windows.update(id, |window| {
                     cosmic::widget::text_input::focus(window.input_id.clone())
-                } else {
-                    Task::none()
-                }
+                });
+                Task::batch([focus_window, focus_input])
             }
             Message::NewWindow => {
                 let count = self.windows.len() + 1;
@@ -123,8 +121,20 @@ impl cosmic::Application for MultiWindow {
     }
 
     fn view_window(&self, id: window::Id) -> cosmic::prelude::Element<Self::Message> {
-        let w = self.windows.get(&id).unwrap();
+        self.windows.view(id, |w| self.view_window_content(id, w))
+    }
 
+    fn view(&self) -> cosmic::prelude::Element<Self::Message> {
+        self.view_window(self.core.main_window_id().unwrap())
+    }
+}
+
+impl MultiWindow {
+    fn view_window_content<'a>(
+        &'a self,
+        id: window::Id,
+        w: &'a Window,
+    ) -> cosmic::prelude::Element<'a, Message> {
         let input_id = w.input_id.clone();
         let input = cosmic::widget::text_input::text_input("something", &w.input_value)
             .on_input(move |msg| Message::Input(input_id.clone(), msg))
@@ -154,8 +164,4 @@ impl cosmic::Application for MultiWindow {
             column![header_bar().focused(focused), window_content].into()
         }
     }
-
-    fn view(&self) -> cosmic::prelude::Element<Self::Message> {
-        self.view_window(self.core.main_window_id().unwrap())
-    }
 }
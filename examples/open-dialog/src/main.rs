@@ -6,8 +6,7 @@
 use apply::Apply;
 use cosmic::app::{Core, Settings, Task};
 use cosmic::dialog::file_chooser::{self, FileFilter};
-use cosmic::iced_core::Length;
-use cosmic::widget::button;
+use cosmic::widget::{button, toaster, Toast, ToastId, Toasts};
 use cosmic::{executor, iced, ApplicationExt, Element};
 use std::sync::Arc;
 use tokio::io::AsyncReadExt;
@@ -28,12 +27,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[derive(Clone, Debug)]
 pub enum Message {
     Cancelled,
-    CloseError,
+    CloseToast(ToastId),
     Error(String),
     FileRead(Url, String),
     OpenError(Arc<file_chooser::Error>),
     OpenFile,
+    OpenFiles,
+    OpenFolder,
+    SaveAs,
+    Saved(Url),
     Selected(Url),
+    SelectedMany(Vec<Url>),
 }
 
 /// The [`App`] stores application-specific state.
@@ -41,7 +45,7 @@ pub struct App {
     core: Core,
     file_contents: String,
     selected_file: Option<Url>,
-    error_status: Option<String>,
+    toasts: Toasts<Message>,
 }
 
 /// Implement [`cosmic::Application`] to integrate with COSMIC.
@@ -72,18 +76,49 @@ impl cosmic::Application for App {
             core,
             file_contents: String::new(),
             selected_file: None,
-            error_status: None,
+            toasts: Toasts::new(Message::CloseToast),
         };
 
+        // Files may also be opened by dropping them onto the window.
+        app.core.window.accept_file_drops = true;
+
         app.set_header_title("Open a file".into());
         let cmd = app.set_window_title("COSMIC OpenDialog Demo".into(), id);
 
         (app, cmd)
     }
 
+    fn on_drag_drop(
+        &mut self,
+        paths: Vec<std::path::PathBuf>,
+        _position: iced::Point,
+    ) -> Task<Self::Message> {
+        let mut urls: Vec<Url> = paths
+            .into_iter()
+            .filter_map(|path| Url::from_file_path(path).ok())
+            .collect();
+
+        match urls.len() {
+            0 => Task::none(),
+            1 => cosmic::Application::update(self, Message::Selected(urls.remove(0))),
+            _ => cosmic::Application::update(self, Message::SelectedMany(urls)),
+        }
+    }
+
     fn header_end(&self) -> Vec<Element<Self::Message>> {
         // Places a button the header to create open dialogs.
-        vec![button::suggested("Open").on_press(Message::OpenFile).into()]
+        vec![
+            button::standard("Save as")
+                .on_press_maybe(self.selected_file.as_ref().map(|_| Message::SaveAs))
+                .into(),
+            button::standard("Open folder")
+                .on_press(Message::OpenFolder)
+                .into(),
+            button::standard("Open multiple")
+                .on_press(Message::OpenFiles)
+                .into(),
+            button::suggested("Open").on_press(Message::OpenFile).into(),
+        ]
     }
 
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
@@ -143,16 +178,21 @@ impl cosmic::Application for App {
                 });
             }
 
+            Message::SelectedMany(urls) => {
+                eprintln!("selected {} files", urls.len());
+
+                // List the selected files instead of reading them.
+                self.set_header_title(format!("{} files selected", urls.len()));
+                self.selected_file = urls.first().cloned();
+                self.file_contents = urls.iter().map(Url::as_str).collect::<Vec<_>>().join("\n");
+            }
+
             // Creates a new open dialog.
             Message::OpenFile => {
                 return cosmic::task::future(async move {
                     eprintln!("opening new dialog");
 
-                    #[cfg(feature = "rfd")]
-                    let filter = FileFilter::new("Text files").extension("txt");
-
-                    #[cfg(feature = "xdg-portal")]
-                    let filter = FileFilter::new("Text files").glob("*.txt");
+                    let filter = FileFilter::new("Text files").mimetype("text/plain");
 
                     let dialog = file_chooser::open::Dialog::new()
                         // Sets title of the dialog window.
@@ -170,12 +210,88 @@ impl cosmic::Application for App {
                 });
             }
 
-            // Displays an error in the application's warning bar.
+            // Creates a new open dialog which accepts multiple files.
+            Message::OpenFiles => {
+                return cosmic::task::future(async move {
+                    let dialog = file_chooser::open::Dialog::new().title("Choose files");
+
+                    match dialog.open_files().await {
+                        Ok(response) => Message::SelectedMany(response.urls().to_vec()),
+
+                        Err(file_chooser::Error::Cancelled) => Message::Cancelled,
+
+                        Err(why) => Message::OpenError(Arc::new(why)),
+                    }
+                });
+            }
+
+            // Creates a new folder selection dialog.
+            Message::OpenFolder => {
+                return cosmic::task::future(async move {
+                    let dialog = file_chooser::open::Dialog::new().title("Choose a folder");
+
+                    match dialog.select_folder().await {
+                        Ok(response) => Message::SelectedMany(vec![response.url().to_owned()]),
+
+                        Err(file_chooser::Error::Cancelled) => Message::Cancelled,
+
+                        Err(why) => Message::OpenError(Arc::new(why)),
+                    }
+                });
+            }
+
+            // Creates a new save dialog, and writes the contents to the chosen destination.
+            Message::SaveAs => {
+                let contents = self.file_contents.clone();
+                let file_name = self
+                    .selected_file
+                    .as_ref()
+                    .and_then(|url| url.path_segments()?.last().map(String::from))
+                    .unwrap_or_default();
+
+                return cosmic::task::future(async move {
+                    let dialog = file_chooser::save::Dialog::new()
+                        .title("Save file")
+                        .set_current_name(file_name);
+
+                    let url = match dialog.save_file().await {
+                        Ok(response) => match response.url() {
+                            Some(url) => url.to_owned(),
+                            None => return Message::Cancelled,
+                        },
+
+                        Err(file_chooser::Error::Cancelled) => return Message::Cancelled,
+
+                        Err(why) => return Message::OpenError(Arc::new(why)),
+                    };
+
+                    let Ok(path) = url.to_file_path() else {
+                        return Message::Error(format!("{url} is not a local file path"));
+                    };
+
+                    if let Err(why) = tokio::fs::write(&path, contents).await {
+                        return Message::Error(format!("failed to save {}: {why}", path.display()));
+                    }
+
+                    Message::Saved(url)
+                });
+            }
+
+            Message::Saved(url) => {
+                eprintln!("saved file");
+                self.set_header_title(url.to_string());
+                self.selected_file = Some(url);
+            }
+
+            // Displays an error as a toast.
             Message::Error(why) => {
-                self.error_status = Some(why);
+                return self
+                    .toasts
+                    .push(Toast::new(why).duration(toaster::Duration::Long))
+                    .map(cosmic::app::Message::App);
             }
 
-            // Displays an error in the application's warning bar.
+            // Displays an error as a toast.
             Message::OpenError(why) => {
                 if let Some(why) = Arc::into_inner(why) {
                     let mut source: &dyn std::error::Error = &why;
@@ -187,12 +303,15 @@ impl cosmic::Application for App {
                         source = new_source;
                     }
 
-                    self.error_status = Some(string);
+                    return self
+                        .toasts
+                        .push(Toast::new(string).duration(toaster::Duration::Long))
+                        .map(cosmic::app::Message::App);
                 }
             }
 
-            Message::CloseError => {
-                self.error_status = None;
+            Message::CloseToast(id) => {
+                self.toasts.remove(id);
             }
         }
 
@@ -200,32 +319,16 @@ impl cosmic::Application for App {
     }
 
     fn view(&self) -> Element<Self::Message> {
-        let mut content = Vec::new();
-
-        if let Some(error) = self.error_status.as_deref() {
-            content.push(
-                cosmic::widget::warning(error)
-                    .on_close(Message::CloseError)
-                    .into(),
-            );
-
-            content.push(
-                iced::widget::vertical_space()
-                    .height(Length::Fixed(12.0))
-                    .into(),
-            );
-        }
-
-        content.push(if self.selected_file.is_none() {
+        let content = if self.selected_file.is_none() {
             center(iced::widget::text("Choose a text file"))
         } else {
             cosmic::widget::text(&self.file_contents)
                 .apply(iced::widget::scrollable)
                 .width(iced::Length::Fill)
                 .into()
-        });
+        };
 
-        iced::widget::column(content).into()
+        cosmic::widget::toaster(&self.toasts, content)
     }
 }
 
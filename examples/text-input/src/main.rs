@@ -4,7 +4,10 @@
 //! Application API example
 
 use cosmic::app::{Core, Settings, Task};
+use cosmic::widget::text_input::cursor::Position;
 use cosmic::{executor, iced, ApplicationExt, Element};
+use std::ops::Range;
+use std::time::Duration;
 
 /// Runs application with these settings
 #[rustfmt::skip]
@@ -20,16 +23,28 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// Messages that are used specifically by our [`App`].
 #[derive(Clone, Debug)]
 pub enum Message {
+    Caret(Position),
+    Command(String),
     EditMode(bool),
     Input(String),
+    Selection(Option<Range<Position>>),
+    Username(String),
+    ValidateUsername(String),
+    UsernameValidated(String, Result<(), String>),
 }
 
 /// The [`App`] stores application-specific state.
 pub struct App {
     core: Core,
+    caret: Position,
+    command: String,
     input: String,
+    selection: Option<Range<Position>>,
     editing: bool,
     search_id: cosmic::widget::Id,
+    username: String,
+    username_validating: bool,
+    username_error: Option<String>,
 }
 
 /// Implement [`cosmic::Application`] to integrate with COSMIC.
@@ -58,9 +73,15 @@ impl cosmic::Application for App {
     fn init(core: Core, _input: Self::Flags) -> (Self, Task<Self::Message>) {
         let mut app = App {
             core,
+            caret: Position::default(),
+            command: String::new(),
             editing: false,
             input: String::from("Test"),
+            selection: None,
             search_id: cosmic::widget::Id::unique(),
+            username: String::new(),
+            username_validating: false,
+            username_error: None,
         };
 
         let commands = Task::batch(vec![
@@ -74,6 +95,18 @@ impl cosmic::Application for App {
     /// Handle application events here.
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
         match message {
+            Message::Caret(caret) => {
+                self.caret = caret;
+            }
+
+            Message::Selection(selection) => {
+                self.selection = selection;
+            }
+
+            Message::Command(command) => {
+                self.command = command;
+            }
+
             Message::Input(text) => {
                 self.input = text;
             }
@@ -81,6 +114,27 @@ impl cosmic::Application for App {
             Message::EditMode(editing) => {
                 self.editing = editing;
             }
+
+            Message::Username(username) => {
+                self.username = username;
+                self.username_error = None;
+            }
+
+            Message::ValidateUsername(username) => {
+                self.username_validating = true;
+                return cosmic::task::future(async move {
+                    let result = username_available(&username).await;
+                    Message::UsernameValidated(username, result)
+                });
+            }
+
+            Message::UsernameValidated(username, result) => {
+                // Ignore results for values which have since been edited.
+                if username == self.username {
+                    self.username_validating = false;
+                    self.username_error = result.err();
+                }
+            }
         }
 
         Task::none()
@@ -95,11 +149,53 @@ impl cosmic::Application for App {
             Message::EditMode,
         )
         .on_input(Message::Input)
+        .on_caret_move(Message::Caret)
+        .on_selection_change(Message::Selection)
         .id(self.search_id.clone());
 
+        // Describes the caret and selection of the editable input.
+        let selected = self
+            .selection
+            .as_ref()
+            .and_then(|range| self.input.get(range.start.byte..range.end.byte))
+            .unwrap_or_default();
+
+        let cursor = cosmic::widget::text::caption(format!(
+            "Caret at {}, selected {selected:?}",
+            self.caret.grapheme
+        ));
+
         let inline = cosmic::widget::inline_input("", &self.input).on_input(Message::Input);
 
-        let column = cosmic::widget::column().push(editable).push(inline);
+        let mut username = cosmic::widget::text_input("Username", &self.username)
+            .label("Username")
+            .max_length(16)
+            .show_counter(true)
+            .on_input(Message::Username)
+            .on_validate(Duration::from_millis(500), Message::ValidateUsername)
+            .validating(self.username_validating);
+
+        if let Some(error) = self.username_error.as_deref() {
+            username = username.error(error);
+        }
+
+        // Suggests the remainder of the first command which starts with the input.
+        let completion = COMMANDS
+            .iter()
+            .find(|command| !self.command.is_empty() && command.starts_with(&self.command))
+            .map(|command| &command[self.command.len()..]);
+
+        let command = cosmic::widget::text_input("Command", &self.command)
+            .label("Command")
+            .completion(completion)
+            .on_input(Message::Command);
+
+        let column = cosmic::widget::column()
+            .push(editable)
+            .push(cursor)
+            .push(inline)
+            .push(username)
+            .push(command);
 
         let centered = cosmic::widget::container(column.width(200))
             .width(iced::Length::Fill)
@@ -121,3 +217,22 @@ where
         self.set_window_title(window_title)
     }
 }
+
+/// Commands which are suggested while typing in the command input.
+const COMMANDS: &[&str] = &[
+    "cosmic-edit",
+    "cosmic-files",
+    "cosmic-settings",
+    "cosmic-term",
+];
+
+/// Simulates checking whether a username is available with a remote service.
+async fn username_available(username: &str) -> Result<(), String> {
+    if username.is_empty() {
+        Err(String::from("Username must not be empty"))
+    } else if ["admin", "root", "system76"].contains(&username) {
+        Err(format!("{username} is already taken"))
+    } else {
+        Ok(())
+    }
+}
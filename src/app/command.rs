@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use iced::window;
+use iced_futures::futures::Stream;
 
 /// Asynchronous actions for COSMIC applications.
 use super::Message;
@@ -28,6 +29,35 @@ pub mod message {
 }
 
 impl crate::app::Core {
+    /// Spawns a background worker which yields application messages from a stream.
+    ///
+    /// Unlike [`Application::subscription`](crate::Application::subscription), workers may
+    /// be started on demand, such as when a button is pressed. The worker runs until its
+    /// stream ends, or until it is stopped with [`Handle::abort`](iced::task::Handle::abort).
+    ///
+    /// ```no_run,ignore
+    /// let (task, handle) = self.core.spawn_worker(index_files(path));
+    /// self.indexer = Some(handle);
+    /// return task;
+    /// ```
+    pub fn spawn_worker<M: Send + 'static>(
+        &self,
+        worker: impl Stream<Item = M> + Send + 'static,
+    ) -> (iced::Task<Message<M>>, iced::task::Handle) {
+        crate::task::stream(worker).abortable()
+    }
+
+    /// Closes a window, or the main window if `id` is `None`.
+    ///
+    /// Unlike a close request from the window manager, this does not consult
+    /// [`Application::confirm_close`](crate::Application::confirm_close).
+    pub fn close<M: Send + 'static>(&self, id: Option<window::Id>) -> iced::Task<Message<M>> {
+        let Some(id) = id.or(self.main_window) else {
+            return iced::Task::none();
+        };
+        crate::task::close(id).map(Message::Cosmic)
+    }
+
     pub fn drag<M: Send + 'static>(&self, id: Option<window::Id>) -> iced::Task<Message<M>> {
         let Some(id) = id.or(self.main_window) else {
             return iced::Task::none();
@@ -35,6 +65,14 @@ impl crate::app::Core {
         crate::task::drag(id).map(Message::Cosmic)
     }
 
+    /// Focuses a window, or the main window if `id` is `None`.
+    pub fn focus<M: Send + 'static>(&self, id: Option<window::Id>) -> iced::Task<Message<M>> {
+        let Some(id) = id.or(self.main_window) else {
+            return iced::Task::none();
+        };
+        crate::task::focus(id).map(Message::Cosmic)
+    }
+
     pub fn maximize<M: Send + 'static>(
         &self,
         id: Option<window::Id>,
@@ -53,10 +91,20 @@ impl crate::app::Core {
         crate::task::minimize(id).map(Message::Cosmic)
     }
 
+    /// Sets the scaling factor of all windows.
     pub fn set_scaling_factor<M: Send + 'static>(&self, factor: f32) -> iced::Task<Message<M>> {
         message::cosmic(super::cosmic::Message::ScaleFactor(factor))
     }
 
+    /// Sets the scaling factor of a single window.
+    pub fn set_window_scaling_factor<M: Send + 'static>(
+        &self,
+        id: window::Id,
+        factor: f32,
+    ) -> iced::Task<Message<M>> {
+        message::cosmic(super::cosmic::Message::WindowScaleFactor(id, factor))
+    }
+
     pub fn set_title<M: Send + 'static>(
         &self,
         id: Option<window::Id>,
@@ -87,6 +135,48 @@ impl crate::app::Core {
         };
         crate::task::toggle_maximize(id).map(Message::Cosmic)
     }
+
+    /// Switches the theme of the application to a light, dark, or high contrast mode.
+    ///
+    /// Light and dark modes use the system theme, and keep the mode until it is changed again.
+    /// If `write_system` is set, the dark mode preference of the system is updated as well,
+    /// and the application goes on following the system preference.
+    pub fn set_theme_mode<M: Send + 'static>(
+        &self,
+        mode: crate::theme::Mode,
+        write_system: bool,
+    ) -> iced::Task<Message<M>> {
+        use crate::theme::Mode;
+        use cosmic_config::ConfigSet;
+
+        if write_system {
+            if let Err(why) = cosmic_theme::ThemeMode::config()
+                .and_then(|config| config.set("is_dark", mode.is_dark()))
+            {
+                tracing::error!(?why, "failed to write the system theme mode");
+            }
+        }
+
+        let theme = match mode {
+            Mode::HighContrastLight => crate::Theme::light_hc(),
+            Mode::HighContrastDark => crate::Theme::dark_hc(),
+            Mode::Light | Mode::Dark => {
+                let mut theme = if mode.is_dark() {
+                    crate::theme::system_dark()
+                } else {
+                    crate::theme::system_light()
+                };
+
+                if !write_system {
+                    theme.theme_type.prefer_dark(Some(mode.is_dark()));
+                }
+
+                theme
+            }
+        };
+
+        set_theme(theme)
+    }
 }
 
 pub fn set_theme<M: Send + 'static>(theme: crate::Theme) -> iced::Task<Message<M>> {
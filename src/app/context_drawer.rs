@@ -20,6 +20,20 @@ pub fn about<'a, Message: Clone + 'static>(
     context_drawer(crate::widget::about(about, on_url_press), on_close)
 }
 
+/// An about context drawer, with a button to copy its debug info.
+#[cfg(feature = "about")]
+pub fn about_with_copy<'a, Message: Clone + 'static>(
+    about: &'a crate::widget::about::About,
+    on_url_press: impl Fn(String) -> Message,
+    on_copy: impl Fn(String) -> Message,
+    on_close: Message,
+) -> ContextDrawer<'a, Message> {
+    context_drawer(
+        crate::widget::about::about_with_copy(about, on_url_press, on_copy),
+        on_close,
+    )
+}
+
 pub fn context_drawer<'a, Message: Clone + 'static>(
     content: impl Into<Element<'a, Message>>,
     on_close: Message,
@@ -59,4 +73,23 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
         self.footer = Some(footer.into());
         self
     }
+
+    /// Maps the messages of the context drawer to another message type.
+    pub fn map<Out: Clone + 'static>(
+        self,
+        on_message: impl Fn(Message) -> Out + Clone + 'a,
+    ) -> ContextDrawer<'a, Out> {
+        ContextDrawer {
+            title: self.title,
+            header_actions: self
+                .header_actions
+                .into_iter()
+                .map(|element| element.map(on_message.clone()))
+                .collect(),
+            header: self.header.map(|element| element.map(on_message.clone())),
+            content: self.content.map(on_message.clone()),
+            footer: self.footer.map(|element| element.map(on_message.clone())),
+            on_close: on_message(self.on_close),
+        }
+    }
 }
@@ -6,13 +6,19 @@ use std::{cell::OnceCell, collections::HashMap};
 use crate::widget::nav_bar;
 use cosmic_config::CosmicConfigEntry;
 use cosmic_theme::ThemeMode;
-use iced::window;
+use iced::{window, Rectangle};
 use iced_core::window::Id;
 use palette::Srgba;
 use slotmap::Key;
 
 use crate::Theme;
 
+/// Minimum width of the context drawer.
+const CONTEXT_DRAWER_MIN_WIDTH: f32 = 344.0;
+
+/// Default maximum width of the context drawer, unless it has been resized.
+const CONTEXT_DRAWER_MAX_WIDTH: f32 = 480.0;
+
 /// Status of the nav bar and its panels.
 #[derive(Clone)]
 pub struct NavBar {
@@ -31,6 +37,8 @@ pub struct Window {
     pub use_template: bool,
     pub content_container: bool,
     pub context_is_overlay: bool,
+    /// Accepts files dropped onto the window, which are passed to `Application::on_drag_drop`.
+    pub accept_file_drops: bool,
     pub sharp_corners: bool,
     pub show_context: bool,
     pub show_headerbar: bool,
@@ -63,9 +71,24 @@ pub struct Core {
     /// Scaling factor used by the application
     scale_factor: f32,
 
+    /// Scaling factors of windows which differ from the application's.
+    window_scale_factors: HashMap<Id, f32>,
+
+    /// Width of the context drawer chosen by resizing it.
+    context_drawer_width: Option<f32>,
+
     /// Window focus state
     pub(super) focused_window: Option<window::Id>,
 
+    /// Whether the application was showing a dialog when last updated.
+    pub(super) dialog_shown: bool,
+
+    /// Widget which was focused before the dialog was shown, to be refocused once it closes.
+    pub(super) focus_before_dialog: Option<iced_core::widget::Id>,
+
+    /// Whether files are being dragged over the window.
+    pub(super) file_drop_hovered: bool,
+
     pub(super) theme_sub_counter: u64,
     /// Last known system theme
     pub(super) system_theme: Theme,
@@ -111,6 +134,8 @@ impl Default for Core {
                 toggled_condensed: false,
             },
             scale_factor: 1.0,
+            window_scale_factors: HashMap::new(),
+            context_drawer_width: None,
             title: HashMap::new(),
             theme_sub_counter: 0,
             system_theme: crate::theme::active(),
@@ -129,6 +154,7 @@ impl Default for Core {
                 use_template: true,
                 content_container: true,
                 context_is_overlay: true,
+                accept_file_drops: false,
                 sharp_corners: false,
                 show_context: false,
                 show_headerbar: true,
@@ -140,6 +166,9 @@ impl Default for Core {
                 width: 0.,
             },
             focused_window: None,
+            dialog_shown: false,
+            focus_before_dialog: None,
+            file_drop_hovered: false,
             #[cfg(feature = "applet")]
             applet: crate::applet::Context::default(),
             #[cfg(feature = "single-instance")]
@@ -168,6 +197,15 @@ impl Core {
         self.scale_factor
     }
 
+    /// The scaling factor used by a window.
+    #[must_use]
+    pub fn window_scale_factor(&self, id: Id) -> f32 {
+        self.window_scale_factors
+            .get(&id)
+            .copied()
+            .unwrap_or(self.scale_factor)
+    }
+
     /// Enable or disable keyboard navigation
     pub fn set_keyboard_nav(&mut self, enabled: bool) {
         self.keyboard_nav = enabled;
@@ -182,9 +220,27 @@ impl Core {
     /// Changes the scaling factor used by the application.
     pub(crate) fn set_scale_factor(&mut self, factor: f32) {
         self.scale_factor = factor;
+        self.window_scale_factors.clear();
         self.is_condensed_update();
     }
 
+    /// Changes the scaling factor used by a window.
+    pub(crate) fn set_window_scale_factor(&mut self, id: Id, factor: f32) {
+        self.window_scale_factors.insert(id, factor);
+        self.is_condensed_update();
+    }
+
+    /// Forgets the scaling factor of a window which was closed.
+    pub(crate) fn remove_window_scale_factor(&mut self, id: Id) {
+        self.window_scale_factors.remove(&id);
+    }
+
+    /// The scaling factor used by the main window.
+    fn main_window_scale_factor(&self) -> f32 {
+        self.main_window_id()
+            .map_or(self.scale_factor, |id| self.window_scale_factor(id))
+    }
+
     /// Set header bar title
     pub fn set_header_title(&mut self, title: String) {
         self.window.header_title = title;
@@ -205,7 +261,8 @@ impl Core {
             // Context drawer min width (344px) + padding (8px)
             breakpoint += 344.0 + 8.0;
         };
-        self.is_condensed = (breakpoint * self.scale_factor) > self.window.width as f32;
+        self.is_condensed =
+            (breakpoint * self.main_window_scale_factor()) > self.window.width as f32;
         self.nav_bar_update();
     }
 
@@ -217,8 +274,21 @@ impl Core {
             && !self.window.context_is_overlay
     }
 
+    /// Width of the context drawer chosen by resizing it, if it was resized.
+    #[must_use]
+    pub fn context_drawer_width(&self) -> Option<f32> {
+        self.context_drawer_width
+    }
+
+    /// Sets the width of the context drawer, such as to restore a width that was persisted.
+    ///
+    /// The width is clamped to the space available in the window when the drawer is displayed.
+    pub fn set_context_drawer_width(&mut self, width: Option<f32>) {
+        self.context_drawer_width = width.map(|width| width.max(CONTEXT_DRAWER_MIN_WIDTH));
+    }
+
     pub(crate) fn context_width(&self, has_nav: bool) -> f32 {
-        let window_width = (self.window.width as f32) / self.scale_factor;
+        let window_width = (self.window.width as f32) / self.main_window_scale_factor();
 
         // Content width (360px) + padding (8px)
         let mut reserved_width = 360.0 + 8.0;
@@ -227,10 +297,82 @@ impl Core {
             reserved_width += 280.0 + 8.0;
         }
 
+        let available_width = window_width - reserved_width;
+
+        // A resized drawer may grow as wide as the space available, but no smaller than the minimum.
+        if let Some(width) = self.context_drawer_width {
+            return width.min(available_width).max(CONTEXT_DRAWER_MIN_WIDTH);
+        }
+
         // This logic is to ensure the context drawer does not take up too much of the content's space
         // The minimum width is 344px and the maximum with is 480px
         // We want to keep the content at least 360px until going down to the minimum width
-        (window_width - reserved_width).min(480.0).max(344.0)
+        available_width
+            .min(CONTEXT_DRAWER_MAX_WIDTH)
+            .max(CONTEXT_DRAWER_MIN_WIDTH)
+    }
+
+    /// The region of the main window in which the application's view is displayed.
+    ///
+    /// This excludes the header bar, the nav bar, and a context drawer beside the content,
+    /// along with the padding between them. Overlays such as popups may be clamped to it,
+    /// instead of to the dimensions of the window. `has_nav` is whether the application
+    /// has a nav bar model, which [`crate::ApplicationExt::content_bounds`] passes for you.
+    ///
+    /// The nav bar is assumed to be displayed at its full width, and footers are not excluded.
+    #[must_use]
+    pub fn content_bounds(&self, has_nav: bool) -> Rectangle {
+        let scale_factor = self.main_window_scale_factor();
+        let has_nav = has_nav && self.nav_bar.active;
+
+        // Border around the window, unless its corners are sharp.
+        let border = if self.window.sharp_corners { 0.0 } else { 1.0 };
+        let header = if self.window.show_headerbar {
+            crate::widget::header_bar::height(crate::config::header_size())
+        } else {
+            0.0
+        };
+
+        let mut bounds = Rectangle {
+            x: border,
+            y: border + header,
+            width: self.window.width / scale_factor - 2.0 * border,
+            height: self.window.height / scale_factor - 2.0 * border - header,
+        };
+
+        // The content is hidden while the nav bar is shown in a condensed window.
+        if has_nav && !self.show_content() {
+            bounds.width = 0.0;
+            return bounds;
+        }
+
+        if has_nav {
+            // Navbar width (280px) + padding (8px) on both sides
+            bounds.x += 280.0 + 16.0;
+            bounds.width -= 280.0 + 16.0;
+        }
+
+        if self.window.content_container {
+            // The padding before the content is shared with the nav bar.
+            let start = if has_nav { 0.0 } else { 8.0 };
+            bounds.x += start;
+            bounds.width -= start + 8.0;
+            bounds.height -= 8.0;
+        }
+
+        if self.window.show_context && !self.window.context_is_overlay {
+            bounds.width -= self.context_width(has_nav)
+                + crate::widget::context_drawer::RESIZE_HANDLE_WIDTH
+                + if self.window.content_container {
+                    8.0
+                } else {
+                    0.0
+                };
+        }
+
+        bounds.width = bounds.width.max(0.0);
+        bounds.height = bounds.height.max(0.0);
+        bounds
     }
 
     pub fn set_show_context(&mut self, show: bool) {
@@ -249,6 +391,23 @@ impl Core {
         self.nav_bar.active
     }
 
+    /// Whether the nav bar is shown when there is room for it, such as to persist the choice.
+    #[must_use]
+    pub fn nav_bar_toggled(&self) -> bool {
+        self.nav_bar.toggled
+    }
+
+    /// Restores whether the nav bar is shown when there is room for it, such as from a
+    /// persisted choice.
+    ///
+    /// Unlike [`Core::nav_bar_set_toggled`], this does not open the nav bar over the
+    /// content when the window is too narrow to show both.
+    pub fn nav_bar_restore_toggled(&mut self, toggled: bool) {
+        self.nav_bar.toggled = toggled;
+        self.nav_bar.toggled_condensed = false;
+        self.nav_bar_update();
+    }
+
     pub fn nav_bar_toggle(&mut self) {
         self.nav_bar.toggled = !self.nav_bar.toggled;
         self.nav_bar_set_toggled_condensed(self.nav_bar.toggled);
@@ -310,6 +469,14 @@ impl Core {
         &self.system_theme
     }
 
+    /// Whether the active theme is light or dark, and whether it has a high contrast.
+    ///
+    /// Use [`Core::set_theme_mode`] to change it.
+    #[must_use]
+    pub fn theme_mode(&self) -> crate::theme::Mode {
+        crate::theme::active_type().mode()
+    }
+
     #[must_use]
     /// Get the current system theme mode
     pub fn system_theme_mode(&self) -> ThemeMode {
@@ -350,6 +517,13 @@ impl Core {
         )
     }
 
+    /// Watches for changes to the COSMIC theme of the current system theme mode.
+    ///
+    /// Rapid changes to the theme are coalesced into a single update.
+    pub fn theme_subscription(&self) -> iced::Subscription<cosmic_theme::Theme> {
+        crate::theme::cosmic_theme_subscription(self.system_is_dark())
+    }
+
     /// Get the current focused window if it exists
     #[must_use]
     pub fn focused_window(&self) -> Option<window::Id> {
@@ -29,6 +29,10 @@ pub enum Message {
     Close,
     /// Closes or shows the context drawer.
     ContextDrawer(bool),
+    /// Resizes the context drawer.
+    ContextDrawerResize(f32),
+    /// The context drawer has finished being resized.
+    ContextDrawerResized(f32),
     /// Requests to drag the window.
     Drag,
     /// Keyboard shortcuts managed by libcosmic.
@@ -41,12 +45,18 @@ pub enum Message {
     NavBar(nav_bar::Id),
     /// Activates a context menu for an item from the nav bar.
     NavBarContext(nav_bar::Id),
+    /// Expands or collapses a group in the nav bar.
+    NavBarExpand(nav_bar::Id, bool),
     /// Set scaling factor
     ScaleFactor(f32),
+    /// Set scaling factor of a window
+    WindowScaleFactor(window::Id, f32),
     /// Notification of system theme changes.
     SystemThemeChange(Vec<&'static str>, Theme),
     /// Notification of system theme mode changes.
     SystemThemeModeChange(Vec<&'static str>, ThemeMode),
+    /// Thumbnails of image buttons were decoded, and may now be shown.
+    ThumbnailsDecoded,
     /// Toggles visibility of the nav bar.
     ToggleNavBar,
     /// Toggles the condensed status of the nav bar.
@@ -63,6 +73,14 @@ pub enum Message {
     /// Capabilities the window manager supports
     #[cfg(feature = "wayland")]
     WmCapabilities(window::Id, WindowManagerCapabilities),
+    /// Files were dropped onto the window.
+    FileDrop(Vec<std::path::PathBuf>, iced::Point),
+    /// Whether files are being dragged over the window.
+    FileDropHover(bool),
+    /// Requests to close a window, which the application may veto.
+    WindowCloseRequested(window::Id),
+    /// Whether the application confirmed that a window should close.
+    WindowCloseConfirmed(window::Id, bool),
     /// Notifies that a surface was closed.
     /// Any data relating to the surface should be cleaned up.
     SurfaceClosed(window::Id),
@@ -73,6 +91,10 @@ pub enum Message {
     DesktopSettings(crate::theme::portal::Desktop),
     /// Window focus changed
     Focus(window::Id),
+    /// Records the widget that was focused before a dialog was shown.
+    FocusBeforeDialog(iced_core::widget::Id),
+    /// A dialog was shown or hidden.
+    DialogShown(bool),
     /// Window focus lost
     Unfocus(window::Id),
     /// Tracks updates to window suggested size.
@@ -126,14 +148,43 @@ where
         }
     }
 
+    /// Remembers the focused widget when a dialog is shown, and refocuses it when the dialog closes.
+    fn dialog_focus_update(
+        &mut self,
+        dialog_shown: bool,
+    ) -> iced::Task<super::Message<T::Message>> {
+        use iced_core::widget::operation::focusable;
+
+        let core = self.app.core_mut();
+
+        if core.dialog_shown == dialog_shown {
+            return iced::Task::none();
+        }
+
+        core.dialog_shown = dialog_shown;
+
+        if dialog_shown {
+            core.focus_before_dialog = None;
+            return iced_runtime::task::widget(focusable::find_focused())
+                .map(|id| super::Message::Cosmic(Message::FocusBeforeDialog(id)));
+        }
+
+        match core.focus_before_dialog.take() {
+            Some(id) => {
+                iced_runtime::task::effect(iced_runtime::Action::widget(focusable::focus(id)))
+            }
+            None => iced::Task::none(),
+        }
+    }
+
     #[cfg(not(feature = "multi-window"))]
     pub fn scale_factor(&self) -> f64 {
         f64::from(self.app.core().scale_factor())
     }
 
     #[cfg(feature = "multi-window")]
-    pub fn scale_factor(&self, _id: window::Id) -> f64 {
-        f64::from(self.app.core().scale_factor())
+    pub fn scale_factor(&self, id: window::Id) -> f64 {
+        f64::from(self.app.core().window_scale_factor(id))
     }
 
     pub fn style(&self, theme: &Theme) -> iced_runtime::Appearance {
@@ -159,6 +210,9 @@ where
                 iced::Event::Window(window::Event::Resized(iced::Size { width, height })) => {
                     return Some(Message::WindowResize(id, width, height));
                 }
+                iced::Event::Window(window::Event::CloseRequested) => {
+                    return Some(Message::WindowCloseRequested(id));
+                }
                 iced::Event::Window(window::Event::Closed) => {
                     return Some(Message::SurfaceClosed(id));
                 }
@@ -236,6 +290,8 @@ where
                 })
                 .map(super::Message::Cosmic),
             window_events.map(super::Message::Cosmic),
+            crate::widget::button::thumbnail::subscription()
+                .map(|()| super::Message::Cosmic(Message::ThumbnailsDecoded)),
             #[cfg(feature = "xdg-portal")]
             crate::theme::portal::desktop_settings()
                 .map(Message::DesktopSettings)
@@ -302,6 +358,15 @@ impl<T: Application> Cosmic<T> {
         }
     }
 
+    /// Closes the window, unless the application vetoes it.
+    fn request_close(&mut self, id: window::Id) -> iced::Task<super::Message<T::Message>> {
+        match self.app.confirm_close(id) {
+            Some(confirmation) => confirmation
+                .map(move |close| super::Message::Cosmic(Message::WindowCloseConfirmed(id, close))),
+            None => iced::window::close(id),
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     fn cosmic_update(&mut self, message: Message) -> iced::Task<super::Message<T::Message>> {
         match message {
@@ -373,11 +438,22 @@ impl<T: Application> Cosmic<T> {
             }
 
             Message::KeyboardNav(message) => match message {
+                // Focus is trapped within the dialog while it is shown.
                 keyboard_nav::Message::FocusNext => {
-                    return iced::widget::focus_next().map(super::Message::Cosmic)
+                    if self.app.core().dialog_shown {
+                        return keyboard_nav::focus_next_within(iced_core::id::Id::new(
+                            "COSMIC_dialog",
+                        ));
+                    }
+                    return iced::widget::focus_next().map(super::Message::Cosmic);
                 }
                 keyboard_nav::Message::FocusPrevious => {
-                    return iced::widget::focus_previous().map(super::Message::Cosmic)
+                    if self.app.core().dialog_shown {
+                        return keyboard_nav::focus_previous_within(iced_core::id::Id::new(
+                            "COSMIC_dialog",
+                        ));
+                    }
+                    return iced::widget::focus_previous().map(super::Message::Cosmic);
                 }
                 keyboard_nav::Message::Escape => return self.app.on_escape(),
                 keyboard_nav::Message::Search => return self.app.on_search(),
@@ -390,6 +466,17 @@ impl<T: Application> Cosmic<T> {
                 return self.app.on_context_drawer();
             }
 
+            Message::ContextDrawerResize(width) => {
+                self.app.core_mut().set_context_drawer_width(Some(width));
+            }
+
+            Message::ContextDrawerResized(width) => {
+                let core = self.app.core_mut();
+                core.set_context_drawer_width(Some(width));
+                let width = core.context_drawer_width().unwrap_or(width);
+                return self.app.on_context_drawer_resize(width);
+            }
+
             Message::Drag => return self.app.core().drag(None),
 
             Message::Minimize => return self.app.core().minimize(None),
@@ -406,8 +493,14 @@ impl<T: Application> Cosmic<T> {
                 return self.app.on_nav_context(key);
             }
 
+            Message::NavBarExpand(key, expanded) => {
+                return self.app.on_nav_expand(key, expanded);
+            }
+
             Message::ToggleNavBar => {
                 self.app.core_mut().nav_bar_toggle();
+                let toggled = self.app.core().nav_bar_toggled();
+                return self.app.on_nav_bar_toggle(toggled);
             }
 
             Message::ToggleNavBarCondensed => {
@@ -472,12 +565,46 @@ impl<T: Application> Cosmic<T> {
                 self.app.core_mut().set_scale_factor(factor);
             }
 
+            Message::WindowScaleFactor(id, factor) => {
+                self.app.core_mut().set_window_scale_factor(id, factor);
+            }
+
             Message::Close => {
                 return match self.app.on_app_exit() {
                     Some(message) => self.app.update(message),
-                    None => self.close(),
+                    None => match self.app.core().main_window_id() {
+                        Some(id) => self.request_close(id),
+                        None => iced::Task::none(),
+                    },
                 };
             }
+
+            Message::WindowCloseRequested(id) => {
+                let core = self.app.core();
+
+                // Only the main window is closed on request, if configured to do so.
+                if core.exit_on_main_window_closed && core.main_window_id() == Some(id) {
+                    return self.request_close(id);
+                }
+            }
+
+            Message::FileDrop(paths, position) => {
+                self.app.core_mut().file_drop_hovered = false;
+                return self.app.on_drag_drop(paths, position);
+            }
+
+            Message::FileDropHover(hovered) => {
+                self.app.core_mut().file_drop_hovered = hovered;
+            }
+
+            // The view is rebuilt after each message, which replaces their placeholders.
+            Message::ThumbnailsDecoded => {}
+
+            Message::WindowCloseConfirmed(id, close) => {
+                if close {
+                    return iced::window::close(id);
+                }
+            }
             Message::SystemThemeModeChange(keys, mode) => {
                 if !keys.contains(&"is_dark") {
                     return iced::Task::none();
@@ -542,6 +669,7 @@ impl<T: Application> Cosmic<T> {
                 }
             }
             Message::SurfaceClosed(id) => {
+                self.app.core_mut().remove_window_scale_factor(id);
                 let mut ret = if let Some(msg) = self.app.on_close_requested(id) {
                     self.app.update(msg)
                 } else {
@@ -647,6 +775,15 @@ impl<T: Application> Cosmic<T> {
 
             Message::Focus(f) => {
                 self.app.core_mut().focused_window = Some(f);
+                return self.app.on_window_focus(f, true);
+            }
+
+            Message::DialogShown(shown) => {
+                return self.dialog_focus_update(shown);
+            }
+
+            Message::FocusBeforeDialog(id) => {
+                self.app.core_mut().focus_before_dialog = Some(id);
             }
 
             Message::Unfocus(id) => {
@@ -654,6 +791,7 @@ impl<T: Application> Cosmic<T> {
                 if core.focused_window.as_ref().is_some_and(|cur| *cur == id) {
                     core.focused_window = None;
                 }
+                return self.app.on_window_focus(id, false);
             }
             #[cfg(feature = "applet")]
             Message::SuggestedBounds(b) => {
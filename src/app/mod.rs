@@ -13,6 +13,7 @@ pub mod cosmic;
 #[cfg(all(feature = "winit", feature = "multi-window"))]
 pub(crate) mod multi_window;
 pub mod settings;
+pub mod windows;
 
 pub mod message {
     #[derive(Clone, Debug)]
@@ -53,6 +54,7 @@ use std::borrow::Cow;
 pub use self::command::Task;
 pub use self::core::Core;
 pub use self::settings::Settings;
+pub use self::windows::Windows;
 use crate::prelude::*;
 use crate::theme::THEME;
 use crate::widget::{container, horizontal_space, id_container, menu, nav_bar, popover};
@@ -105,7 +107,8 @@ pub(crate) fn iced_settings<App: Application>(
     iced.is_daemon = false;
     iced.exit_on_close_request = settings.is_daemon;
     let mut window_settings = iced::window::Settings::default();
-    window_settings.exit_on_close_request = exit_on_close;
+    // Close requests are handled by COSMIC so that the application may veto them.
+    window_settings.exit_on_close_request = false;
     iced.id = Some(App::APP_ID.to_owned());
     #[cfg(target_os = "linux")]
     {
@@ -497,11 +500,22 @@ where
         let mut nav =
             crate::widget::nav_bar(nav_model, |id| Message::Cosmic(cosmic::Message::NavBar(id)))
                 .on_context(|id| Message::Cosmic(cosmic::Message::NavBarContext(id)))
-                .context_menu(self.nav_context_menu(self.core().nav_bar_context()))
-                .into_container()
-                // XXX both must be shrink to avoid flex layout from ignoring it
-                .width(iced::Length::Shrink)
-                .height(iced::Length::Shrink);
+                .on_expand(|id, expanded| {
+                    Message::Cosmic(cosmic::Message::NavBarExpand(id, expanded))
+                })
+                .context_menu(self.nav_context_menu(self.core().nav_bar_context()));
+
+        for id in nav_model.iter() {
+            if let Some(element) = self.nav_trailing(id) {
+                nav = nav.trailing(id, element.map(Message::App));
+            }
+        }
+
+        let mut nav = nav
+            .into_container()
+            // XXX both must be shrink to avoid flex layout from ignoring it
+            .width(iced::Length::Shrink)
+            .height(iced::Length::Shrink);
 
         if !self.core().is_condensed() {
             nav = nav.max_width(280);
@@ -511,10 +525,19 @@ where
     }
 
     /// Shows a context menu for the active nav bar item.
+    ///
+    /// The menu opens on a right click, or with the menu key or Shift+F10 while the nav bar is focused.
     fn nav_context_menu(&self, id: nav_bar::Id) -> Option<Vec<menu::Tree<Message<Self::Message>>>> {
         None
     }
 
+    /// Attaches a small widget, such as a button or progress ring, to the end of a nav bar item.
+    ///
+    /// Clicks on the widget emit its own messages, rather than activating the item.
+    fn nav_trailing(&self, id: nav_bar::Id) -> Option<Element<Self::Message>> {
+        None
+    }
+
     /// Allows COSMIC to integrate with your application's [`nav_bar::Model`].
     fn nav_model(&self) -> Option<&nav_bar::Model> {
         None
@@ -530,21 +553,69 @@ where
         None
     }
 
+    /// Called before the main window is closed, allowing the close to be vetoed.
+    ///
+    /// Returning a task defers closing the window until the task yields `true`, or
+    /// cancels it if the task yields `false`. This may be used to await a confirmation
+    /// dialog, such as when there are unsaved changes. The window closes immediately
+    /// when `None` is returned.
+    fn confirm_close(&mut self, id: window::Id) -> Option<iced::Task<bool>> {
+        None
+    }
+
     // Called when context drawer is toggled
     fn on_context_drawer(&mut self) -> Task<Self::Message> {
         Task::none()
     }
 
+    /// Called when the user has finished resizing the context drawer, such as to persist its width.
+    fn on_context_drawer_resize(&mut self, width: f32) -> Task<Self::Message> {
+        Task::none()
+    }
+
+    /// Called when the nav bar is shown or hidden by the user, such as to persist the choice.
+    ///
+    /// The choice may be stored with `cosmic_config`, and restored on launch with
+    /// [`Core::nav_bar_restore_toggled`].
+    fn on_nav_bar_toggle(&mut self, toggled: bool) -> Task<Self::Message> {
+        Task::none()
+    }
+
+    /// Called when files are dropped onto the window, at the position they were dropped.
+    ///
+    /// Files are only accepted if `core.window.accept_file_drops` is enabled.
+    fn on_drag_drop(
+        &mut self,
+        paths: Vec<std::path::PathBuf>,
+        position: iced::Point,
+    ) -> Task<Self::Message> {
+        Task::none()
+    }
+
     /// Called when the escape key is pressed.
     fn on_escape(&mut self) -> Task<Self::Message> {
         Task::none()
     }
 
+    /// Called when a window of the application gains or loses focus.
+    ///
+    /// Use this to pause work which is unnecessary while the application is in the background.
+    fn on_window_focus(&mut self, id: window::Id, focused: bool) -> Task<Self::Message> {
+        Task::none()
+    }
+
     /// Called when a navigation item is selected.
     fn on_nav_select(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
         Task::none()
     }
 
+    /// Called when a group of navigation items is expanded or collapsed.
+    ///
+    /// Apply the change with [`nav_bar::Model::expand`].
+    fn on_nav_expand(&mut self, id: nav_bar::Id, expanded: bool) -> Task<Self::Message> {
+        Task::none()
+    }
+
     /// Called when a context menu is requested for a navigation item.
     fn on_nav_context(&mut self, id: nav_bar::Id) -> Task<Self::Message> {
         Task::none()
@@ -590,8 +661,11 @@ where
     fn view(&self) -> Element<Self::Message>;
 
     /// Constructs views for other windows.
+    ///
+    /// An empty view is displayed for windows which the application has no view for.
     fn view_window(&self, id: window::Id) -> Element<Self::Message> {
-        panic!("no view for window {id:?}");
+        tracing::debug!(?id, "no view for window");
+        horizontal_space().width(Length::Shrink).into()
     }
 
     /// Overrides the default style for applications
@@ -625,6 +699,13 @@ pub trait ApplicationExt: Application {
     /// Get the title of a window.
     fn title(&self, id: window::Id) -> &str;
 
+    /// The region of the main window in which the application's view is displayed.
+    ///
+    /// See [`Core::content_bounds`].
+    fn content_bounds(&self) -> iced::Rectangle {
+        self.core().content_bounds(self.nav_model().is_some())
+    }
+
     /// Set the context drawer visibility.
     fn set_show_context(&mut self, show: bool) {
         self.core_mut().set_show_context(show);
@@ -732,8 +813,10 @@ impl<App: Application> ApplicationExt for App {
 
                 //TODO: reduce duplication
                 let context_width = core.context_width(has_nav);
+
                 if core.window.context_is_overlay && core.window.show_context {
                     if let Some(context) = self.context_drawer() {
+                        let context = context.map(Message::App);
                         widgets.push(
                             crate::widget::context_drawer(
                                 context.title,
@@ -741,10 +824,16 @@ impl<App: Application> ApplicationExt for App {
                                 context.header,
                                 context.footer,
                                 context.on_close,
-                                main_content,
+                                main_content.map(Message::App),
                                 context.content,
                                 context_width,
                             )
+                            .on_resize(|width| {
+                                Message::Cosmic(cosmic::Message::ContextDrawerResize(width))
+                            })
+                            .on_resize_end(|width| {
+                                Message::Cosmic(cosmic::Message::ContextDrawerResized(width))
+                            })
                             .apply(|drawer| {
                                 Element::from(id_container(
                                     drawer,
@@ -757,8 +846,7 @@ impl<App: Application> ApplicationExt for App {
                             } else {
                                 [0, 0, 0, 0]
                             })
-                            .apply(Element::from)
-                            .map(Message::App),
+                            .into(),
                         );
                     } else {
                         //TODO: container and padding are temporary, until
@@ -779,8 +867,9 @@ impl<App: Application> ApplicationExt for App {
                             .into(),
                     );
                     if let Some(context) = self.context_drawer() {
+                        let context = context.map(Message::App);
                         widgets.push(
-                            crate::widget::ContextDrawer::new_inner(
+                            crate::widget::ContextDrawer::new_inner_resizable(
                                 context.title,
                                 context.header_actions,
                                 context.header,
@@ -788,11 +877,17 @@ impl<App: Application> ApplicationExt for App {
                                 context.content,
                                 context.on_close,
                                 context_width,
+                                Box::new(|width| {
+                                    Message::Cosmic(cosmic::Message::ContextDrawerResize(width))
+                                }),
+                                Some(Box::new(|width| {
+                                    Message::Cosmic(cosmic::Message::ContextDrawerResized(width))
+                                })),
                             )
-                            .apply(Element::from)
-                            .map(Message::App)
                             .apply(container)
-                            .width(context_width)
+                            .width(
+                                context_width + crate::widget::context_drawer::RESIZE_HANDLE_WIDTH,
+                            )
                             .apply(|drawer| {
                                 Element::from(id_container(
                                     drawer,
@@ -834,6 +929,22 @@ impl<App: Application> ApplicationExt for App {
             content_col.into()
         };
 
+        let content = if core.window.accept_file_drops {
+            crate::widget::dnd_destination(content, vec![Cow::Borrowed("text/uri-list")])
+                .on_enter(|_, _, _| Message::Cosmic(cosmic::Message::FileDropHover(true)))
+                .on_leave(|| Message::Cosmic(cosmic::Message::FileDropHover(false)))
+                .on_finish(|_mime, data, _action, x, y| {
+                    let position = iced::Point::new(x as f32, y as f32);
+                    Message::Cosmic(cosmic::Message::FileDrop(file_paths(&data), position))
+                })
+                .into()
+        } else {
+            content
+        };
+
+        // Highlights the window border while files are dragged over it.
+        let file_drop_hovered = core.file_drop_hovered;
+
         let view_column = crate::widget::column::with_capacity(2)
             .push_maybe(if core.window.show_headerbar {
                 Some({
@@ -923,7 +1034,11 @@ impl<App: Application> ApplicationExt for App {
                         None
                     },
                     border: iced::Border {
-                        color: theme.cosmic().bg_divider().into(),
+                        color: if file_drop_hovered {
+                            theme.cosmic().accent_color().into()
+                        } else {
+                            theme.cosmic().bg_divider().into()
+                        },
                         width: if sharp_corners { 0.0 } else { 1.0 },
                         radius: theme.cosmic().radius_s().into(),
                     },
@@ -933,7 +1048,10 @@ impl<App: Application> ApplicationExt for App {
 
         // Show any current dialog on top and centered over the view content
         // We have to use a popover even without a dialog to keep the tree from changing
-        let mut popover = popover(view_column).modal(true);
+        // The popover reports when the dialog is shown, so that focus may be restored after.
+        let mut popover = popover(view_column)
+            .modal(true)
+            .on_popup(|shown| Message::Cosmic(cosmic::Message::DialogShown(shown)));
         if let Some(dialog) = self
             .dialog()
             .map(|w| Element::from(id_container(w, iced_core::id::Id::new("COSMIC_dialog"))))
@@ -946,6 +1064,16 @@ impl<App: Application> ApplicationExt for App {
     }
 }
 
+/// Paths of the local files in a `text/uri-list`.
+fn file_paths(uri_list: &[u8]) -> Vec<std::path::PathBuf> {
+    String::from_utf8_lossy(uri_list)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| Url::parse(line).ok()?.to_file_path().ok())
+        .collect()
+}
+
 #[cfg(feature = "single-instance")]
 fn single_instance_subscription<App: ApplicationExt>() -> Subscription<Message<App::Message>> {
     use iced_futures::futures::StreamExt;
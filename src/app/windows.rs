@@ -0,0 +1,114 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! State associated with each window of a multi-window application.
+
+use std::collections::HashMap;
+
+use iced::{window, Length};
+
+use crate::Element;
+
+/// State associated with each window of a multi-window application.
+///
+/// Messages addressed to a window are routed to its state with [`Windows::update`],
+/// and its view is built with [`Windows::view`]. Windows which have no state are
+/// ignored, rather than causing a panic.
+#[derive(Debug)]
+pub struct Windows<S> {
+    states: HashMap<window::Id, S>,
+}
+
+impl<S> Default for Windows<S> {
+    fn default() -> Self {
+        Self {
+            states: HashMap::new(),
+        }
+    }
+}
+
+impl<S> Windows<S> {
+    /// Creates an empty set of window states.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Associates a state with a window, returning its previous state.
+    pub fn insert(&mut self, id: window::Id, state: S) -> Option<S> {
+        self.states.insert(id, state)
+    }
+
+    /// Removes the state of a window, such as when it has been closed.
+    pub fn remove(&mut self, id: window::Id) -> Option<S> {
+        self.states.remove(&id)
+    }
+
+    /// The state of a window.
+    pub fn get(&self, id: window::Id) -> Option<&S> {
+        self.states.get(&id)
+    }
+
+    /// The mutable state of a window.
+    pub fn get_mut(&mut self, id: window::Id) -> Option<&mut S> {
+        self.states.get_mut(&id)
+    }
+
+    /// Whether the window has state associated with it.
+    pub fn contains(&self, id: window::Id) -> bool {
+        self.states.contains_key(&id)
+    }
+
+    /// The number of windows with state associated with them.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Whether no windows have state associated with them.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Iterates the windows and their states.
+    pub fn iter(&self) -> impl Iterator<Item = (window::Id, &S)> {
+        self.states.iter().map(|(id, state)| (*id, state))
+    }
+
+    /// Iterates the windows and their mutable states.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (window::Id, &mut S)> {
+        self.states.iter_mut().map(|(id, state)| (*id, state))
+    }
+
+    /// Routes a message to the state of a window.
+    ///
+    /// If the window has no state, the message is dropped and no task is returned.
+    pub fn update<M>(
+        &mut self,
+        id: window::Id,
+        update: impl FnOnce(&mut S) -> iced::Task<M>,
+    ) -> iced::Task<M> {
+        match self.states.get_mut(&id) {
+            Some(state) => update(state),
+            None => {
+                tracing::warn!(?id, "message addressed to a window without state");
+                iced::Task::none()
+            }
+        }
+    }
+
+    /// Builds the view of a window from its state.
+    ///
+    /// If the window has no state, an empty view is returned.
+    pub fn view<'a, M: 'a>(
+        &'a self,
+        id: window::Id,
+        view: impl FnOnce(&'a S) -> Element<'a, M>,
+    ) -> Element<'a, M> {
+        match self.states.get(&id) {
+            Some(state) => view(state),
+            None => {
+                tracing::debug!(?id, "no view for window without state");
+                crate::widget::Space::new(Length::Shrink, Length::Shrink).into()
+            }
+        }
+    }
+}
@@ -362,15 +362,17 @@ impl Context {
 
     pub fn text<'a>(&self, msg: impl Into<Cow<'a, str>>) -> crate::widget::Text<'a, crate::Theme> {
         let msg = msg.into();
-        let t = match self.size {
-            Size::PanelSize(PanelSize::XL) => crate::widget::text::title2,
-            Size::PanelSize(PanelSize::L) => crate::widget::text::title3,
-            Size::PanelSize(PanelSize::M) => crate::widget::text::title4,
-            Size::PanelSize(PanelSize::S) => crate::widget::text::body,
-            Size::PanelSize(PanelSize::XS) => crate::widget::text::body,
-            Size::Hardcoded(_) => crate::widget::text,
+        let typography = match self.size {
+            Size::PanelSize(PanelSize::XL) => crate::widget::text::Typography::Title2,
+            Size::PanelSize(PanelSize::L) => crate::widget::text::Typography::Title3,
+            Size::PanelSize(PanelSize::M) => crate::widget::text::Typography::Title4,
+            Size::PanelSize(PanelSize::S) => crate::widget::text::Typography::Body,
+            Size::PanelSize(PanelSize::XS) => crate::widget::text::Typography::Body,
+            Size::Hardcoded(_) => return crate::widget::text(msg).font(crate::font::default()),
         };
-        t(msg).font(crate::font::default())
+        crate::widget::text::Preset::new(msg, typography)
+            .font(crate::font::default())
+            .into()
     }
 }
 
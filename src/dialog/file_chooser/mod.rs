@@ -83,6 +83,26 @@
 //!     }
 //! });
 //! ```
+//!
+//! # Save a file
+//!
+//! ```no_run
+//! cosmic::task::future(async {
+//!     use cosmic::dialog::file_chooser;
+//!
+//!     let dialog = file_chooser::save::Dialog::new()
+//!         .title("Save file")
+//!         .set_current_name("Untitled.txt");
+//!
+//!     match dialog.save_file().await {
+//!         Ok(response) => println!("selected to save to {:?}", response.url()),
+//!
+//!         Err(file_chooser::Error::Cancelled) => (),
+//!
+//!         Err(why) => eprintln!("error selecting where to save: {why:?}")
+//!     }
+//! });
+//! ```
 
 /// Open file dialog.
 pub mod open;
@@ -116,8 +136,77 @@ impl FileFilter {
         self.extensions.push(extension.into());
         self
     }
+
+    /// Accepts files of a MIME type, such as `image/png`, or every subtype of `image/*`.
+    ///
+    /// The file dialog matches files by extension, so this accepts the common extensions of the
+    /// type. This mirrors the portal's file filter, which matches the MIME type itself.
+    pub fn mimetype(mut self, mimetype: &str) -> Self {
+        let (kind, subtype) = mimetype.split_once('/').unwrap_or((mimetype, "*"));
+
+        for &(mime, extensions) in MIME_EXTENSIONS {
+            let matches = match mime.split_once('/') {
+                Some((mime_kind, mime_subtype)) => {
+                    mime_kind == kind && (subtype == "*" || mime_subtype == subtype)
+                }
+                None => false,
+            };
+
+            if matches {
+                for extension in extensions {
+                    if !self.extensions.iter().any(|e| e == extension) {
+                        self.extensions.push((*extension).to_owned());
+                    }
+                }
+            }
+        }
+
+        self
+    }
 }
 
+/// Common extensions of MIME types, for file dialogs which only filter by extension.
+#[cfg(feature = "rfd")]
+const MIME_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("application/gzip", &["gz"]),
+    ("application/json", &["json"]),
+    ("application/pdf", &["pdf"]),
+    ("application/toml", &["toml"]),
+    ("application/vnd.oasis.opendocument.presentation", &["odp"]),
+    ("application/vnd.oasis.opendocument.spreadsheet", &["ods"]),
+    ("application/vnd.oasis.opendocument.text", &["odt"]),
+    ("application/x-7z-compressed", &["7z"]),
+    ("application/x-tar", &["tar"]),
+    ("application/x-xz", &["xz"]),
+    ("application/xml", &["xml"]),
+    ("application/zip", &["zip"]),
+    ("audio/flac", &["flac"]),
+    ("audio/mpeg", &["mp3"]),
+    ("audio/ogg", &["oga", "ogg", "opus"]),
+    ("audio/wav", &["wav"]),
+    ("font/otf", &["otf"]),
+    ("font/ttf", &["ttf"]),
+    ("image/avif", &["avif"]),
+    ("image/bmp", &["bmp"]),
+    ("image/gif", &["gif"]),
+    ("image/jpeg", &["jpg", "jpeg"]),
+    ("image/png", &["png"]),
+    ("image/svg+xml", &["svg", "svgz"]),
+    ("image/tiff", &["tif", "tiff"]),
+    ("image/webp", &["webp"]),
+    ("image/x-icon", &["ico"]),
+    ("text/css", &["css"]),
+    ("text/csv", &["csv"]),
+    ("text/html", &["html", "htm"]),
+    ("text/markdown", &["md", "markdown"]),
+    ("text/plain", &["txt", "text", "log"]),
+    ("text/rust", &["rs"]),
+    ("video/mp4", &["mp4", "m4v"]),
+    ("video/ogg", &["ogv"]),
+    ("video/webm", &["webm"]),
+    ("video/x-matroska", &["mkv"]),
+];
+
 /// Errors that my occur when interacting with the file chooser subscription
 #[derive(Debug, Error)]
 pub enum Error {
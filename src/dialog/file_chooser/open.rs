@@ -125,6 +125,13 @@ impl Dialog {
         folder(self).await
     }
 
+    /// Create a dialog for selecting a folder, such as a destination directory.
+    ///
+    /// This is equivalent to [`Dialog::open_folder`].
+    pub async fn select_folder(self) -> Result<FileResponse, Error> {
+        folder(self).await
+    }
+
     /// Create an open folder dialog with multi file select.
     pub async fn open_folders(self) -> Result<MultiFileResponse, Error> {
         folders(self).await
@@ -20,6 +20,7 @@ use std::path::PathBuf;
 #[must_use]
 pub struct Dialog {
     /// The label for the dialog's window title.
+    #[setters(into)]
     title: String,
 
     /// The label for the accept button. Mnemonic underlines are allowed.
@@ -114,7 +115,21 @@ impl Dialog {
         self
     }
 
+    /// Suggests a name for the file to be saved.
+    pub fn set_current_name(mut self, name: impl Into<String>) -> Self {
+        self.file_name = Some(name.into());
+        self
+    }
+
+    /// Sets the folder which the dialog initially opens in.
+    pub fn set_current_folder(mut self, folder: impl Into<PathBuf>) -> Self {
+        self.directory = Some(folder.into());
+        self
+    }
+
     /// Create a save file dialog request.
+    ///
+    /// Confirming whether an existing file should be overwritten is left to the backend.
     pub async fn save_file(self) -> Result<Response, Error> {
         file(self).await
     }
@@ -144,12 +159,20 @@ mod portal {
             .map_err(Error::SetAbsolutePath)?
             .send()
             .await
-            .map_err(Error::Save)?
+            .map_err(error_or_cancel)?
             .response()
-            .map_err(Error::Save)
+            .map_err(error_or_cancel)
             .map(Response)
     }
 
+    fn error_or_cancel(error: ashpd::Error) -> Error {
+        if let ashpd::Error::Response(ashpd::desktop::ResponseError::Cancelled) = error {
+            Error::Cancelled
+        } else {
+            Error::Save(error)
+        }
+    }
+
     /// A dialog response containing the selected file or folder.
     pub struct Response(pub SelectedFiles);
 
@@ -192,7 +215,7 @@ mod rust_fd {
             return Ok(Response(Some(url)));
         }
 
-        Ok(Response(None))
+        Err(Error::Cancelled)
     }
 
     /// A dialog response containing the selected file or folder.
@@ -0,0 +1,60 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A bounded pool of threads for blocking work, such as decoding images.
+//!
+//! The pool does not depend on the async executor of the application, so that work may also be
+//! queued from outside of an async context, such as while building a view.
+
+use iced_futures::futures::channel::oneshot;
+use std::sync::{mpsc, Arc, LazyLock, Mutex};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Queues jobs onto a fixed number of worker threads, which are started on first use.
+static POOL: LazyLock<mpsc::Sender<Job>> = LazyLock::new(|| {
+    let (tx, rx) = mpsc::channel::<Job>();
+    let rx = Arc::new(Mutex::new(rx));
+
+    let workers = std::thread::available_parallelism().map_or(2, |n| n.get().min(4));
+
+    for nth in 0..workers {
+        let rx = rx.clone();
+        let spawned = std::thread::Builder::new()
+            .name(format!("cosmic-blocking-{nth}"))
+            .spawn(move || loop {
+                let job = rx.lock().unwrap().recv();
+                match job {
+                    // A job which panics must not take its worker down with it.
+                    Ok(job) => _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(job)),
+                    Err(_) => break,
+                }
+            });
+
+        if let Err(why) = spawned {
+            tracing::error!(?why, "failed to spawn blocking worker thread");
+        }
+    }
+
+    tx
+});
+
+/// Runs `job` on the blocking pool.
+pub(crate) fn spawn(job: impl FnOnce() + Send + 'static) {
+    _ = POOL.send(Box::new(job));
+}
+
+/// Runs `f` on the blocking pool, and awaits its result.
+///
+/// Returns `None` if `f` panicked.
+pub(crate) async fn unblock<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Option<T> {
+    let (tx, rx) = oneshot::channel();
+
+    spawn(move || {
+        _ = tx.send(f());
+    });
+
+    rx.await.ok()
+}
@@ -3,6 +3,10 @@
 
 //! Select the preferred async executor for an application.
 
+pub(crate) mod blocking;
+#[cfg(not(any(feature = "tokio", feature = "smol")))]
+mod timer;
+
 #[cfg(feature = "tokio")]
 pub mod multi;
 
@@ -16,3 +20,17 @@ pub type Default = iced::executor::Default;
 /// Uses the single thread executor by default.
 #[cfg(feature = "tokio")]
 pub type Default = single::Executor;
+
+/// Waits for the `duration` to elapse, using the timer of the enabled async runtime.
+///
+/// Without one, the wait is tracked by a dedicated timer thread, so that it works on any executor.
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    #[cfg(feature = "tokio")]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(all(feature = "smol", not(feature = "tokio")))]
+    smol::Timer::after(duration).await;
+
+    #[cfg(not(any(feature = "tokio", feature = "smol")))]
+    timer::sleep(duration).await;
+}
@@ -0,0 +1,94 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A timer for when no async runtime with its own timer is enabled.
+//!
+//! Every wait is tracked by a single dedicated thread, so that waiting does not occupy a thread
+//! of the blocking pool.
+
+use iced_futures::futures::channel::oneshot;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{mpsc, LazyLock};
+use std::time::{Duration, Instant};
+
+/// A wait which is woken at its deadline.
+struct Wait {
+    deadline: Instant,
+    wake: oneshot::Sender<()>,
+}
+
+impl PartialEq for Wait {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Wait {}
+
+impl PartialOrd for Wait {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Wait {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.deadline.cmp(&other.deadline)
+    }
+}
+
+/// Sends waits to the timer thread, which is started on first use.
+static TIMER: LazyLock<mpsc::Sender<Wait>> = LazyLock::new(|| {
+    let (tx, rx) = mpsc::channel::<Wait>();
+
+    let spawned = std::thread::Builder::new()
+        .name("cosmic-timer".into())
+        .spawn(move || {
+            let mut waits = BinaryHeap::<Reverse<Wait>>::new();
+
+            loop {
+                let received = match waits.peek() {
+                    Some(Reverse(next)) => rx
+                        .recv_timeout(next.deadline.saturating_duration_since(Instant::now()))
+                        .map_err(|why| why == mpsc::RecvTimeoutError::Disconnected),
+                    None => rx.recv().map_err(|_| true),
+                };
+
+                match received {
+                    Ok(wait) => waits.push(Reverse(wait)),
+                    Err(true) => break,
+                    Err(false) => (),
+                }
+
+                let now = Instant::now();
+                while waits
+                    .peek()
+                    .is_some_and(|Reverse(next)| next.deadline <= now)
+                {
+                    if let Some(Reverse(wait)) = waits.pop() {
+                        _ = wait.wake.send(());
+                    }
+                }
+            }
+        });
+
+    if let Err(why) = spawned {
+        tracing::error!(?why, "failed to spawn timer thread");
+    }
+
+    tx
+});
+
+/// Waits for the `duration` to elapse.
+pub(crate) async fn sleep(duration: Duration) {
+    let (wake, woken) = oneshot::channel();
+    let wait = Wait {
+        deadline: Instant::now() + duration,
+        wake,
+    };
+
+    if TIMER.send(wait).is_ok() {
+        _ = woken.await;
+    }
+}
@@ -10,6 +10,8 @@ pub const COSMIC: &str = "Cosmic";
 
 pub(crate) static DEFAULT: Mutex<Cow<'static, str>> = Mutex::new(Cow::Borrowed(COSMIC));
 
+pub(crate) static FALLBACKS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
 /// The fallback icon theme to search if no icon theme was specified.
 #[must_use]
 #[allow(clippy::missing_panics_doc)]
@@ -22,3 +24,37 @@ pub fn default() -> String {
 pub fn set_default(name: impl Into<Cow<'static, str>>) {
     *DEFAULT.lock().unwrap() = name.into();
 }
+
+/// Icon themes to search, in order, when an icon is missing from the default icon theme.
+#[must_use]
+#[allow(clippy::missing_panics_doc)]
+pub fn fallbacks() -> Vec<String> {
+    FALLBACKS.lock().unwrap().clone()
+}
+
+/// Set the icon themes to search, in order, when an icon is missing from the default icon theme.
+///
+/// ```no_run
+/// cosmic::icon_theme::set_fallbacks(&["Pop", "Adwaita", "hicolor"]);
+/// ```
+#[allow(clippy::missing_panics_doc)]
+pub fn set_fallbacks(names: &[impl AsRef<str>]) {
+    *FALLBACKS.lock().unwrap() = names.iter().map(|name| name.as_ref().to_owned()).collect();
+}
+
+/// Icon themes to search, in order, when loading system icons.
+pub(crate) fn search_order() -> Vec<String> {
+    let mut themes = vec![default()];
+
+    for name in FALLBACKS.lock().unwrap().iter() {
+        if !themes.contains(name) {
+            themes.push(name.clone());
+        }
+    }
+
+    if !themes.iter().any(|name| name == COSMIC) {
+        themes.push(COSMIC.to_owned());
+    }
+
+    themes
+}
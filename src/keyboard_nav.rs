@@ -5,7 +5,11 @@
 
 use iced::{event, keyboard, Event, Subscription};
 use iced_core::keyboard::key::Named;
+use iced_core::widget::operation::{Focusable, Operation, Outcome};
+use iced_core::widget::Id;
+use iced_core::Rectangle;
 use iced_futures::event::listen_raw;
+use iced_runtime::{task, Action, Task};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Message {
@@ -60,3 +64,135 @@ pub fn subscription() -> Subscription<Message> {
         None
     })
 }
+
+/// Focuses the next focusable widget within the container of the given [`Id`].
+///
+/// Focus wraps from the last widget to the first, and never leaves the container.
+pub fn focus_next_within<Message>(scope: Id) -> Task<Message> {
+    task::effect(Action::widget(ScopedCount::new(scope, Direction::Next)))
+}
+
+/// Focuses the previous focusable widget within the container of the given [`Id`].
+///
+/// Focus wraps from the first widget to the last, and never leaves the container.
+pub fn focus_previous_within<Message>(scope: Id) -> Task<Message> {
+    task::effect(Action::widget(ScopedCount::new(scope, Direction::Previous)))
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Next,
+    Previous,
+}
+
+/// Counts the focusable widgets within a container, and which of them is focused.
+struct ScopedCount {
+    scope: Id,
+    direction: Direction,
+    in_scope: bool,
+    total: usize,
+    focused: Option<usize>,
+}
+
+impl ScopedCount {
+    fn new(scope: Id, direction: Direction) -> Self {
+        Self {
+            scope,
+            direction,
+            in_scope: false,
+            total: 0,
+            focused: None,
+        }
+    }
+}
+
+impl Operation for ScopedCount {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.in_scope || id != Some(&self.scope) {
+            operate_on_children(self);
+            return;
+        }
+
+        self.in_scope = true;
+        operate_on_children(self);
+        self.in_scope = false;
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if !self.in_scope {
+            return;
+        }
+
+        if state.is_focused() {
+            self.focused = Some(self.total);
+        }
+
+        self.total += 1;
+    }
+
+    fn finish(&self) -> Outcome<()> {
+        if self.total == 0 {
+            return Outcome::None;
+        }
+
+        let target = match (self.direction, self.focused) {
+            (Direction::Next, Some(nth)) => (nth + 1) % self.total,
+            (Direction::Next, None) => 0,
+            (Direction::Previous, Some(nth)) => (nth + self.total - 1) % self.total,
+            (Direction::Previous, None) => self.total - 1,
+        };
+
+        Outcome::Chain(Box::new(ScopedFocus {
+            scope: self.scope.clone(),
+            in_scope: false,
+            current: 0,
+            target,
+        }))
+    }
+}
+
+/// Focuses the `target` focusable widget within a container, and unfocuses all others.
+struct ScopedFocus {
+    scope: Id,
+    in_scope: bool,
+    current: usize,
+    target: usize,
+}
+
+impl Operation for ScopedFocus {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.in_scope || id != Some(&self.scope) {
+            operate_on_children(self);
+            return;
+        }
+
+        self.in_scope = true;
+        operate_on_children(self);
+        self.in_scope = false;
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if !self.in_scope {
+            state.unfocus();
+            return;
+        }
+
+        if self.current == self.target {
+            state.focus();
+        } else {
+            state.unfocus();
+        }
+
+        self.current += 1;
+    }
+}
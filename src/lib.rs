@@ -81,8 +81,13 @@ pub mod process;
 #[cfg(feature = "wayland")]
 pub use cctk;
 
+#[cfg(feature = "wayland")]
+pub mod surface;
+
 pub mod theme;
 
+pub mod time;
+
 #[doc(inline)]
 pub use theme::{style, Theme};
 
@@ -0,0 +1,78 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Create and position surfaces, such as popups, on Wayland.
+//!
+//! The content of a surface is the view returned by
+//! [`Application::view_window`](crate::Application::view_window) for its id.
+
+use crate::cctk::sctk::reexports::protocols::xdg::shell::client::xdg_positioner::{
+    Anchor, Gravity,
+};
+use iced::platform_specific::shell::commands::popup::{destroy_popup, get_popup};
+use iced::{window, Point, Rectangle, Task};
+use iced_runtime::platform_specific::wayland::popup::{SctkPopupSettings, SctkPositioner};
+
+/// Requests to create or destroy surfaces.
+#[derive(Clone, Debug)]
+pub enum Action {
+    /// Opens a popup whose top left corner is anchored to a point within its parent surface.
+    ///
+    /// The popup is flipped or slid by the compositor if it would not fit on the screen.
+    PopupAt {
+        /// The surface which the popup belongs to.
+        parent: window::Id,
+        /// The id of the popup, whose view is built by `view_window`.
+        id: window::Id,
+        /// The point within the parent surface to anchor the popup to.
+        position: Point,
+        /// The size of the popup, or `None` to size it to its content.
+        size: Option<(u32, u32)>,
+    },
+    /// Closes a popup.
+    DestroyPopup(window::Id),
+}
+
+/// Creates a task which performs a surface action.
+pub fn action<M>(action: Action) -> Task<M> {
+    match action {
+        Action::PopupAt {
+            parent,
+            id,
+            position,
+            size,
+        } => get_popup(popup_at(parent, id, position, size)),
+        Action::DestroyPopup(id) => destroy_popup(id),
+    }
+}
+
+/// Settings for a popup whose top left corner is anchored to a point within its parent surface.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn popup_at(
+    parent: window::Id,
+    id: window::Id,
+    position: Point,
+    size: Option<(u32, u32)>,
+) -> SctkPopupSettings {
+    SctkPopupSettings {
+        parent,
+        id,
+        positioner: SctkPositioner {
+            anchor: Anchor::TopLeft,
+            gravity: Gravity::BottomRight,
+            size,
+            anchor_rect: Rectangle {
+                x: position.x.round() as i32,
+                y: position.y.round() as i32,
+                width: 1,
+                height: 1,
+            },
+            reactive: true,
+            constraint_adjustment: 15, // slide_y, slide_x, flip_x, flip_y
+            ..Default::default()
+        },
+        parent_size: None,
+        grab: true,
+    }
+}
@@ -6,6 +6,7 @@
 use iced::window;
 use iced::Task;
 use iced_core::window::Mode;
+use iced_futures::futures::Stream;
 use iced_runtime::{task, Action};
 use std::future::Future;
 
@@ -21,6 +22,13 @@ pub fn future<X: Into<Y>, Y: 'static>(future: impl Future<Output = X> + Send + '
     Task::future(async move { future.await.into() })
 }
 
+/// Yields a task which will forward every message of the stream, until the stream ends.
+pub fn stream<X: Into<Y> + 'static, Y: 'static>(
+    stream: impl Stream<Item = X> + Send + 'static,
+) -> Task<Y> {
+    Task::run(stream, Into::into)
+}
+
 /// Yields a task which will return a message.
 pub fn message<X: Send + 'static + Into<Y>, Y: 'static>(message: X) -> Task<Y> {
     future(async move { message.into() })
@@ -31,6 +39,16 @@ pub fn drag<M>(id: window::Id) -> Task<M> {
     iced_runtime::window::drag(id)
 }
 
+/// Closes the window.
+pub fn close<M>(id: window::Id) -> Task<M> {
+    iced_runtime::window::close(id)
+}
+
+/// Focuses the window.
+pub fn focus<M>(id: window::Id) -> Task<M> {
+    iced_runtime::window::gain_focus(id)
+}
+
 /// Maximizes the window.
 pub fn maximize<M>(id: window::Id, maximized: bool) -> Task<M> {
     iced_runtime::window::maximize(id, maximized)
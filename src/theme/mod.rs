@@ -99,6 +99,72 @@ pub fn subscription(is_dark: bool) -> Subscription<crate::theme::Theme> {
     })
 }
 
+/// How long to wait for further changes to the system theme before emitting an update.
+const COALESCE_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Watches for changes to the system's COSMIC theme, coalescing rapid changes.
+pub fn cosmic_theme_subscription(is_dark: bool) -> Subscription<CosmicTheme> {
+    use iced::futures::{channel::mpsc, future::pending, SinkExt, StreamExt};
+
+    struct CosmicThemeSubscription;
+
+    Subscription::run_with_id(
+        (std::any::TypeId::of::<CosmicThemeSubscription>(), is_dark),
+        iced_futures::stream::channel(10, move |mut output| async move {
+            let config = if is_dark {
+                CosmicTheme::dark_config()
+            } else {
+                CosmicTheme::light_config()
+            };
+
+            let config = match config {
+                Ok(config) => config,
+                Err(why) => {
+                    tracing::error!(?why, "failed to load cosmic theme config");
+                    return pending().await;
+                }
+            };
+
+            let (tx, mut rx) = mpsc::channel(10);
+            let _watcher = match config.watch(move |_helper, _keys| {
+                let mut tx = tx.clone();
+                let _ = tx.try_send(());
+            }) {
+                Ok(watcher) => watcher,
+                Err(why) => {
+                    tracing::error!(?why, "failed to watch cosmic theme config");
+                    return pending().await;
+                }
+            };
+
+            let get_theme = || {
+                CosmicTheme::get_entry(&config).unwrap_or_else(|(errors, theme)| {
+                    for why in errors {
+                        tracing::error!(?why, "cosmic theme config error");
+                    }
+                    theme
+                })
+            };
+
+            let mut theme = get_theme();
+            _ = output.send(theme.clone()).await;
+
+            while rx.next().await.is_some() {
+                crate::executor::sleep(COALESCE_DELAY).await;
+
+                // Discard changes which arrived while waiting, as they are read together.
+                while let Ok(Some(())) = rx.try_next() {}
+
+                let new_theme = get_theme();
+                if new_theme != theme {
+                    theme = new_theme;
+                    _ = output.send(theme.clone()).await;
+                }
+            }
+        }),
+    )
+}
+
 pub fn system_dark() -> Theme {
     let Ok(helper) = crate::cosmic_theme::Theme::dark_config() else {
         return Theme::dark();
@@ -145,6 +211,29 @@ pub fn system_preference() -> Theme {
     }
 }
 
+/// Whether a theme is light or dark, and whether it has a high contrast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Light,
+    Dark,
+    HighContrastLight,
+    HighContrastDark,
+}
+
+impl Mode {
+    /// Whether the mode is dark.
+    #[must_use]
+    pub fn is_dark(self) -> bool {
+        matches!(self, Self::Dark | Self::HighContrastDark)
+    }
+
+    /// Whether the mode has a high contrast.
+    #[must_use]
+    pub fn is_high_contrast(self) -> bool {
+        matches!(self, Self::HighContrastLight | Self::HighContrastDark)
+    }
+}
+
 #[must_use]
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum ThemeType {
@@ -181,6 +270,16 @@ impl ThemeType {
         }
     }
 
+    /// Whether the theme is light or dark, and whether it has a high contrast.
+    pub fn mode(&self) -> Mode {
+        match (self.is_dark(), self.is_high_contrast()) {
+            (false, false) => Mode::Light,
+            (true, false) => Mode::Dark,
+            (false, true) => Mode::HighContrastLight,
+            (true, true) => Mode::HighContrastDark,
+        }
+    }
+
     /// Prefer dark or light theme.
     /// If `None`, the system preference is used.
     pub fn prefer_dark(&mut self, new_prefer_dark: Option<bool>) {
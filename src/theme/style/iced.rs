@@ -1457,11 +1457,14 @@ impl iced_widget::text_editor::Catalog for Theme {
 #[cfg(feature = "markdown")]
 impl iced_widget::markdown::Catalog for Theme {
     fn code_block<'a>() -> <Self as iced_container::Catalog>::Class<'a> {
-        Container::custom(|_| iced_container::Style {
-            background: Some(iced::color!(0x111111).into()),
-            text_color: Some(Color::WHITE),
-            border: iced::border::rounded(2),
-            ..iced_container::Style::default()
+        Container::custom(|theme| {
+            let cosmic = theme.cosmic();
+            iced_container::Style {
+                background: Some(Color::from(cosmic.background.component.base).into()),
+                text_color: Some(cosmic.background.component.on.into()),
+                border: iced::border::rounded(cosmic.corner_radii.radius_s),
+                ..iced_container::Style::default()
+            }
         })
     }
 }
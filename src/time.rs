@@ -0,0 +1,29 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Subscriptions which tick at a regular interval, such as for clocks and polling.
+//!
+//! ```ignore
+//! # use cosmic::iced::Subscription;
+//! # use std::time::Duration;
+//! # #[derive(Clone)]
+//! # enum Message { Tick(cosmic::time::Instant) }
+//! fn subscription() -> Subscription<Message> {
+//!     cosmic::time::every(Duration::from_secs(1), Message::Tick)
+//! }
+//! ```
+
+pub use iced::time::{Duration, Instant};
+
+/// Emits a message created from the current time once every `duration`.
+///
+/// The timer runs on the async executor of the application.
+#[cfg(any(feature = "tokio", feature = "smol"))]
+pub fn every<Message: Send + 'static>(
+    duration: Duration,
+    f: fn(Instant) -> Message,
+) -> iced::Subscription<Message> {
+    iced::time::every(duration)
+        .with(f)
+        .map(|(f, instant)| f(instant))
+}
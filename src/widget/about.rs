@@ -43,6 +43,9 @@ pub struct About {
     /// Links associated with the application.
     #[setters(skip)]
     links: Vec<(String, String)>,
+    /// System information to include in bug reports.
+    #[setters(skip)]
+    debug_info: Vec<(String, String)>,
 }
 
 impl<'a> About {
@@ -106,6 +109,37 @@ impl<'a> About {
         self
     }
 
+    /// System information to include in bug reports, such as the OS and GPU renderer.
+    pub fn debug_info<K: Into<String>, V: Into<String>>(
+        mut self,
+        debug_info: impl Into<Vec<(K, V)>>,
+    ) -> Self {
+        let debug_info: Vec<(K, V)> = debug_info.into();
+        self.debug_info = debug_info
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        self
+    }
+
+    /// The debug info formatted as plain text, for pasting into bug reports.
+    pub fn debug_info_text(&self) -> String {
+        let application = self.name.as_ref().map(|name| match self.version.as_ref() {
+            Some(version) => format!("{name} {version}"),
+            None => name.clone(),
+        });
+
+        application
+            .into_iter()
+            .chain(
+                self.debug_info
+                    .iter()
+                    .map(|(label, value)| format!("{label}: {value}")),
+            )
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn license_url(&self) -> Option<String> {
         let license: &dyn License = match self.license.as_ref() {
             Some(license) => license.parse().ok()?,
@@ -122,6 +156,25 @@ impl<'a> About {
 pub fn about<'a, Message: Clone + 'static>(
     about: &'a About,
     on_url_press: impl Fn(String) -> Message,
+) -> Element<'a, Message> {
+    about_inner(about, on_url_press, None)
+}
+
+/// Constructs the widget for the about section, with a button to copy its debug info.
+///
+/// `on_copy` receives the [`About::debug_info_text`] when its copy button is pressed.
+pub fn about_with_copy<'a, Message: Clone + 'static>(
+    about: &'a About,
+    on_url_press: impl Fn(String) -> Message,
+    on_copy: impl Fn(String) -> Message,
+) -> Element<'a, Message> {
+    about_inner(about, on_url_press, Some(&on_copy))
+}
+
+fn about_inner<'a, Message: Clone + 'static>(
+    about: &'a About,
+    on_url_press: impl Fn(String) -> Message,
+    on_copy: Option<&dyn Fn(String) -> Message>,
 ) -> Element<'a, Message> {
     let cosmic_theme::Spacing {
         space_xxs,
@@ -187,6 +240,26 @@ pub fn about<'a, Message: Clone + 'static>(
             .width(Length::Fill),
         )
     });
+    let debug_info = (!about.debug_info.is_empty()).then(|| {
+        widget::settings::section()
+            .title("Debug Info")
+            .extend(
+                about
+                    .debug_info
+                    .iter()
+                    .map(|(label, value)| widget::settings::item(label, widget::text(value))),
+            )
+            .add_maybe(on_copy.map(|on_copy| {
+                widget::row()
+                    .push(horizontal_space())
+                    .push(
+                        widget::button::standard("Copy to clipboard")
+                            .leading_icon(crate::widget::icon::from_name("edit-copy-symbolic"))
+                            .on_press(on_copy(about.debug_info_text())),
+                    )
+                    .padding(space_xxs)
+            }))
+    });
     let copyright = about.copyright.as_ref().map(widget::text::body);
     let comments = about.comments.as_ref().map(widget::text::body);
 
@@ -202,6 +275,7 @@ pub fn about<'a, Message: Clone + 'static>(
         .push_maybe(artists_section)
         .push_maybe(translators_section)
         .push_maybe(documenters_section)
+        .push_maybe(debug_info)
         .push_maybe(comments)
         .push_maybe(copyright)
         .align_x(Alignment::Center)
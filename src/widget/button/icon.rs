@@ -8,7 +8,10 @@ use crate::widget::{
 };
 use crate::Element;
 use apply::Apply;
-use iced_core::{font::Weight, text::LineHeight, widget::Id, Alignment, Length, Padding};
+use iced_core::{
+    font::Weight, text::LineHeight, widget::Id, Alignment, Background, Border, Color, Length,
+    Padding,
+};
 use std::borrow::Cow;
 
 pub type Button<'a, Message> = Builder<'a, Message, Icon>;
@@ -18,6 +21,7 @@ pub struct Icon {
     handle: Handle,
     vertical: bool,
     selected: bool,
+    badge: Option<String>,
 }
 
 /// A button constructed from an icon handle, using icon button styling.
@@ -26,6 +30,7 @@ pub fn icon<'a, Message>(handle: impl Into<Handle>) -> Button<'a, Message> {
         handle: handle.into(),
         vertical: false,
         selected: false,
+        badge: None,
     })
 }
 
@@ -49,6 +54,7 @@ impl<'a, Message> Button<'a, Message> {
             font_size: 14,
             font_weight: Weight::Normal,
             class: ButtonClass::Icon,
+            loading: false,
             variant: icon,
         }
     }
@@ -124,6 +130,23 @@ impl<'a, Message> Button<'a, Message> {
         self.class = ButtonClass::IconVertical;
         self
     }
+
+    /// Displays a count badge in the top-right corner of the icon.
+    ///
+    /// A count of zero hides the badge.
+    pub fn badge(mut self, count: u32) -> Self {
+        self.variant.badge = (count > 0).then(|| count.to_string());
+        self
+    }
+
+    /// Displays a text badge in the top-right corner of the icon.
+    ///
+    /// Empty text hides the badge.
+    pub fn badge_text(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        self.variant.badge = (!text.is_empty()).then_some(text);
+        self
+    }
 }
 
 impl<'a, Message: Clone + 'static> From<Button<'a, Message>> for Element<'a, Message> {
@@ -134,11 +157,12 @@ impl<'a, Message: Clone + 'static> From<Button<'a, Message>> for Element<'a, Mes
             named.size = Some(builder.icon_size);
         }
 
-        content.push(
-            crate::widget::icon(builder.variant.handle.clone())
-                .size(builder.icon_size)
-                .into(),
-        );
+        let icon = crate::widget::icon(builder.variant.handle.clone()).size(builder.icon_size);
+
+        content.push(match builder.variant.badge.take() {
+            Some(badge) => with_badge(icon, badge, builder.icon_size),
+            None => icon.into(),
+        });
 
         if !builder.label.is_empty() {
             content.push(
@@ -174,6 +198,7 @@ impl<'a, Message: Clone + 'static> From<Button<'a, Message>> for Element<'a, Mes
             .id(builder.id)
             .on_press_maybe(builder.on_press)
             .selected(builder.variant.selected)
+            .loading(builder.loading)
             .class(builder.class);
 
         if builder.tooltip.is_empty() {
@@ -193,3 +218,45 @@ impl<'a, Message: Clone + 'static> From<Button<'a, Message>> for Element<'a, Mes
         }
     }
 }
+
+/// Overlays a badge in the top-right corner of the icon, scaled to the icon size.
+fn with_badge<'a, Message: 'static>(
+    icon: impl Into<Element<'a, Message>>,
+    badge: String,
+    icon_size: u16,
+) -> Element<'a, Message> {
+    let font_size = icon_size / 2 + 2;
+
+    let badge = crate::widget::text(badge)
+        .size(font_size)
+        .line_height(LineHeight::Absolute(font_size.into()))
+        .font(crate::font::Font {
+            weight: Weight::Bold,
+            ..crate::font::default()
+        })
+        .apply(crate::widget::container)
+        .padding([0, font_size / 3])
+        .class(crate::theme::Container::custom(badge_style))
+        .apply(crate::widget::container)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Alignment::End)
+        .align_y(Alignment::Start);
+
+    iced::widget::Stack::with_children([icon.into(), badge.into()]).into()
+}
+
+fn badge_style(theme: &crate::Theme) -> crate::widget::container::Style {
+    let cosmic = theme.cosmic();
+    crate::widget::container::Style {
+        icon_color: Some(cosmic.accent.on.into()),
+        text_color: Some(cosmic.accent.on.into()),
+        background: Some(Background::Color(cosmic.accent_color().into())),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: cosmic.corner_radii.radius_xl.into(),
+        },
+        ..Default::default()
+    }
+}
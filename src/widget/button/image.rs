@@ -1,30 +1,51 @@
 // Copyright 2023 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-use super::{Builder, Style};
+use super::{thumbnail, Builder, Style};
 use crate::{
+    theme,
     widget::{self, image::Handle},
     Element,
 };
-use iced_core::{font::Weight, widget::Id, Length, Padding};
+use iced_core::{
+    alignment, font::Weight, widget::Id, Background, Border, Color, Length, Padding, Shadow,
+};
 use std::borrow::Cow;
 
 pub type Button<'a, Message> = Builder<'a, Message, Image<'a, Handle, Message>>;
 
 /// A button constructed from an image handle, using image button styling.
+///
+/// Images loaded from a path with a fixed width or height are decoded into a
+/// cached thumbnail in the background, and a placeholder is shown meanwhile.
 pub fn image<'a, Message>(handle: impl Into<Handle> + 'a) -> Button<'a, Message> {
+    let handle = handle.into();
     Button::new(Image {
-        image: widget::image(handle).border_radius([9.0; 4]),
+        image: widget::image(handle.clone()).border_radius([9.0; 4]),
+        handle,
         selected: false,
         on_remove: None,
+        video: false,
+        duration: None,
     })
 }
 
+/// A button showing the first frame of a video, with a play glyph over it.
+///
+/// Videos can't be decoded as images, so the handle should be of a frame which was already
+/// decoded, such as with [`Handle::from_rgba`].
+pub fn video<'a, Message>(first_frame: impl Into<Handle> + 'a) -> Button<'a, Message> {
+    image(first_frame).video(true)
+}
+
 /// The image variant of a button.
 pub struct Image<'a, Handle, Message> {
     image: widget::Image<'a, Handle>,
+    handle: Handle,
     selected: bool,
     on_remove: Option<Message>,
+    video: bool,
+    duration: Option<Cow<'a, str>>,
 }
 
 impl<'a, Message> Button<'a, Message> {
@@ -43,6 +64,7 @@ impl<'a, Message> Button<'a, Message> {
             font_size: 14,
             font_weight: Weight::Normal,
             class: crate::theme::style::Button::Image,
+            loading: false,
             variant,
         }
     }
@@ -61,6 +83,18 @@ impl<'a, Message> Button<'a, Message> {
         self.variant.selected = selected;
         self
     }
+
+    /// Marks the image as a frame of a video, which overlays a play glyph on it.
+    pub fn video(mut self, video: bool) -> Self {
+        self.variant.video = video;
+        self
+    }
+
+    /// Overlays the duration of a video, such as `"1:23"`, in the bottom corner of the image.
+    pub fn duration_badge(mut self, duration: impl Into<Cow<'a, str>>) -> Self {
+        self.variant.duration = Some(duration.into());
+        self
+    }
 }
 
 impl<'a, Message> From<Button<'a, Message>> for Element<'a, Message>
@@ -69,18 +103,103 @@ where
     Message: Clone + 'static,
 {
     fn from(builder: Button<'a, Message>) -> Element<'a, Message> {
-        let content = builder
-            .variant
-            .image
-            .width(builder.width)
-            .height(builder.height);
+        let thumbnail = thumbnail::Key::new(&builder.variant.handle, builder.width, builder.height);
+
+        let content: Element<'a, Message> = match thumbnail.as_ref() {
+            Some(key) => match thumbnail::get(key) {
+                Some(handle) => widget::image(handle)
+                    .border_radius([9.0; 4])
+                    .width(builder.width)
+                    .height(builder.height)
+                    .into(),
+                None => thumbnail::pending(key.clone()).into(),
+            },
+            None => builder
+                .variant
+                .image
+                .width(builder.width)
+                .height(builder.height)
+                .into(),
+        };
+
+        let content = if builder.variant.video || builder.variant.duration.is_some() {
+            video_overlay(content, builder.variant.video, builder.variant.duration)
+        } else {
+            content
+        };
 
         super::custom_image_button(content, builder.variant.on_remove)
+            .thumbnail(thumbnail)
             .padding(0)
             .selected(builder.variant.selected)
             .id(builder.id)
             .on_press_maybe(builder.on_press)
+            .loading(builder.loading)
             .class(builder.class)
             .into()
     }
 }
+
+/// Layers a play glyph and the duration of a video over its frame.
+///
+/// The overlays are decorative, so presses still reach the button beneath them.
+fn video_overlay<'a, Message: 'static>(
+    content: Element<'a, Message>,
+    video: bool,
+    duration: Option<Cow<'a, str>>,
+) -> Element<'a, Message> {
+    let mut layers = Vec::with_capacity(3);
+    layers.push(content);
+
+    if video {
+        let glyph = widget::icon::from_name("media-playback-start-symbolic")
+            .size(24)
+            .icon();
+
+        layers.push(
+            widget::container(
+                widget::container(glyph)
+                    .padding(8)
+                    .class(theme::Container::custom(|theme| {
+                        overlay_style(theme.cosmic().corner_radii.radius_xl)
+                    })),
+            )
+            .center(Length::Fill)
+            .into(),
+        );
+    }
+
+    if let Some(duration) = duration {
+        layers.push(
+            widget::container(
+                widget::container(widget::text::caption(duration))
+                    .padding([2, 6])
+                    .class(theme::Container::custom(|theme| {
+                        overlay_style(theme.cosmic().corner_radii.radius_s)
+                    })),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(8)
+            .align_x(alignment::Horizontal::Right)
+            .align_y(alignment::Vertical::Bottom)
+            .into(),
+        );
+    }
+
+    iced::widget::stack(layers).into()
+}
+
+/// A translucent dark backdrop, which keeps overlays legible over any image.
+fn overlay_style(radius: [f32; 4]) -> widget::container::Style {
+    widget::container::Style {
+        icon_color: Some(Color::WHITE),
+        text_color: Some(Color::WHITE),
+        background: Some(Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.6))),
+        border: Border {
+            radius: radius.into(),
+            ..Border::default()
+        },
+        shadow: Shadow::default(),
+    }
+}
@@ -45,6 +45,7 @@ impl<'a, Message> Button<'a, Message> {
             font_size: 14,
             font_weight: Weight::Normal,
             class: ButtonClass::Link,
+            loading: false,
             variant: link,
         }
     }
@@ -86,6 +87,7 @@ impl<'a, Message: Clone + 'static> From<Button<'a, Message>> for Element<'a, Mes
             .padding(0)
             .id(builder.id)
             .on_press_maybe(builder.on_press.take())
+            .loading(builder.loading)
             .class(builder.class);
 
         if builder.tooltip.is_empty() {
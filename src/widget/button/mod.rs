@@ -20,9 +20,9 @@ pub use icon::Button as IconButton;
 
 mod image;
 #[doc(inline)]
-pub use image::image;
-#[doc(inline)]
 pub use image::Button as ImageButton;
+#[doc(inline)]
+pub use image::{image, video};
 
 mod style;
 #[doc(inline)]
@@ -34,6 +34,8 @@ pub use text::Button as TextButton;
 #[doc(inline)]
 pub use text::{destructive, standard, suggested, text};
 
+pub(crate) mod thumbnail;
+
 mod widget;
 #[doc(inline)]
 pub use widget::{draw, focus, layout, mouse_interaction, Button};
@@ -107,6 +109,9 @@ pub struct Builder<'a, Message, Variant> {
     /// The preferred style of the button.
     class: ButtonClass,
 
+    /// Displays a spinner in place of the label and icons, and ignores presses, while loading.
+    loading: bool,
+
     #[setters(skip)]
     variant: Variant,
 }
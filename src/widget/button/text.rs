@@ -69,6 +69,7 @@ impl<'a, Message> Button<'a, Message> {
             font_size: 14,
             font_weight: Weight::Normal,
             class: ButtonClass::Standard,
+            loading: false,
             variant: text,
         }
     }
@@ -132,6 +133,7 @@ impl<'a, Message: Clone + 'static> From<Button<'a, Message>> for Element<'a, Mes
             .padding(0)
             .id(builder.id)
             .on_press_maybe(builder.on_press.take())
+            .loading(builder.loading)
             .class(builder.class);
 
         #[cfg(feature = "a11y")]
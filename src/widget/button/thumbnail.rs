@@ -0,0 +1,250 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Thumbnails of image buttons, which are decoded in the background.
+//!
+//! Decoding runs on a bounded pool of threads, and the application runtime is woken by
+//! [`subscription`] to rebuild the view once thumbnails are ready.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+
+use crate::widget::image::Handle;
+use iced::Subscription;
+use iced_core::widget::Tree;
+use iced_core::{
+    layout, mouse, renderer, Background, Border, Layout, Length, Rectangle, Shadow, Size, Widget,
+};
+use iced_futures::futures::channel::mpsc;
+use iced_futures::futures::{SinkExt, StreamExt};
+
+/// The most thumbnails which are kept at once, after which the least recently used are evicted.
+const CAPACITY: usize = 512;
+
+/// Decoded thumbnails, which are evicted when least recently used.
+static THUMBNAILS: LazyLock<Mutex<Cache>> = LazyLock::new(|| {
+    Mutex::new(Cache {
+        entries: HashMap::new(),
+        clock: 0,
+    })
+});
+
+/// Notified whenever a thumbnail has been decoded.
+static LISTENERS: Mutex<Vec<mpsc::UnboundedSender<()>>> = Mutex::new(Vec::new());
+
+struct Cache {
+    entries: HashMap<Key, Entry>,
+    /// Incremented on each use, to order entries by how recently they were used.
+    clock: u64,
+}
+
+struct Entry {
+    /// `None` while the thumbnail is being decoded.
+    thumbnail: Option<Handle>,
+    used: u64,
+}
+
+/// Identifies a thumbnail by the path of its image and the size it was requested at.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Key {
+    path: PathBuf,
+    width: u32,
+    height: u32,
+}
+
+impl Key {
+    /// Creates a key for images loaded from a path, which have a fixed width and/or height.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn new(handle: &Handle, width: Length, height: Length) -> Option<Self> {
+        let Handle::Path(_, path) = handle else {
+            return None;
+        };
+
+        let dimension = |length| match length {
+            Length::Fixed(pixels) => Some(f32::ceil(pixels) as u32),
+            _ => None,
+        };
+
+        let (width, height) = match (dimension(width), dimension(height)) {
+            (Some(width), Some(height)) => (width, height),
+            (Some(width), None) => (width, u32::MAX),
+            (None, Some(height)) => (u32::MAX, height),
+            (None, None) => return None,
+        };
+
+        Some(Self {
+            path: path.clone(),
+            width,
+            height,
+        })
+    }
+
+    /// The size of the placeholder to display while the thumbnail is decoding.
+    #[allow(clippy::cast_precision_loss)]
+    fn placeholder_size(&self) -> Size {
+        let width = if self.width == u32::MAX {
+            self.height
+        } else {
+            self.width
+        };
+
+        let height = if self.height == u32::MAX {
+            self.width
+        } else {
+            self.height
+        };
+
+        Size::new(width as f32, height as f32)
+    }
+}
+
+/// Returns the decoded thumbnail, or begins decoding it in the background.
+pub fn get(key: &Key) -> Option<Handle> {
+    let mut cache = THUMBNAILS.lock().unwrap();
+    cache.clock += 1;
+    let used = cache.clock;
+
+    if let Some(entry) = cache.entries.get_mut(key) {
+        entry.used = used;
+        return entry.thumbnail.clone();
+    }
+
+    if cache.entries.len() >= CAPACITY {
+        let least_recent = cache
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.used)
+            .map(|(key, _)| key.clone());
+
+        if let Some(least_recent) = least_recent {
+            cache.entries.remove(&least_recent);
+        }
+    }
+
+    cache.entries.insert(
+        key.clone(),
+        Entry {
+            thumbnail: None,
+            used,
+        },
+    );
+
+    let key = key.clone();
+    crate::executor::blocking::spawn(move || {
+        let handle = decode(&key).unwrap_or_else(|why| {
+            tracing::error!(?why, path = ?key.path, "failed to decode thumbnail");
+            Handle::from_path(&key.path)
+        });
+
+        // The entry is not restored if it was evicted while decoding.
+        if let Some(entry) = THUMBNAILS.lock().unwrap().entries.get_mut(&key) {
+            entry.thumbnail = Some(handle);
+        }
+
+        LISTENERS
+            .lock()
+            .unwrap()
+            .retain(|listener| listener.unbounded_send(()).is_ok());
+    });
+
+    None
+}
+
+/// Removes a thumbnail from the cache.
+pub fn evict(key: &Key) {
+    THUMBNAILS.lock().unwrap().entries.remove(key);
+}
+
+/// Emits when thumbnails have been decoded, so that the view may be rebuilt to show them.
+pub(crate) fn subscription() -> Subscription<()> {
+    struct Thumbnails;
+
+    Subscription::run_with_id(
+        std::any::TypeId::of::<Thumbnails>(),
+        iced_futures::stream::channel(1, |mut output| async move {
+            let (tx, mut rx) = mpsc::unbounded();
+            LISTENERS.lock().unwrap().push(tx);
+
+            while rx.next().await.is_some() {
+                // Thumbnails which were decoded together are shown together.
+                while let Ok(Some(())) = rx.try_next() {}
+
+                _ = output.send(()).await;
+            }
+        }),
+    )
+}
+
+fn decode(key: &Key) -> image::ImageResult<Handle> {
+    let image = image::open(&key.path)?
+        .thumbnail(key.width, key.height)
+        .into_rgba8();
+
+    Ok(Handle::from_rgba(
+        image.width(),
+        image.height(),
+        image.into_raw(),
+    ))
+}
+
+/// Creates a placeholder which is displayed until the thumbnail has been decoded.
+pub fn pending(key: Key) -> Pending {
+    Pending { key }
+}
+
+/// A placeholder which is displayed until the thumbnail has been decoded.
+pub struct Pending {
+    key: Key,
+}
+
+impl<Message> Widget<Message, crate::Theme, crate::Renderer> for Pending {
+    fn size(&self) -> Size<Length> {
+        let size = self.key.placeholder_size();
+        Size::new(Length::Fixed(size.width), Length::Fixed(size.height))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &crate::Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.key.placeholder_size();
+        layout::Node::new(limits.resolve(
+            Length::Fixed(size.width),
+            Length::Fixed(size.height),
+            size,
+        ))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut crate::Renderer,
+        theme: &crate::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        iced_core::Renderer::fill_quad(
+            renderer,
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: Border {
+                    radius: 9.0.into(),
+                    ..Default::default()
+                },
+                shadow: Shadow::default(),
+            },
+            Background::Color(theme.cosmic().bg_component_color().into()),
+        );
+    }
+}
+
+impl<'a, Message: 'a> From<Pending> for crate::Element<'a, Message> {
+    fn from(pending: Pending) -> Self {
+        Self::new(pending)
+    }
+}
@@ -32,6 +32,7 @@ enum Variant<Message> {
     Image {
         close_icon: svg::Handle,
         on_remove: Option<Message>,
+        thumbnail: Option<super::thumbnail::Key>,
     },
 }
 
@@ -53,6 +54,7 @@ pub struct Button<'a, Message> {
     height: Length,
     padding: Padding,
     selected: bool,
+    loading: Option<svg::Handle>,
     style: crate::theme::Button,
     variant: Variant<Message>,
 }
@@ -75,6 +77,7 @@ impl<'a, Message> Button<'a, Message> {
             height: Length::Shrink,
             padding: Padding::new(5.0),
             selected: false,
+            loading: None,
             style: crate::theme::Button::default(),
             variant: Variant::Normal,
         }
@@ -99,9 +102,11 @@ impl<'a, Message> Button<'a, Message> {
             height: Length::Shrink,
             padding: Padding::new(5.0),
             selected: false,
+            loading: None,
             style: crate::theme::Button::default(),
             variant: Variant::Image {
                 on_remove,
+                thumbnail: None,
                 close_icon: crate::widget::icon::from_name("window-close-symbolic")
                     .size(8)
                     .icon()
@@ -114,6 +119,14 @@ impl<'a, Message> Button<'a, Message> {
         }
     }
 
+    /// Evicts the cached thumbnail of an image button when it is removed.
+    pub(super) fn thumbnail(mut self, key: Option<super::thumbnail::Key>) -> Self {
+        if let Variant::Image { thumbnail, .. } = &mut self.variant {
+            *thumbnail = key;
+        }
+        self
+    }
+
     /// Sets the [`Id`] of the [`Button`].
     pub fn id(mut self, id: Id) -> Self {
         self.id = id;
@@ -163,6 +176,14 @@ impl<'a, Message> Button<'a, Message> {
         self
     }
 
+    /// Displays a spinner in place of the content while an action is in progress.
+    ///
+    /// The button keeps its size, and does not emit messages while it is loading.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading.then(crate::widget::spinner::handle);
+        self
+    }
+
     /// Sets the widget to a selected state.
     ///
     /// Displays a selection indicator on image buttons.
@@ -282,8 +303,17 @@ impl<'a, Message: 'a + Clone> Widget<Message, crate::Theme, crate::Renderer>
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        if self.loading.is_some() {
+            let state = tree.state.downcast_mut::<State>();
+            state.spinner.on_event(&event, shell);
+            return event::Status::Ignored;
+        }
+
+        tree.state.downcast_mut::<State>().spinner.reset();
+
         if let Variant::Image {
             on_remove: Some(on_remove),
+            thumbnail,
             ..
         } = &self.variant
         {
@@ -293,6 +323,10 @@ impl<'a, Message: 'a + Clone> Widget<Message, crate::Theme, crate::Renderer>
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
                     if let Some(position) = cursor.position() {
                         if removal_bounds(layout.bounds(), 4.0).contains(position) {
+                            if let Some(thumbnail) = thumbnail {
+                                super::thumbnail::evict(thumbnail);
+                            }
+
                             shell.publish(on_remove.clone());
                             return event::Status::Captured;
                         }
@@ -348,7 +382,8 @@ impl<'a, Message: 'a + Clone> Widget<Message, crate::Theme, crate::Renderer>
 
         let mut headerbar_alpha = None;
 
-        let is_enabled = self.on_press.is_some() || self.on_press_down.is_some();
+        let is_enabled =
+            self.loading.is_none() && (self.on_press.is_some() || self.on_press_down.is_some());
         let is_mouse_over = cursor.position().is_some_and(|p| bounds.contains(p));
 
         let state = tree.state.downcast_ref::<State>();
@@ -397,6 +432,19 @@ impl<'a, Message: 'a + Clone> Widget<Message, crate::Theme, crate::Renderer>
             *viewport,
             &styling,
             |renderer, _styling| {
+                // The content is laid out but not drawn, so that the size is preserved.
+                if let Some(spinner) = &self.loading {
+                    let center = content_layout.bounds().center();
+                    let bounds = Rectangle {
+                        x: center.x - 8.0,
+                        y: center.y - 8.0,
+                        width: 16.0,
+                        height: 16.0,
+                    };
+                    state.spinner.draw(renderer, spinner, icon_color, bounds);
+                    return;
+                }
+
                 self.content.as_widget().draw(
                     &tree.children[0],
                     renderer,
@@ -417,6 +465,7 @@ impl<'a, Message: 'a + Clone> Widget<Message, crate::Theme, crate::Renderer>
         if let Variant::Image {
             close_icon,
             on_remove,
+            ..
         } = &self.variant
         {
             renderer.with_layer(*viewport, |renderer| {
@@ -523,7 +572,11 @@ impl<'a, Message: 'a + Clone> Widget<Message, crate::Theme, crate::Renderer>
         _viewport: &Rectangle,
         _renderer: &crate::Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor, self.on_press.is_some())
+        mouse_interaction(
+            layout,
+            cursor,
+            self.loading.is_none() && self.on_press.is_some(),
+        )
     }
 
     fn overlay<'b>(
@@ -641,6 +694,7 @@ pub struct State {
     is_hovered: bool,
     is_pressed: bool,
     is_focused: bool,
+    spinner: crate::widget::spinner::Animation,
 }
 
 impl State {
@@ -21,6 +21,9 @@ pub fn calendar<M>(
         on_select: Box::new(on_select),
         on_prev: Box::new(on_prev),
         on_next: Box::new(on_next),
+        is_enabled: None,
+        first_day_of_week: Weekday::Sun,
+        show_week_numbers: false,
     }
 }
 
@@ -46,20 +49,70 @@ pub fn set_day(date_selected: NaiveDate, day: u32) -> NaiveDate {
 pub struct CalendarModel {
     pub selected: NaiveDate,
     pub visible: NaiveDate,
+    /// The last day of the selected range, which begins at `selected`.
+    pub range_end: Option<NaiveDate>,
+    /// Whether selections are made as a range of dates.
+    pub range_selection: bool,
+    /// Whether the next selection sets the end of the range.
+    awaiting_range_end: bool,
 }
 
 impl CalendarModel {
     pub fn now() -> Self {
         let now = Local::now();
         let naive_now = NaiveDate::from(now.naive_local());
+        CalendarModel::new(naive_now, naive_now)
+    }
+
+    pub fn new(selected: NaiveDate, visible: NaiveDate) -> Self {
         CalendarModel {
-            selected: naive_now.clone(),
-            visible: naive_now,
+            selected,
+            visible,
+            range_end: None,
+            range_selection: false,
+            awaiting_range_end: false,
         }
     }
 
-    pub fn new(selected: NaiveDate, visible: NaiveDate) -> Self {
-        CalendarModel { selected, visible }
+    /// Select a range of dates instead of a single date.
+    ///
+    /// The first selection sets the start of the range, and the second sets its end.
+    #[must_use]
+    pub fn range_selection(mut self, range_selection: bool) -> Self {
+        self.range_selection = range_selection;
+        self
+    }
+
+    /// The selected range of dates, if a range has been selected.
+    pub fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.range_end.map(|end| (self.selected, end))
+    }
+
+    /// Whether the date is within the selected range, or is the selected date.
+    pub fn is_selected(&self, date: NaiveDate) -> bool {
+        match self.range_end {
+            Some(end) => self.selected <= date && date <= end,
+            None => self.selected == date,
+        }
+    }
+
+    /// Handles the selection of a date.
+    ///
+    /// In range selection mode, this alternates between setting the start and the end of
+    /// the range. The range is reordered if the end precedes the start.
+    pub fn select(&mut self, date: NaiveDate) {
+        if !self.range_selection {
+            self.selected = date;
+            self.range_end = None;
+        } else if self.awaiting_range_end {
+            self.range_end = Some(cmp::max(self.selected, date));
+            self.selected = cmp::min(self.selected, date);
+            self.awaiting_range_end = false;
+        } else {
+            self.selected = date;
+            self.range_end = None;
+            self.awaiting_range_end = true;
+        }
     }
 
     pub fn show_prev_month(&mut self) {
@@ -103,6 +156,34 @@ pub struct Calendar<'a, M> {
     on_select: Box<dyn Fn(NaiveDate) -> M>,
     on_prev: Box<dyn Fn() -> M>,
     on_next: Box<dyn Fn() -> M>,
+    is_enabled: Option<Box<dyn Fn(NaiveDate) -> bool + 'a>>,
+    first_day_of_week: Weekday,
+    show_week_numbers: bool,
+}
+
+impl<'a, M> Calendar<'a, M> {
+    /// Dates which are not enabled are greyed out and cannot be selected.
+    #[must_use]
+    pub fn is_enabled(mut self, is_enabled: impl Fn(NaiveDate) -> bool + 'a) -> Self {
+        self.is_enabled = Some(Box::new(is_enabled));
+        self
+    }
+
+    /// The day which each week begins with. Defaults to [`Weekday::Sun`].
+    ///
+    /// Use [`locale_first_day_of_week`] to follow the user's locale.
+    #[must_use]
+    pub fn first_day_of_week(mut self, first_day_of_week: Weekday) -> Self {
+        self.first_day_of_week = first_day_of_week;
+        self
+    }
+
+    /// Display the ISO 8601 week number in a leading column.
+    #[must_use]
+    pub fn show_week_numbers(mut self, show_week_numbers: bool) -> Self {
+        self.show_week_numbers = show_week_numbers;
+        self
+    }
 }
 
 impl<'a, Message> From<Calendar<'a, Message>> for crate::Element<'a, Message>
@@ -128,7 +209,12 @@ where
         let mut calendar_grid: Grid<'_, Message> =
             grid().padding([0, 12].into()).width(Length::Fill);
 
-        let mut first_day_of_week = Weekday::Sun; // TODO: Configurable
+        if this.show_week_numbers {
+            calendar_grid =
+                calendar_grid.push(crate::widget::Space::with_width(Length::Fixed(36.0)));
+        }
+
+        let mut first_day_of_week = this.first_day_of_week;
         for _ in 0..7 {
             calendar_grid = calendar_grid.push(
                 text(first_day_of_week.to_string())
@@ -153,19 +239,28 @@ where
             }
 
             let date = day_iter.next().unwrap();
+
+            if this.show_week_numbers && i % 7 == 0 {
+                calendar_grid = calendar_grid.push(week_number(date));
+            }
+
             let is_currently_viewed_month = date.month() == this.model.visible.month()
                 && date.year_ce() == this.model.visible.year_ce();
-            let is_currently_selected_month = date.month() == this.model.selected.month()
-                && date.year_ce() == this.model.selected.year_ce();
-            let is_currently_selected_day =
-                date.day() == this.model.selected.day() && is_currently_selected_month;
-
-            calendar_grid = calendar_grid.push(date_button(
-                date,
-                is_currently_viewed_month,
-                is_currently_selected_day,
-                &this.on_select,
-            ));
+            let selection = if !this.model.is_selected(date) {
+                DateSelection::None
+            } else if date == this.model.selected || Some(date) == this.model.range_end {
+                DateSelection::Selected
+            } else {
+                DateSelection::InRange
+            };
+            let is_enabled = is_currently_viewed_month
+                && this
+                    .is_enabled
+                    .as_ref()
+                    .map_or(true, |is_enabled| is_enabled(date));
+
+            calendar_grid =
+                calendar_grid.push(date_button(date, is_enabled, selection, &this.on_select));
         }
 
         let content_list = column::with_children(vec![
@@ -186,16 +281,26 @@ where
     }
 }
 
+/// How a date is highlighted by the selection.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DateSelection {
+    None,
+    /// Within a selected range.
+    InRange,
+    /// The selected date, or an end of the selected range.
+    Selected,
+}
+
 fn date_button<Message>(
     date: NaiveDate,
-    is_currently_viewed_month: bool,
-    is_currently_selected_day: bool,
+    is_enabled: bool,
+    selection: DateSelection,
     on_select: &dyn Fn(NaiveDate) -> Message,
 ) -> crate::widget::Button<'static, Message> {
-    let style = if is_currently_selected_day {
-        button::ButtonClass::Suggested
-    } else {
-        button::ButtonClass::Text
+    let style = match selection {
+        DateSelection::Selected => button::ButtonClass::Suggested,
+        DateSelection::InRange => button::ButtonClass::Standard,
+        DateSelection::None => button::ButtonClass::Text,
     };
 
     let button = button::custom(text(format!("{}", date.day())).center())
@@ -203,13 +308,69 @@ fn date_button<Message>(
         .height(Length::Fixed(36.0))
         .width(Length::Fixed(36.0));
 
-    if is_currently_viewed_month {
+    if is_enabled {
         button.on_press((on_select)(set_day(date, date.day())))
     } else {
         button
     }
 }
 
+/// The ISO 8601 week number of the week which begins on `first_date`.
+fn week_number<'a, Message: 'static>(first_date: NaiveDate) -> crate::Element<'a, Message> {
+    // The ISO week of a week is the week of its Thursday, regardless of its first day.
+    let offset = (Weekday::Thu as u64 + 7 - first_date.weekday() as u64) % 7;
+    let thursday = first_date
+        .checked_add_days(Days::new(offset))
+        .unwrap_or(first_date);
+
+    text(thursday.iso_week().week().to_string())
+        .size(12)
+        .width(Length::Fixed(36.0))
+        .height(Length::Fixed(36.0))
+        .align_x(Alignment::Center)
+        .align_y(Alignment::Center)
+        .class(crate::theme::Text::Custom(|theme| {
+            let mut color: crate::iced::Color = theme.cosmic().on_bg_color().into();
+            color.a = 0.6;
+            crate::iced_widget::text::Style { color: Some(color) }
+        }))
+        .into()
+}
+
+/// The first day of the week in the user's locale.
+///
+/// The region is read from the `LC_ALL`, `LC_TIME` or `LANG` environment variables,
+/// and defaults to [`Weekday::Mon`] when it is unknown.
+#[must_use]
+pub fn locale_first_day_of_week() -> Weekday {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|locale| !locale.is_empty())
+        .and_then(|locale| {
+            // Locales are formatted as `language_REGION.encoding@modifier`.
+            let locale = locale.split(['.', '@']).next()?;
+            let (_, region) = locale.split_once('_')?;
+            Some(first_day_of_week_in_region(region))
+        })
+        .unwrap_or(Weekday::Mon)
+}
+
+/// The first day of the week in a region, as given by its ISO 3166 code.
+fn first_day_of_week_in_region(region: &str) -> Weekday {
+    match region {
+        "AG" | "AS" | "AU" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CN" | "CO"
+        | "DM" | "DO" | "ET" | "GT" | "GU" | "HK" | "HN" | "ID" | "IL" | "IN" | "JM" | "JP"
+        | "KE" | "KH" | "KR" | "LA" | "MH" | "MM" | "MO" | "MT" | "MX" | "MZ" | "NI" | "NP"
+        | "PA" | "PE" | "PH" | "PK" | "PR" | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT"
+        | "TW" | "UM" | "US" | "VE" | "VI" | "WS" | "YE" | "ZA" | "ZW" => Weekday::Sun,
+        "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM"
+        | "QA" | "SD" | "SY" => Weekday::Sat,
+        "MV" => Weekday::Fri,
+        _ => Weekday::Mon,
+    }
+}
+
 /// Gets the first date that will be visible on the calender
 #[must_use]
 pub fn get_calender_first(year: i32, month: u32, from_weekday: Weekday) -> NaiveDate {
@@ -0,0 +1,136 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A compact, pill-shaped label, such as a tag or an active filter.
+//!
+//! ```no_run
+//! # use cosmic::widget::{chip, flex_row};
+//! # #[derive(Clone)]
+//! # enum Message { Remove(usize) }
+//! let tags = ["rust", "wayland", "gtk"];
+//!
+//! let chips = flex_row(
+//!     tags.iter()
+//!         .enumerate()
+//!         .map(|(id, tag)| chip(*tag).on_close(Message::Remove(id)).into())
+//!         .collect(),
+//! );
+//! ```
+
+use super::icon;
+use crate::{theme, widget, Element, Renderer, Theme};
+use apply::Apply;
+use iced::{Alignment, Background, Color};
+use iced_core::{Border, Shadow};
+use std::borrow::Cow;
+
+/// A compact, pill-shaped label, with an optional icon and close button.
+#[must_use]
+pub fn chip<'a, Message>(label: impl Into<Cow<'a, str>>) -> Chip<'a, Message> {
+    Chip {
+        label: label.into(),
+        icon: None,
+        on_close: None,
+        selected: false,
+    }
+}
+
+/// A compact, pill-shaped label, with an optional icon and close button.
+#[must_use]
+pub struct Chip<'a, Message> {
+    label: Cow<'a, str>,
+    icon: Option<icon::Handle>,
+    on_close: Option<Message>,
+    selected: bool,
+}
+
+impl<'a, Message: 'static + Clone> Chip<'a, Message> {
+    /// Shows an icon before the label.
+    pub fn icon(mut self, icon: impl Into<icon::Handle>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Shows a close button after the label, which emits this message when pressed.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Highlights the chip with the accent color, such as for an active filter.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// A container with the label, icon, and close button of the chip.
+    pub fn into_widget(self) -> widget::Container<'a, Message, crate::Theme, Renderer> {
+        let spacing = theme::active().cosmic().spacing;
+        let selected = self.selected;
+
+        let mut row = widget::row::with_capacity(3)
+            .spacing(spacing.space_xxs)
+            .align_y(Alignment::Center);
+
+        if let Some(icon) = self.icon {
+            row = row.push(icon::icon(icon).size(16));
+        }
+
+        row = row.push(widget::text::body(self.label));
+
+        // The close button is padded by the container on every side except the end.
+        let end_padding = if let Some(message) = self.on_close {
+            row = row.push(
+                icon::from_name("window-close-symbolic")
+                    .size(16)
+                    .apply(widget::button::icon)
+                    .padding(0)
+                    .on_press(message),
+            );
+            spacing.space_xxs
+        } else {
+            spacing.space_s
+        };
+
+        row.apply(widget::container)
+            .class(theme::Container::custom(move |theme| {
+                chip_container(theme, selected)
+            }))
+            .padding([
+                spacing.space_xxxs,
+                end_padding,
+                spacing.space_xxxs,
+                spacing.space_s,
+            ])
+            .align_y(Alignment::Center)
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<Chip<'a, Message>> for Element<'a, Message> {
+    fn from(chip: Chip<'a, Message>) -> Self {
+        Self::from(chip.into_widget())
+    }
+}
+
+/// The style of a chip, which is neutral unless selected.
+#[must_use]
+pub fn chip_container(theme: &Theme, selected: bool) -> widget::container::Style {
+    let cosmic = theme.cosmic();
+    let component = if selected {
+        &cosmic.accent_button
+    } else {
+        &cosmic.button
+    };
+
+    widget::container::Style {
+        icon_color: Some(component.on.into()),
+        text_color: Some(component.on.into()),
+        background: Some(Background::Color(component.base.into())),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: cosmic.corner_radii.radius_xl.into(),
+        },
+        shadow: Shadow::default(),
+    }
+}
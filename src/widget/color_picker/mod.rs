@@ -52,9 +52,15 @@ lazy_static! {
 
 const MAX_RECENT: usize = 20;
 
+/// The config key which shared recent colors are stored under.
+///
+/// See [`ColorPickerModel::with_shared_recents`].
+pub const RECENT_COLORS_KEY: &str = "color_picker_recent_colors";
+
 #[derive(Debug, Clone)]
 pub enum ColorPickerUpdate {
     ActiveColor(palette::Hsv),
+    ActiveAlpha(f32),
     ActionFinished,
     Input(String),
     AppliedColor,
@@ -63,6 +69,10 @@ pub enum ColorPickerUpdate {
     Copied(Instant),
     Cancel,
     ToggleColorPicker,
+    /// Pick a color from the screen. Handled by [`ColorPickerModel::update_with`].
+    ///
+    /// Only emitted when enabled with [`ColorPickerModel::pick_from_screen`].
+    Pick,
 }
 
 #[derive(Setters)]
@@ -72,6 +82,15 @@ pub struct ColorPickerModel {
     #[setters(skip)]
     active_color: palette::Hsv,
     #[setters(skip)]
+    alpha: f32,
+    /// Show a slider for the alpha channel, and include it in formatted colors.
+    alpha_slider: bool,
+    /// Show a button to pick a color from the screen.
+    ///
+    /// Updates must then be handled with [`ColorPickerModel::update_with`], which picks
+    /// the color.
+    pick_from_screen: bool,
+    #[setters(skip)]
     save_next: Option<Color>,
     #[setters(skip)]
     input_color: String,
@@ -81,6 +100,8 @@ pub struct ColorPickerModel {
     fallback_color: Option<Color>,
     #[setters(skip)]
     recent_colors: Vec<Color>,
+    #[setters(skip)]
+    shared_recents: Option<cosmic_config::Config>,
     active: bool,
     width: Length,
     height: Length,
@@ -107,11 +128,15 @@ impl ColorPickerModel {
                 .insert(move |b| b.text(rgb.clone()))
                 .build(),
             active_color: hsv,
+            alpha: initial.map_or(1.0, |c| c.a),
+            alpha_slider: false,
+            pick_from_screen: false,
             save_next: None,
-            input_color: color_to_string(hsv, true),
+            input_color: color_to_string(hsv, None, true),
             applied_color: initial,
             fallback_color,
-            recent_colors: Vec::new(), // TODO should all color pickers show the same recent colors?
+            recent_colors: Vec::new(),
+            shared_recents: None,
             active: false,
             width: Length::Fixed(300.0),
             height: Length::Fixed(200.0),
@@ -120,6 +145,47 @@ impl ColorPickerModel {
         }
     }
 
+    /// Share recent colors with other color pickers through a config.
+    ///
+    /// Recent colors are stored under [`RECENT_COLORS_KEY`], and are reloaded whenever
+    /// the color picker is opened. By default, recent colors are kept in memory.
+    #[must_use]
+    pub fn with_shared_recents(mut self, config: cosmic_config::Config) -> Self {
+        self.shared_recents = Some(config);
+        self.load_recent_colors();
+        self
+    }
+
+    fn load_recent_colors(&mut self) {
+        let Some(config) = self.shared_recents.as_ref() else {
+            return;
+        };
+
+        match cosmic_config::ConfigGet::get::<Vec<[f32; 4]>>(config, RECENT_COLORS_KEY) {
+            Ok(colors) => {
+                self.recent_colors = colors.into_iter().map(Color::from).collect();
+            }
+            Err(why) if why.is_not_found() => (),
+            Err(why) => tracing::error!(?why, "failed to load recent colors"),
+        }
+    }
+
+    fn save_recent_colors(&self) {
+        let Some(config) = self.shared_recents.as_ref() else {
+            return;
+        };
+
+        let colors = self
+            .recent_colors
+            .iter()
+            .map(|c| [c.r, c.g, c.b, c.a])
+            .collect::<Vec<_>>();
+
+        if let Err(why) = cosmic_config::ConfigSet::set(config, RECENT_COLORS_KEY, colors) {
+            tracing::error!(?why, "failed to save recent colors");
+        }
+    }
+
     /// Get a color picker button that displays the applied color
     ///
     pub fn picker_button<'a, Message: 'static, T: Fn(ColorPickerUpdate) -> Message>(
@@ -138,25 +204,33 @@ impl ColorPickerModel {
         match update {
             ColorPickerUpdate::ActiveColor(c) => {
                 self.must_clear_cache.store(true, Ordering::SeqCst);
-                self.input_color = color_to_string(c, self.is_hex());
+                self.input_color = color_to_string(c, self.display_alpha(), self.is_hex());
                 if let Some(to_save) = self.save_next.take() {
                     self.recent_colors.insert(0, to_save);
                     self.recent_colors.truncate(MAX_RECENT);
+                    self.save_recent_colors();
                 }
                 self.active_color = c;
                 self.copied_at = None;
             }
+            ColorPickerUpdate::ActiveAlpha(alpha) => {
+                self.alpha = alpha.clamp(0.0, 1.0);
+                self.input_color =
+                    color_to_string(self.active_color, self.display_alpha(), self.is_hex());
+                self.copied_at = None;
+            }
             ColorPickerUpdate::AppliedColor => {
-                let srgb = palette::Srgb::from_color(self.active_color);
                 if let Some(applied_color) = self.applied_color.take() {
                     self.recent_colors.push(applied_color);
+                    self.save_recent_colors();
                 }
-                self.applied_color = Some(Color::from(srgb));
+                self.applied_color = Some(self.active_rgba());
                 self.active = false;
             }
             ColorPickerUpdate::ActivateSegmented(e) => {
                 self.segmented_model.activate(e);
-                self.input_color = color_to_string(self.active_color, self.is_hex());
+                self.input_color =
+                    color_to_string(self.active_color, self.display_alpha(), self.is_hex());
                 self.copied_at = None;
             }
             ColorPickerUpdate::Copied(t) => {
@@ -170,6 +244,7 @@ impl ColorPickerModel {
                 let initial_srgb = palette::Srgb::from(self.fallback_color.unwrap_or(Color::BLACK));
                 let hsv = palette::Hsv::from_color(initial_srgb);
                 self.active_color = hsv;
+                self.alpha = self.fallback_color.map_or(1.0, |c| c.a);
                 self.applied_color = self.fallback_color;
                 self.copied_at = None;
             }
@@ -188,26 +263,65 @@ impl ColorPickerModel {
                 if let Ok(c) = self.input_color.parse::<css_color::Srgb>() {
                     self.active_color =
                         palette::Hsv::from_color(palette::Srgb::new(c.red, c.green, c.blue));
+                    if self.alpha_slider {
+                        self.alpha = c.alpha;
+                    }
                 }
             }
             ColorPickerUpdate::ActionFinished => {
-                let srgb = palette::Srgb::from_color(self.active_color);
+                let color = self.active_rgba();
                 if let Some(applied_color) = self.applied_color.take() {
                     self.recent_colors.push(applied_color);
+                    self.save_recent_colors();
                 }
-                self.applied_color = Some(Color::from(srgb));
+                self.applied_color = Some(color);
                 self.active = false;
-                self.save_next = Some(Color::from(srgb));
+                self.save_next = Some(color);
             }
             ColorPickerUpdate::ToggleColorPicker => {
                 self.must_clear_cache.store(true, Ordering::SeqCst);
                 self.active = !self.active;
                 self.copied_at = None;
+                if self.active {
+                    self.load_recent_colors();
+                }
             }
+            // Picking requires the message mapping given to `update_with`.
+            ColorPickerUpdate::Pick => {}
         };
         Task::none()
     }
 
+    /// Handles an update like [`Self::update`], and picks a color from the screen on
+    /// [`ColorPickerUpdate::Pick`].
+    ///
+    /// The picked color is emitted as a [`ColorPickerUpdate::ActiveColor`]. Nothing is
+    /// emitted if the color picker portal is unavailable, or the pick was cancelled.
+    pub fn update_with<Message: Send + 'static>(
+        &mut self,
+        update: ColorPickerUpdate,
+        on_update: fn(ColorPickerUpdate) -> Message,
+    ) -> Task<Message> {
+        if let ColorPickerUpdate::Pick = update {
+            return pick_color()
+                .and_then(move |hsv| Task::done(on_update(ColorPickerUpdate::ActiveColor(hsv))));
+        }
+
+        self.update(update)
+    }
+
+    /// The alpha to display, if the alpha slider is enabled.
+    fn display_alpha(&self) -> Option<f32> {
+        self.alpha_slider.then_some(self.alpha)
+    }
+
+    /// The active color, with the alpha channel if the alpha slider is enabled.
+    fn active_rgba(&self) -> Color {
+        let mut color = Color::from(palette::Srgb::from_color(self.active_color));
+        color.a = self.display_alpha().unwrap_or(1.0);
+        color
+    }
+
     #[must_use]
     pub fn is_hex(&self) -> bool {
         self.segmented_model.position(self.segmented_model.active()) == Some(0)
@@ -233,6 +347,8 @@ impl ColorPickerModel {
         ColorPickerBuilder {
             model: &self.segmented_model,
             active_color: self.active_color,
+            alpha: self.display_alpha(),
+            pick_from_screen: self.pick_from_screen,
             recent_colors: &self.recent_colors,
             on_update,
             width: self.width,
@@ -254,6 +370,10 @@ pub struct ColorPickerBuilder<'a, Message> {
     #[setters(skip)]
     active_color: palette::Hsv,
     #[setters(skip)]
+    alpha: Option<f32>,
+    #[setters(skip)]
+    pick_from_screen: bool,
+    #[setters(skip)]
     input_color: &'a str,
     #[setters(skip)]
     on_update: fn(ColorPickerUpdate) -> Message,
@@ -287,6 +407,11 @@ where
     ) -> ColorPicker<'a, Message> {
         let on_update = self.on_update;
         let spacing = THEME.lock().unwrap().cosmic().spacing;
+
+        let pick_button = (cfg!(feature = "xdg-portal") && self.pick_from_screen).then(|| {
+            button::icon(from_name("color-select-symbolic"))
+                .on_press(on_update(ColorPickerUpdate::Pick))
+        });
         let mut inner =
             column![
                 // segmented buttons
@@ -466,17 +591,34 @@ where
                     }),
                 })
                 .width(self.width),
-                text_input("", self.input_color)
+            ]
+            .push_maybe(self.alpha.map(|alpha| {
+                let color = Color::from(palette::Srgb::from_color(self.active_color));
+                slider(0.0..=1.0, alpha, move |v| {
+                    on_update(ColorPickerUpdate::ActiveAlpha(v))
+                })
+                .step(0.01)
+                .on_release(on_update(ColorPickerUpdate::ActionFinished))
+                .class(alpha_slider_class(color))
+                .width(self.width)
+            }))
+            .push(
+                row![text_input("", self.input_color)
                     .on_input(move |s| on_update(ColorPickerUpdate::Input(s)))
                     .on_paste(move |s| on_update(ColorPickerUpdate::Input(s)))
                     .on_submit(on_update(ColorPickerUpdate::AppliedColor))
                     .leading_icon(
                         color_button(
                             None,
-                            Some(Color::from(palette::Srgb::from_color(self.active_color))),
-                            Length::FillPortion(12)
+                            Some({
+                                let mut color =
+                                    Color::from(palette::Srgb::from_color(self.active_color));
+                                color.a = self.alpha.unwrap_or(1.0);
+                                color
+                            }),
+                            Length::FillPortion(12),
                         )
-                        .into()
+                        .into(),
                     )
                     // TODO copy paste input contents
                     .trailing_icon({
@@ -506,8 +648,12 @@ where
                             .into(),
                         }
                     })
-                    .width(self.width),
-            ]
+                    .width(Length::Fill)]
+                .push_maybe(pick_button)
+                .spacing(spacing.space_xxs)
+                .align_y(iced_core::Alignment::Center)
+                .width(self.width),
+            )
             // Should we ensure the side padding is at least half the width of the handle?
             .padding([
                 spacing.space_none,
@@ -876,13 +1022,85 @@ impl State {
 
 impl<'a, Message> ColorPicker<'a, Message> where Message: Clone + 'static {}
 // TODO convert active color to hex or rgba
-fn color_to_string(c: palette::Hsv, is_hex: bool) -> String {
+/// Picks a color from the screen through the xdg-desktop-portal.
+#[allow(clippy::cast_possible_truncation)]
+fn pick_color() -> Task<Option<palette::Hsv>> {
+    #[cfg(feature = "xdg-portal")]
+    {
+        Task::future(async {
+            match ashpd::desktop::Color::pick()
+                .send()
+                .await
+                .and_then(|request| request.response())
+            {
+                Ok(color) => Some(palette::Hsv::from_color(palette::Srgb::new(
+                    color.red() as f32,
+                    color.green() as f32,
+                    color.blue() as f32,
+                ))),
+                Err(why) => {
+                    tracing::warn!(?why, "failed to pick a color from the screen");
+                    None
+                }
+            }
+        })
+    }
+
+    #[cfg(not(feature = "xdg-portal"))]
+    Task::none()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn color_to_string(c: palette::Hsv, alpha: Option<f32>, is_hex: bool) -> String {
     let srgb = palette::Srgb::from_color(c);
     let hex = srgb.into_format::<u8>();
-    if is_hex {
-        format!("#{:02X}{:02X}{:02X}", hex.red, hex.green, hex.blue)
-    } else {
-        format!("rgb({}, {}, {})", hex.red, hex.green, hex.blue)
+    match (alpha, is_hex) {
+        (None, true) => format!("#{:02X}{:02X}{:02X}", hex.red, hex.green, hex.blue),
+        (None, false) => format!("rgb({}, {}, {})", hex.red, hex.green, hex.blue),
+        (Some(alpha), true) => format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            hex.red,
+            hex.green,
+            hex.blue,
+            (alpha.clamp(0.0, 1.0) * 255.0).round() as u8
+        ),
+        (Some(alpha), false) => format!(
+            "rgba({}, {}, {}, {:.2})",
+            hex.red, hex.green, hex.blue, alpha
+        ),
+    }
+}
+
+/// A slider rail which fades from transparent to the opaque color.
+fn alpha_slider_class(color: Color) -> Slider {
+    let style = move |t: &crate::Theme| {
+        let cosmic = t.cosmic();
+        let mut a = slider::Catalog::style(t, &Slider::default(), slider::Status::Active);
+        let gradient = Background::Gradient(iced::Gradient::Linear(
+            Linear::new(Radians(90.0)).add_stops([
+                ColorStop {
+                    color: Color { a: 0.0, ..color },
+                    offset: 0.0,
+                },
+                ColorStop {
+                    color: Color { a: 1.0, ..color },
+                    offset: 1.0,
+                },
+            ]),
+        ));
+        a.rail.backgrounds = (gradient, gradient);
+        a.rail.width = 8.0;
+        a.handle.background = Color::TRANSPARENT.into();
+        a.handle.shape = HandleShape::Circle { radius: 8.0 };
+        a.handle.border_color = cosmic.palette.neutral_10.into();
+        a.handle.border_width = 4.0;
+        a
+    };
+
+    Slider::Custom {
+        active: Rc::new(style),
+        hovered: Rc::new(style),
+        dragging: Rc::new(style),
     }
 }
 
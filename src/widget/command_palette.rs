@@ -0,0 +1,252 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A searchable list of the application's menu actions, with their key bindings.
+//!
+//! Display it from [`Application::dialog`](crate::Application::dialog) when an action bound
+//! to Ctrl+Shift+P is triggered, and hide it again once an action has been selected.
+//!
+//! ```no_run
+//! # use std::collections::HashMap;
+//! # use cosmic::widget::{command_palette, menu};
+//! # #[derive(Clone, Copy, Eq, PartialEq)]
+//! # enum Action { Open, Save }
+//! # impl menu::Action for Action {
+//! #     type Message = Message;
+//! #     fn message(&self) -> Message { Message::Action(*self) }
+//! # }
+//! # #[derive(Clone)]
+//! # enum Message { Action(Action), Query(String) }
+//! # let (query, key_binds) = (String::new(), HashMap::<menu::KeyBind, Action>::new());
+//! let actions = [("&Open", Action::Open), ("&Save", Action::Save)];
+//!
+//! let palette = command_palette(actions, &key_binds)
+//!     .query(&query)
+//!     .on_input(Message::Query);
+//! ```
+
+use super::menu;
+use crate::{style, theme, widget, Element};
+use iced::Length;
+use iced_core::widget::Id;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A searchable list of menu actions, which emits the message of the selected action.
+///
+/// Actions are labeled as they are in menus, without their mnemonic markers, and shown with
+/// the key binding which triggers them, if any. A literal `&` before a character is written
+/// as `&&`, as in menu labels.
+pub fn command_palette<'a, A, L>(
+    actions: impl IntoIterator<Item = (L, A)>,
+    key_binds: &HashMap<impl fmt::Display, A>,
+) -> CommandPalette<'a, A>
+where
+    A: menu::Action,
+    L: AsRef<str>,
+{
+    let entries = actions
+        .into_iter()
+        .map(|(label, action)| Entry {
+            label: menu::parse_mnemonic(label.as_ref()).0,
+            key_bind: key_binds
+                .iter()
+                .find(|(_, bound)| **bound == action)
+                .map(|(key_bind, _)| key_bind.to_string())
+                .unwrap_or_default(),
+            action,
+        })
+        .collect();
+
+    CommandPalette {
+        entries,
+        query: Cow::Borrowed(""),
+        placeholder: Cow::Borrowed(""),
+        on_input: None,
+        id: None,
+        max_results: 10,
+    }
+}
+
+/// An action listed by a [`CommandPalette`].
+struct Entry<A> {
+    label: String,
+    key_bind: String,
+    action: A,
+}
+
+/// A searchable list of menu actions, which emits the message of the selected action.
+#[must_use]
+pub struct CommandPalette<'a, A: menu::Action> {
+    entries: Vec<Entry<A>>,
+    query: Cow<'a, str>,
+    placeholder: Cow<'a, str>,
+    on_input: Option<Box<dyn Fn(String) -> A::Message + 'a>>,
+    id: Option<Id>,
+    max_results: usize,
+}
+
+impl<'a, A: menu::Action> CommandPalette<'a, A> {
+    /// The text which actions are filtered by.
+    pub fn query(mut self, query: impl Into<Cow<'a, str>>) -> Self {
+        self.query = query.into();
+        self
+    }
+
+    /// Text displayed in the search input while the query is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<Cow<'a, str>>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Emitted when the query is changed.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> A::Message + 'a) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    /// Sets the [`Id`] of the search input, so that it may be focused when the palette is shown.
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The maximum number of actions listed at once.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Actions which match the query, from the best match to the worst.
+    fn matches(&self) -> Vec<&Entry<A>> {
+        let mut matches = self
+            .entries
+            .iter()
+            .filter_map(|entry| Some((fuzzy_score(&entry.label, &self.query)?, entry)))
+            .collect::<Vec<_>>();
+
+        // Stable, so that equal matches keep the order they were given in.
+        matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        matches
+            .into_iter()
+            .take(self.max_results)
+            .map(|(_, entry)| entry)
+            .collect()
+    }
+}
+
+impl<'a, A> From<CommandPalette<'a, A>> for Element<'a, A::Message>
+where
+    A: menu::Action + 'a,
+    A::Message: Clone + 'static,
+{
+    fn from(mut palette: CommandPalette<'a, A>) -> Self {
+        let spacing = theme::active().cosmic().spacing;
+        let on_input = palette.on_input.take();
+        let id = palette.id.take();
+        let matches = palette.matches();
+
+        let mut input = widget::search_input(palette.placeholder.clone(), palette.query.clone())
+            // Pressing enter selects the best match.
+            .on_submit_maybe(matches.first().map(|entry| entry.action.message()));
+
+        if let Some(id) = id {
+            input = input.id(id);
+        }
+
+        if let Some(on_input) = on_input {
+            input = input.on_input(on_input);
+        }
+
+        let results = matches
+            .into_iter()
+            .map(|entry| {
+                menu::menu_button(vec![
+                    widget::text(entry.label.clone()).into(),
+                    widget::horizontal_space().into(),
+                    widget::text(entry.key_bind.clone()).into(),
+                ])
+                .on_press(entry.action.message())
+                .into()
+            })
+            .collect::<Vec<_>>();
+
+        let mut content = widget::column::with_capacity(2)
+            .push(input)
+            .spacing(spacing.space_xxs);
+
+        if !results.is_empty() {
+            content = content.push(widget::scrollable(widget::column::with_children(results)));
+        }
+
+        Element::from(
+            widget::container(content)
+                .class(style::Container::Dialog)
+                .padding(spacing.space_xxs)
+                .width(Length::Fixed(570.0))
+                .max_height(480.0),
+        )
+    }
+}
+
+/// Scores how well a query matches a label, where every character of the query must appear in
+/// the label in order, ignoring case and whitespace.
+///
+/// Characters which match consecutively, or at the start of a word, score higher.
+fn fuzzy_score(label: &str, query: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let mut wanted = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut consecutive = false;
+
+    for c in label.to_lowercase().chars() {
+        let Some(&next) = wanted.peek() else {
+            break;
+        };
+
+        if c == next {
+            wanted.next();
+            score += 1;
+
+            if consecutive {
+                score += 2;
+            }
+
+            if previous.map_or(true, |previous| !previous.is_alphanumeric()) {
+                score += 3;
+            }
+
+            consecutive = true;
+        } else {
+            consecutive = false;
+        }
+
+        previous = Some(c);
+    }
+
+    wanted.peek().is_none().then_some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_score;
+
+    #[test]
+    fn fuzzy_score_matches_in_order() {
+        assert_eq!(fuzzy_score("Open", ""), Some(0));
+        assert!(fuzzy_score("Save As", "sva").is_some());
+        assert!(fuzzy_score("Save As", "SAVE AS").is_some());
+        assert_eq!(fuzzy_score("Save As", "as s"), None);
+        assert_eq!(fuzzy_score("Open", "sa"), None);
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_word_starts() {
+        let save_as = fuzzy_score("Save As", "sa").unwrap();
+        let disable = fuzzy_score("Disable", "sa").unwrap();
+        assert!(save_as > disable);
+    }
+}
@@ -4,10 +4,12 @@
 //! An overlayed widget that attaches a toggleable context drawer to the view.
 
 mod overlay;
+mod resize_handle;
 
 mod widget;
 use std::borrow::Cow;
 
+pub use resize_handle::WIDTH as RESIZE_HANDLE_WIDTH;
 pub use widget::ContextDrawer;
 
 use crate::Element;
@@ -0,0 +1,156 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A handle on the leading edge of the context drawer which resizes it when dragged.
+
+use crate::{Renderer, Theme};
+
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, renderer, touch, Clipboard, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+/// Width of the area which may be dragged to resize the drawer.
+pub const WIDTH: f32 = 4.0;
+
+/// Creates a handle which emits the new width of a drawer of `width` as it is dragged, and
+/// once more with `on_resize_end` when it is released.
+pub fn resize_handle<'a, Message>(
+    width: f32,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+    on_resize_end: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+) -> ResizeHandle<'a, Message> {
+    ResizeHandle {
+        width,
+        on_resize,
+        on_resize_end,
+    }
+}
+
+/// A handle which resizes the drawer that it is placed before.
+pub struct ResizeHandle<'a, Message> {
+    width: f32,
+    on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+    on_resize_end: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The position of the cursor, and the width of the drawer, when dragging began.
+    drag_origin: Option<(f32, f32)>,
+    /// The width which was last emitted while dragging.
+    resized_to: Option<f32>,
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for ResizeHandle<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(WIDTH), Length::Fill)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(Length::Fixed(WIDTH), Length::Fill, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    state.drag_origin = Some((position.x, self.width));
+                    state.resized_to = None;
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((origin, width)) = state.drag_origin {
+                    // The drawer is on the trailing side, so it grows as the handle moves left.
+                    let width = width + origin - position.x;
+                    state.resized_to = Some(width);
+                    shell.publish((self.on_resize)(width));
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if state.drag_origin.take().is_some() {
+                    // Only the final width is emitted, so that it is not persisted on every move.
+                    if let (Some(on_resize_end), Some(width)) =
+                        (self.on_resize_end.as_ref(), state.resized_to.take())
+                    {
+                        shell.publish(on_resize_end(width));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => (),
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag_origin.is_some() || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::ResizingHorizontally
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl<'a, Message: 'a> From<ResizeHandle<'a, Message>> for crate::Element<'a, Message> {
+    fn from(handle: ResizeHandle<'a, Message>) -> Self {
+        Self::new(handle)
+    }
+}
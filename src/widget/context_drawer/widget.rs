@@ -7,6 +7,7 @@ use crate::widget::{button, column, container, icon, row, scrollable, text, Laye
 use crate::{Apply, Element, Renderer, Theme};
 
 use super::overlay::Overlay;
+use super::resize_handle::{resize_handle, ResizeHandle};
 
 use iced_core::event::{self, Event};
 use iced_core::widget::{Operation, Tree};
@@ -22,6 +23,9 @@ pub struct ContextDrawer<'a, Message> {
     content: Element<'a, Message>,
     drawer: Element<'a, Message>,
     on_close: Option<Message>,
+    max_width: f32,
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_resize_end: Option<Box<dyn Fn(f32) -> Message + 'a>>,
 }
 
 impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
@@ -34,6 +38,66 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
         on_close: Message,
         max_width: f32,
     ) -> Element<'a, Message>
+    where
+        Drawer: Into<Element<'a, Message>>,
+    {
+        let pane = Self::pane(
+            title,
+            header_actions,
+            header_opt,
+            footer_opt,
+            drawer,
+            on_close,
+            max_width,
+        );
+
+        Self::place(pane, max_width, None)
+    }
+
+    /// Like [`Self::new_inner`], with a handle on its leading edge which resizes it.
+    ///
+    /// `on_resize` is emitted as the handle is dragged, and `on_resize_end` once it is released.
+    pub(crate) fn new_inner_resizable<Drawer>(
+        title: Option<Cow<'a, str>>,
+        header_actions: Vec<Element<'a, Message>>,
+        header_opt: Option<Element<'a, Message>>,
+        footer_opt: Option<Element<'a, Message>>,
+        drawer: Drawer,
+        on_close: Message,
+        max_width: f32,
+        on_resize: Box<dyn Fn(f32) -> Message + 'a>,
+        on_resize_end: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    ) -> Element<'a, Message>
+    where
+        Drawer: Into<Element<'a, Message>>,
+    {
+        let pane = Self::pane(
+            title,
+            header_actions,
+            header_opt,
+            footer_opt,
+            drawer,
+            on_close,
+            max_width,
+        );
+
+        Self::place(
+            pane,
+            max_width,
+            Some(resize_handle(max_width, on_resize, on_resize_end)),
+        )
+    }
+
+    /// The drawer with its header and footer, before it is placed against the trailing edge.
+    fn pane<Drawer>(
+        title: Option<Cow<'a, str>>,
+        header_actions: Vec<Element<'a, Message>>,
+        header_opt: Option<Element<'a, Message>>,
+        footer_opt: Option<Element<'a, Message>>,
+        drawer: Drawer,
+        on_close: Message,
+        max_width: f32,
+    ) -> Element<'a, Message>
     where
         Drawer: Into<Element<'a, Message>>,
     {
@@ -47,8 +111,11 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
 
         let horizontal_padding = if max_width < 392.0 { space_s } else { space_l };
 
+        // Headers and footers span the drawer once it is resized beyond its default width.
+        let pane_width = Length::Fixed(max_width.max(480.0));
+
         let header_row = row::with_capacity(3)
-            .width(Length::Fixed(480.0))
+            .width(pane_width)
             .align_y(Alignment::Center)
             .push(
                 row::with_children(header_actions)
@@ -67,7 +134,7 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
                     .align_x(Alignment::End),
             );
         let header = column::with_capacity(2)
-            .width(Length::Fixed(480.0))
+            .width(pane_width)
             .align_x(Alignment::Center)
             .spacing(space_m)
             .padding([space_m, horizontal_padding])
@@ -75,7 +142,7 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
             .push_maybe(header_opt);
         let footer = footer_opt.map(|element| {
             container(element)
-                .width(Length::Fixed(480.0))
+                .width(pane_width)
                 .align_y(Alignment::Center)
                 .padding([space_xxs, horizontal_padding])
         });
@@ -95,18 +162,13 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
 
         // XXX new limits do not exactly handle the max width well for containers
         // XXX this is a hack to get around that
-        container(
-            LayerContainer::new(pane)
-                .layer(cosmic_theme::Layer::Primary)
-                .class(crate::style::Container::ContextDrawer)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .max_width(max_width),
-        )
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .align_x(Alignment::End)
-        .into()
+        LayerContainer::new(pane)
+            .layer(cosmic_theme::Layer::Primary)
+            .class(crate::style::Container::ContextDrawer)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .max_width(max_width)
+            .into()
     }
 
     /// Creates an empty [`ContextDrawer`].
@@ -124,7 +186,8 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
         Content: Into<Element<'a, Message>>,
         Drawer: Into<Element<'a, Message>>,
     {
-        let drawer = Self::new_inner(
+        // The pane is placed once it is known whether it may be resized.
+        let drawer = Self::pane(
             title,
             header_actions,
             header_opt,
@@ -139,9 +202,27 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
             content: content.into(),
             drawer,
             on_close: None,
+            max_width,
+            on_resize: None,
+            on_resize_end: None,
         }
     }
 
+    /// Adds a handle on the leading edge of the drawer, which emits its new width as it is
+    /// dragged.
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Emits the width of the drawer once its resize handle is released, such as to persist it.
+    ///
+    /// Only emitted if [`Self::on_resize`] is set.
+    pub fn on_resize_end(mut self, on_resize_end: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize_end = Some(Box::new(on_resize_end));
+        self
+    }
+
     /// Sets the [`Id`] of the [`ContextDrawer`].
     pub fn id(mut self, id: iced_core::widget::Id) -> Self {
         self.id = Some(id);
@@ -155,6 +236,35 @@ impl<'a, Message: Clone + 'static> ContextDrawer<'a, Message> {
     }
 }
 
+impl<'a, Message: 'a> ContextDrawer<'a, Message> {
+    /// Places the pane against the trailing edge, with the resize handle on its leading edge.
+    fn place(
+        pane: Element<'a, Message>,
+        max_width: f32,
+        handle: Option<ResizeHandle<'a, Message>>,
+    ) -> Element<'a, Message> {
+        let Some(handle) = handle else {
+            return container(pane)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(Alignment::End)
+                .into();
+        };
+
+        row::with_capacity(2)
+            .push(handle)
+            .push(pane)
+            .apply(container)
+            .width(Length::Fill)
+            .max_width(max_width + super::RESIZE_HANDLE_WIDTH)
+            .apply(container)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Alignment::End)
+            .into()
+    }
+}
+
 impl<'a, Message: Clone> Widget<Message, crate::Theme, Renderer> for ContextDrawer<'a, Message> {
     fn children(&self) -> Vec<Tree> {
         vec![Tree::new(&self.content), Tree::new(&self.drawer)]
@@ -310,7 +420,17 @@ impl<'a, Message: Clone> Widget<Message, crate::Theme, Renderer> for ContextDraw
 }
 
 impl<'a, Message: 'a + Clone> From<ContextDrawer<'a, Message>> for Element<'a, Message> {
-    fn from(widget: ContextDrawer<'a, Message>) -> Element<'a, Message> {
+    fn from(mut widget: ContextDrawer<'a, Message>) -> Element<'a, Message> {
+        let handle = widget.on_resize.take().map(|on_resize| {
+            resize_handle(widget.max_width, on_resize, widget.on_resize_end.take())
+        });
+
+        let pane = std::mem::replace(
+            &mut widget.drawer,
+            Element::new(crate::widget::Space::new(0, 0)),
+        );
+        widget.drawer = ContextDrawer::place(pane, widget.max_width, handle);
+
         Element::new(widget)
     }
 }
@@ -10,10 +10,12 @@ use derive_setters::Setters;
 use iced::touch::Finger;
 use iced::{Event, Vector};
 use iced_core::widget::{tree, Tree, Widget};
-use iced_core::{event, mouse, touch, Length, Point, Size};
+use iced_core::{event, mouse, touch, Length, Point, Rectangle, Size};
 use std::collections::HashSet;
 
 /// A context menu is a menu in a graphical user interface that appears upon user interaction, such as a right-click mouse operation.
+///
+/// Items with children, such as a [`menu::Item::Folder`], open as submenus when hovered or clicked.
 pub fn context_menu<'a, Message: 'a>(
     content: impl Into<crate::Element<'a, Message>> + 'a,
     // on_context: Message,
@@ -209,7 +211,7 @@ impl<'a, Message: Clone> Widget<Message, crate::Theme, crate::Renderer>
     fn overlay<'b>(
         &'b mut self,
         tree: &'b mut Tree,
-        layout: iced_core::Layout<'_>,
+        _layout: iced_core::Layout<'_>,
         _renderer: &crate::Renderer,
         translation: Vector,
     ) -> Option<iced_core::overlay::Element<'b, Message, crate::Theme, crate::Renderer>> {
@@ -223,9 +225,9 @@ impl<'a, Message: Clone> Widget<Message, crate::Theme, crate::Renderer>
             return None;
         }
 
-        let mut bounds = layout.bounds();
-        bounds.x = state.context_cursor.x;
-        bounds.y = state.context_cursor.y;
+        // Anchor the menu to the position of the click, rather than to the content, so that it
+        // opens beside the cursor and flips to whichever side has space for it.
+        let bounds = Rectangle::new(state.context_cursor, Size::new(1.0, 1.0));
 
         Some(
             crate::widget::menu::Menu {
@@ -1,4 +1,10 @@
-use crate::{iced::Length, style, theme, widget, Element};
+use crate::{iced::Length, style, theme, widget, Element, Renderer, Theme};
+use iced_core::event::{self, Event};
+use iced_core::keyboard::{self, key::Named};
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Clipboard, Layout, Rectangle, Shell, Size, Vector, Widget,
+};
 use std::borrow::Cow;
 
 pub fn dialog<'a, Message>() -> Dialog<'a, Message> {
@@ -128,3 +134,244 @@ impl<'a, Message: Clone + 'static> From<Dialog<'a, Message>> for Element<'a, Mes
         )
     }
 }
+
+/// A dialog which asks the user to confirm an action.
+///
+/// Enter presses the primary button, and Escape presses the secondary button.
+///
+/// ```no_run
+/// # use cosmic::widget::dialog;
+/// # #[derive(Clone)]
+/// # enum Message { Delete, Cancel }
+/// let confirm = dialog::confirm("Delete file?", "The file will be permanently deleted.")
+///     .primary("Delete", Message::Delete)
+///     .secondary("Cancel", Message::Cancel);
+/// ```
+pub fn confirm<'a, Message>(
+    title: impl Into<Cow<'a, str>>,
+    body: impl Into<Cow<'a, str>>,
+) -> Confirm<'a, Message> {
+    Confirm {
+        title: title.into(),
+        body: body.into(),
+        primary: None,
+        secondary: None,
+        destructive: false,
+    }
+}
+
+/// A dialog which asks the user to confirm an action.
+#[must_use]
+pub struct Confirm<'a, Message> {
+    title: Cow<'a, str>,
+    body: Cow<'a, str>,
+    primary: Option<(Cow<'a, str>, Message)>,
+    secondary: Option<(Cow<'a, str>, Message)>,
+    destructive: bool,
+}
+
+impl<'a, Message> Confirm<'a, Message> {
+    /// The button which confirms the action, and is pressed by Enter.
+    pub fn primary(mut self, label: impl Into<Cow<'a, str>>, message: Message) -> Self {
+        self.primary = Some((label.into(), message));
+        self
+    }
+
+    /// The button which cancels the action, and is pressed by Escape.
+    pub fn secondary(mut self, label: impl Into<Cow<'a, str>>, message: Message) -> Self {
+        self.secondary = Some((label.into(), message));
+        self
+    }
+
+    /// Styles the primary button as destructive, for actions which can't be undone.
+    pub fn destructive(mut self, destructive: bool) -> Self {
+        self.destructive = destructive;
+        self
+    }
+}
+
+impl<'a, Message: Clone + 'static> From<Confirm<'a, Message>> for Element<'a, Message> {
+    fn from(confirm: Confirm<'a, Message>) -> Self {
+        let mut dialog = Dialog::new().title(confirm.title).body(confirm.body);
+        let mut on_enter = None;
+        let mut on_escape = None;
+
+        if let Some((label, message)) = confirm.primary {
+            let button = if confirm.destructive {
+                widget::button::destructive(label)
+            } else {
+                widget::button::suggested(label)
+            };
+
+            dialog = dialog.primary_action(button.on_press(message.clone()));
+            on_enter = Some(message);
+        }
+
+        if let Some((label, message)) = confirm.secondary {
+            dialog =
+                dialog.secondary_action(widget::button::standard(label).on_press(message.clone()));
+            on_escape = Some(message);
+        }
+
+        Element::new(Shortcuts {
+            content: Element::from(dialog),
+            on_enter,
+            on_escape,
+        })
+    }
+}
+
+/// Publishes a message when Enter or Escape is pressed, unless the content handled the key.
+struct Shortcuts<'a, Message> {
+    content: Element<'a, Message>,
+    on_enter: Option<Message>,
+    on_escape: Option<Message>,
+}
+
+impl<'a, Message: Clone> Widget<Message, Theme, Renderer> for Shortcuts<'a, Message> {
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.children[0].diff(&mut self.content);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            ..
+        }) = event
+        else {
+            return status;
+        };
+
+        let message = match key {
+            Named::Enter => self.on_enter.clone(),
+            Named::Escape => self.on_escape.clone(),
+            _ => None,
+        };
+
+        match message {
+            Some(message) => {
+                shell.publish(message);
+                event::Status::Captured
+            }
+            None => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+
+    #[cfg(feature = "a11y")]
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content
+            .as_widget()
+            .a11y_nodes(layout, &state.children[0], p)
+    }
+}
@@ -12,7 +12,7 @@ use iced_core::text::{self, Text};
 use iced_core::widget::Tree;
 use iced_core::{
     alignment, mouse, overlay, renderer, svg, touch, Border, Clipboard, Element, Length, Padding,
-    Pixels, Point, Rectangle, Renderer, Shadow, Shell, Size, Vector, Widget,
+    Pixels, Point, Rectangle, Renderer, Shadow, Shell, Size, Widget,
 };
 use iced_widget::scrollable::Scrollable;
 
@@ -189,29 +189,11 @@ impl<'a, Message> iced_core::Overlay<Message, crate::Theme, crate::Renderer>
     for Overlay<'a, Message>
 {
     fn layout(&mut self, renderer: &crate::Renderer, bounds: Size) -> layout::Node {
-        let position = self.position;
-        let space_below = bounds.height - (position.y + self.target_height);
-        let space_above = position.y;
-
-        let limits = layout::Limits::new(
-            Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
-        )
-        .width(self.width);
+        let width = self.width;
 
-        let node = self.container.layout(self.state, renderer, &limits);
-
-        node.clone().move_to(if space_below > space_above {
-            position + Vector::new(0.0, self.target_height)
-        } else {
-            position - Vector::new(0.0, node.size().height)
+        layout_beside_target(bounds, self.position, self.target_height, |max| {
+            let limits = layout::Limits::new(Size::ZERO, max).width(width);
+            self.container.layout(self.state, renderer, &limits)
         })
     }
 
@@ -543,3 +525,35 @@ impl<'a, S: AsRef<str>, Message: 'a> From<List<'a, S, Message>>
         Element::new(list)
     }
 }
+
+/// Positions a menu directly beneath its target, or above it if it only fits there.
+///
+/// The menu is shifted horizontally to remain within the `bounds` of the window.
+pub(super) fn layout_beside_target(
+    bounds: Size,
+    position: Point,
+    target_height: f32,
+    mut layout: impl FnMut(Size) -> layout::Node,
+) -> layout::Node {
+    let space_below = bounds.height - (position.y + target_height);
+    let space_above = position.y;
+
+    // Measure the menu first to learn whether it fits below the target.
+    let height = layout(bounds).size().height;
+    let below = height <= space_below || space_below >= space_above;
+
+    let node = layout(Size::new(
+        bounds.width,
+        if below { space_below } else { space_above },
+    ));
+
+    let size = node.size();
+    let x = position.x.min(bounds.width - size.width).max(0.0);
+    let y = if below {
+        position.y + target_height
+    } else {
+        position.y - size.height
+    };
+
+    node.move_to(Point::new(x, y))
+}
@@ -8,7 +8,7 @@ use iced_core::text::{self, Text};
 use iced_core::widget::Tree;
 use iced_core::{
     alignment, mouse, overlay, renderer, svg, touch, Border, Clipboard, Element, Length, Padding,
-    Pixels, Point, Rectangle, Renderer, Shadow, Shell, Size, Vector, Widget,
+    Pixels, Point, Rectangle, Renderer, Shadow, Shell, Size, Widget,
 };
 use iced_widget::scrollable::Scrollable;
 
@@ -184,32 +184,17 @@ impl<'a, Message> iced_core::Overlay<Message, crate::Theme, crate::Renderer>
     for Overlay<'a, Message>
 {
     fn layout(&mut self, renderer: &crate::Renderer, bounds: Size) -> layout::Node {
-        let position = self.position;
-        let space_below = bounds.height - (position.y + self.target_height);
-        let space_above = position.y;
-
-        let limits = layout::Limits::new(
-            Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
+        let width = self.width;
+
+        crate::widget::dropdown::menu::layout_beside_target(
+            bounds,
+            self.position,
+            self.target_height,
+            |max| {
+                let limits = layout::Limits::new(Size::ZERO, max).width(width);
+                self.container.layout(self.state, renderer, &limits)
+            },
         )
-        .width(self.width);
-
-        let mut node = self.container.layout(self.state, renderer, &limits);
-
-        node = node.clone().move_to(if space_below > space_above {
-            position + Vector::new(0.0, self.target_height)
-        } else {
-            position - Vector::new(0.0, node.size().height)
-        });
-
-        node
     }
 
     fn on_event(
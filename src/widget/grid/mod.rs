@@ -12,3 +12,18 @@ pub use widget::Grid;
 pub const fn grid<'a, Message>() -> Grid<'a, Message> {
     Grid::new()
 }
+
+/// The number of columns of `item_width`, separated by `spacing`, which fit in `width`.
+///
+/// This is at least one column, and may be used to arrange items within a `responsive` widget.
+/// Any number of columns fit in an unbounded width, so `usize::MAX` is returned for it.
+#[must_use]
+pub fn columns(width: f32, item_width: f32, spacing: f32) -> usize {
+    if !width.is_finite() || item_width <= 0.0 {
+        return usize::MAX;
+    }
+
+    ((width + spacing) / (item_width + spacing))
+        .floor()
+        .max(1.0) as usize
+}
@@ -42,6 +42,9 @@ pub struct Grid<'a, Message> {
     column: u16,
     #[setters(skip)]
     row: u16,
+    /// Width of the columns which children are arranged into, when responsive.
+    #[setters(skip)]
+    item_width: Option<f32>,
 }
 
 impl<'a, Message> Grid<'a, Message> {
@@ -60,6 +63,7 @@ impl<'a, Message> Grid<'a, Message> {
             max_width: f32::INFINITY,
             column: 1,
             row: 1,
+            item_width: None,
         }
     }
 
@@ -104,6 +108,13 @@ impl<'a, Message> Grid<'a, Message> {
         self.column = 1;
         self
     }
+
+    /// Arranges children in order into as many columns of `item_width` as fit in the available
+    /// width, rather than by the rows and columns which they were pushed into.
+    pub fn item_width(mut self, item_width: f32) -> Self {
+        self.item_width = Some(item_width);
+        self
+    }
 }
 
 impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer> for Grid<'a, Message> {
@@ -131,11 +142,34 @@ impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer> for G
             .width(size.width)
             .height(size.height);
 
+        let responsive_assignments;
+        let assignments = if let Some(item_width) = self.item_width {
+            let columns = super::columns(
+                limits.max().width - self.padding.horizontal(),
+                item_width,
+                f32::from(self.column_spacing),
+            )
+            .min(self.children.len().max(1));
+
+            responsive_assignments = (0..self.children.len())
+                .map(|nth| Assignment {
+                    column: (nth % columns) as u16 + 1,
+                    row: (nth / columns) as u16 + 1,
+                    width: 1,
+                    height: 1,
+                })
+                .collect::<Vec<_>>();
+
+            &responsive_assignments
+        } else {
+            &self.assignments
+        };
+
         super::layout::resolve(
             renderer,
             &limits,
             &self.children,
-            &self.assignments,
+            assignments,
             self.width,
             self.height,
             self.padding,
@@ -27,6 +27,16 @@ pub fn header_bar<'a, Message>() -> HeaderBar<'a, Message> {
     }
 }
 
+/// The height of a header bar with the given density.
+#[must_use]
+pub(crate) fn height(density: Density) -> f32 {
+    match density {
+        Density::Compact => 40.0,
+        Density::Spacious => 48.0,
+        Density::Standard => 48.0,
+    }
+}
+
 #[derive(Setters)]
 pub struct HeaderBar<'a, Message> {
     /// Defines the title of the window
@@ -301,11 +311,7 @@ impl<'a, Message: Clone + 'static> HeaderBar<'a, Message> {
         // Also packs the window controls at the very end.
         end.push(self.window_controls());
 
-        let height = match self.density.unwrap_or_else(crate::config::header_size) {
-            Density::Compact => 40.0,
-            Density::Spacious => 48.0,
-            Density::Standard => 48.0,
-        };
+        let height = height(self.density.unwrap_or_else(crate::config::header_size));
 
         // Creates the headerbar widget.
         let mut widget = widget::row::with_capacity(3)
@@ -80,6 +80,13 @@ pub fn from_raster_pixels(
     }
 }
 
+/// Create an image handle from owned RGBA pixels, such as an image generated at runtime.
+///
+/// The `pixels` must contain `width * height * 4` bytes.
+pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
+    from_raster_pixels(width, height, pixels)
+}
+
 /// Create a SVG handle from memory.
 pub fn from_svg_bytes(bytes: impl Into<Cow<'static, [u8]>>) -> Handle {
     Handle {
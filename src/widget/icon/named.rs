@@ -54,6 +54,16 @@ impl Named {
     #[cfg(not(windows))]
     #[must_use]
     pub fn path(self) -> Option<PathBuf> {
+        self.theme_path().map(|(_theme, path)| path)
+    }
+
+    /// Locates the icon, along with the name of the icon theme which provided it.
+    ///
+    /// The default icon theme is searched first, followed by the icon themes given to
+    /// [`crate::icon_theme::set_fallbacks`], and then the COSMIC icon theme.
+    #[cfg(not(windows))]
+    #[must_use]
+    pub fn theme_path(self) -> Option<(String, PathBuf)> {
         let name = &*self.name;
         let fallback = &self.fallback;
         let locate = |theme: &str, name| {
@@ -72,16 +82,12 @@ impl Named {
             if self.prefer_svg {
                 lookup = lookup.force_svg();
             }
-            lookup.find()
-        };
 
-        let theme = crate::icon_theme::DEFAULT.lock().unwrap();
-        let themes = if theme.as_ref() == crate::icon_theme::COSMIC {
-            vec![theme.as_ref()]
-        } else {
-            vec![theme.as_ref(), crate::icon_theme::COSMIC]
+            lookup.find().map(|path| (theme.to_owned(), path))
         };
 
+        let themes = crate::icon_theme::search_order();
+
         let mut result = themes.iter().find_map(|t| locate(t, name));
 
         // On failure, attempt to locate fallback icon.
@@ -103,6 +109,11 @@ impl Named {
             }
         }
 
+        match &result {
+            Some((theme, path)) => tracing::debug!(name, %theme, ?path, "located icon"),
+            None => tracing::debug!(name, ?themes, "icon not found in any icon theme"),
+        }
+
         result
     }
 
@@ -113,6 +124,13 @@ impl Named {
         None
     }
 
+    #[cfg(windows)]
+    #[must_use]
+    pub fn theme_path(self) -> Option<(String, PathBuf)> {
+        //TODO: implement icon lookup for Windows
+        None
+    }
+
     pub fn handle(self) -> Handle {
         Handle {
             symbolic: self.symbolic,
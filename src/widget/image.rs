@@ -0,0 +1,370 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Display images, including images which are decoded in the background.
+//!
+//! Images loaded with [`load`] or [`load_bytes`] are decoded on a bounded pool of threads. Until then,
+//! [`lazy`] shows a placeholder in their place, and fades them in once they are ready.
+//!
+//! ```no_run
+//! # use cosmic::widget::image;
+//! # #[derive(Clone)]
+//! # enum Message { Loaded(image::Status) }
+//! # let status = image::Status::Loading;
+//! // Decodes the image in the background.
+//! let task = image::load("/usr/share/backgrounds/pop/kait-herzog-8242.jpg").map(Message::Loaded);
+//!
+//! // Shows a placeholder until the image is ready.
+//! let view = image::lazy(&status).width(300).height(200);
+//! ```
+
+use crate::{Element, Renderer, Theme};
+
+use iced_core::event::{self, Event};
+use iced_core::image::Id;
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, renderer, svg, window, Border, Clipboard, ContentFit, Layout, Length, Radians,
+    Rectangle, Shadow, Shell, Size, Vector, Widget,
+};
+use std::path::PathBuf;
+
+#[doc(inline)]
+pub use iced::widget::image::*;
+
+/// How long an image takes to fade in once it has loaded.
+const FADE: Duration = Duration::from_millis(200);
+
+/// Size of the glyph shown in place of an image which failed to load.
+const GLYPH_SIZE: f32 = 32.0;
+
+/// Creates an image widget from a handle.
+pub fn image<H>(handle: impl Into<H>) -> Image<H> {
+    Image::new(handle)
+}
+
+/// The status of an image which is decoded in the background.
+#[derive(Clone, Debug, Default)]
+pub enum Status {
+    /// The image is being decoded.
+    #[default]
+    Loading,
+    /// The image has been decoded.
+    Ready(Handle),
+    /// The image could not be loaded.
+    Failed,
+}
+
+/// Decodes the image at `path` in the background.
+pub fn load(path: impl Into<PathBuf>) -> crate::Task<Status> {
+    let path = path.into();
+    decode(move || ::image::open(path))
+}
+
+/// Decodes an image from its encoded bytes, such as a download, in the background.
+pub fn load_bytes(bytes: impl Into<Vec<u8>>) -> crate::Task<Status> {
+    let bytes = bytes.into();
+    decode(move || ::image::load_from_memory(&bytes))
+}
+
+/// Runs `decode` on the blocking pool, yielding the decoded image as a handle.
+fn decode(
+    decode: impl FnOnce() -> ::image::ImageResult<::image::DynamicImage> + Send + 'static,
+) -> crate::Task<Status> {
+    crate::task::future(async move {
+        let decoded = crate::executor::blocking::unblock(move || {
+            decode().map(|image| {
+                let image = image.into_rgba8();
+                Handle::from_rgba(image.width(), image.height(), image.into_raw())
+            })
+        })
+        .await;
+
+        match decoded {
+            Some(Ok(handle)) => Status::Ready(handle),
+            Some(Err(why)) => {
+                tracing::error!(?why, "failed to decode image");
+                Status::Failed
+            }
+            None => Status::Failed,
+        }
+    })
+}
+
+/// Shows an image once it has loaded, with a placeholder until then.
+pub fn lazy(status: &Status) -> LazyImage {
+    LazyImage::new(status)
+}
+
+/// Shows an image once it has loaded, with a placeholder until then.
+///
+/// The image fades in when it becomes ready, and a glyph is shown in its place if it failed
+/// to load. Give it a fixed size to prevent the layout from shifting once the image is ready.
+#[must_use]
+pub struct LazyImage {
+    status: Status,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    border_radius: [f32; 4],
+}
+
+impl LazyImage {
+    /// Shows an image once it has loaded, with a placeholder until then.
+    pub fn new(status: &Status) -> Self {
+        Self {
+            status: status.clone(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            border_radius: [0.0; 4],
+        }
+    }
+
+    /// Sets the width of the image.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the image.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets how the image is fit within its bounds.
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Rounds the corners of the image and its placeholder.
+    pub fn border_radius(mut self, border_radius: [f32; 4]) -> Self {
+        self.border_radius = border_radius;
+        self
+    }
+
+    /// The handle of the image, if it is ready.
+    fn handle(&self) -> Option<&Handle> {
+        match &self.status {
+            Status::Ready(handle) => Some(handle),
+            Status::Loading | Status::Failed => None,
+        }
+    }
+}
+
+/// The image which is shown, and the progress of its fade in.
+#[derive(Default)]
+struct State {
+    shown: Option<Id>,
+    fade_pending: bool,
+    fade_start: Option<Instant>,
+}
+
+impl State {
+    /// The opacity of the image while it fades in.
+    fn opacity(&self) -> f32 {
+        if self.fade_pending {
+            return 0.0;
+        }
+
+        self.fade_start.map_or(1.0, |start| {
+            (start.elapsed().as_secs_f32() / FADE.as_secs_f32()).min(1.0)
+        })
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for LazyImage {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        // Images which are ready when the widget is created are shown without fading in.
+        tree::State::new(State {
+            shown: self.handle().map(Handle::id),
+            ..State::default()
+        })
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let id = self.handle().map(Handle::id);
+
+        if state.shown != id {
+            state.shown = id;
+            state.fade_pending = id.is_some();
+            state.fade_start = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Some(handle) = self.handle() else {
+            let size = Size::new(GLYPH_SIZE * 2.0, GLYPH_SIZE * 2.0);
+            return layout::Node::new(limits.resolve(self.width, self.height, size));
+        };
+
+        let Size { width, height } = iced_core::image::Renderer::measure_image(renderer, handle);
+        let image_size = Size::new(width as f32, height as f32);
+        let raw_size = limits.resolve(self.width, self.height, image_size);
+        let full_size = self.content_fit.fit(image_size, raw_size);
+
+        layout::Node::new(Size::new(
+            if self.width == Length::Shrink {
+                full_size.width
+            } else {
+                raw_size.width
+            },
+            if self.height == Length::Shrink {
+                full_size.height
+            } else {
+                raw_size.height
+            },
+        ))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.fade_pending {
+                state.fade_pending = false;
+                state.fade_start = Some(now);
+            }
+
+            if let Some(start) = state.fade_start {
+                if now.duration_since(start) < FADE {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                } else {
+                    state.fade_start = None;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let opacity = if self.handle().is_some() {
+            state.opacity()
+        } else {
+            0.0
+        };
+
+        // The placeholder is shown until the image has completely faded in.
+        if opacity < 1.0 {
+            let container = theme.current_container();
+
+            iced_core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        radius: self.border_radius.into(),
+                        ..Border::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                iced_core::Color::from(container.component.base),
+            );
+
+            if let Status::Failed = self.status {
+                let glyph = crate::widget::icon::from_name("image-missing-symbolic")
+                    .size(GLYPH_SIZE as u16)
+                    .icon()
+                    .into_svg_handle();
+
+                if let Some(glyph) = glyph {
+                    let center = bounds.center();
+                    let glyph_bounds = Rectangle {
+                        x: center.x - GLYPH_SIZE / 2.0,
+                        y: center.y - GLYPH_SIZE / 2.0,
+                        width: GLYPH_SIZE,
+                        height: GLYPH_SIZE,
+                    };
+
+                    svg::Renderer::draw_svg(
+                        renderer,
+                        svg::Svg::new(glyph).color(iced_core::Color::from(container.component.on)),
+                        glyph_bounds,
+                    );
+                }
+            }
+        }
+
+        let Some(handle) = self.handle() else {
+            return;
+        };
+
+        let Size { width, height } = iced_core::image::Renderer::measure_image(renderer, handle);
+        let image_size = Size::new(width as f32, height as f32);
+        let adjusted_fit = self.content_fit.fit(image_size, bounds.size());
+
+        let render = |renderer: &mut Renderer| {
+            let offset = Vector::new(
+                (bounds.width - adjusted_fit.width).max(0.0) / 2.0,
+                (bounds.height - adjusted_fit.height).max(0.0) / 2.0,
+            );
+
+            let drawing_bounds = Rectangle {
+                width: adjusted_fit.width,
+                height: adjusted_fit.height,
+                ..bounds
+            };
+
+            iced_core::image::Renderer::draw_image(
+                renderer,
+                handle.clone(),
+                FilterMethod::Linear,
+                drawing_bounds + offset,
+                Radians::from(0),
+                opacity,
+                self.border_radius,
+            );
+        };
+
+        if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height {
+            iced_core::Renderer::with_layer(renderer, bounds, render);
+        } else {
+            render(renderer);
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<LazyImage> for Element<'a, Message> {
+    fn from(image: LazyImage) -> Self {
+        Self::new(image)
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Aligned label and value pairs, such as for about pages and detail panes.
+//!
+//! Labels are arranged into a column as wide as the widest label, with values beside them.
+//! When narrower than its breakpoint, each value is stacked beneath its label instead.
+//!
+//! ```no_run
+//! # use cosmic::widget::{self, key_value};
+//! # use cosmic::iced::Alignment;
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! let details = key_value::<Message>([
+//!     ("Version", widget::text("1.0.0").into()),
+//!     ("License", widget::text("MPL-2.0").into()),
+//! ])
+//! .align_values(Alignment::End);
+//! ```
+
+use crate::{widget, Element, Renderer};
+use iced_core::event::{self, Event};
+use iced_core::widget::{Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, Alignment, Clipboard, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+use std::borrow::Cow;
+
+/// Aligned label and value pairs, which stack when narrow.
+pub fn key_value<'a, Message>(
+    pairs: impl IntoIterator<Item = (impl Into<Cow<'a, str>> + 'a, Element<'a, Message>)>,
+) -> KeyValue<'a, Message> {
+    pairs
+        .into_iter()
+        .fold(KeyValue::new(), |key_value, (label, value)| {
+            key_value.push(label, value)
+        })
+}
+
+/// Aligned label and value pairs, which stack when narrow.
+#[must_use]
+pub struct KeyValue<'a, Message> {
+    /// Labels and values, interleaved.
+    children: Vec<Element<'a, Message>>,
+    value_alignment: Alignment,
+    breakpoint: f32,
+    column_spacing: f32,
+    row_spacing: f32,
+    width: Length,
+}
+
+impl<'a, Message> KeyValue<'a, Message> {
+    pub fn new() -> Self {
+        let spacing = crate::theme::active().cosmic().spacing;
+
+        Self {
+            children: Vec::new(),
+            value_alignment: Alignment::Start,
+            breakpoint: 360.0,
+            column_spacing: f32::from(spacing.space_s),
+            row_spacing: f32::from(spacing.space_xxs),
+            width: Length::Fill,
+        }
+    }
+
+    /// Appends a label and its value.
+    pub fn push(
+        mut self,
+        label: impl Into<Cow<'a, str>> + 'a,
+        value: impl Into<Element<'a, Message>>,
+    ) -> Self {
+        self.children.push(widget::text::heading(label).into());
+        self.children.push(value.into());
+        self
+    }
+
+    /// Aligns values to the start, center, or end of their column.
+    ///
+    /// Values are always aligned to the start when stacked beneath their labels.
+    pub fn align_values(mut self, alignment: Alignment) -> Self {
+        self.value_alignment = alignment;
+        self
+    }
+
+    /// The width below which values are stacked beneath their labels.
+    pub fn breakpoint(mut self, breakpoint: f32) -> Self {
+        self.breakpoint = breakpoint;
+        self
+    }
+
+    /// Sets the space between the label and value columns.
+    pub fn column_spacing(mut self, spacing: impl Into<iced::Pixels>) -> Self {
+        self.column_spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the space between each pair.
+    pub fn row_spacing(mut self, spacing: impl Into<iced::Pixels>) -> Self {
+        self.row_spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the width of the widget.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+impl<'a, Message> Default for KeyValue<'a, Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message: 'static + Clone> Widget<Message, crate::Theme, Renderer>
+    for KeyValue<'a, Message>
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+        let unbounded = |width| layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY));
+
+        let mut nodes = Vec::with_capacity(self.children.len());
+        let mut y = 0.0;
+        let mut content_width = 0.0f32;
+
+        if max_width < self.breakpoint {
+            // Stack each value beneath its label.
+            let gap = self.row_spacing / 2.0;
+
+            for (nth, (child, state)) in self.children.iter().zip(&mut tree.children).enumerate() {
+                if nth > 0 {
+                    y += if nth % 2 == 0 { self.row_spacing } else { gap };
+                }
+
+                let node = child
+                    .as_widget()
+                    .layout(state, renderer, &unbounded(max_width))
+                    .move_to(Point::new(0.0, y));
+
+                y += node.size().height;
+                content_width = content_width.max(node.size().width);
+                nodes.push(node);
+            }
+        } else {
+            // Labels may take no more than half of the width.
+            let widest_label = self
+                .children
+                .iter()
+                .zip(&mut tree.children)
+                .step_by(2)
+                .map(|(label, state)| {
+                    label
+                        .as_widget()
+                        .layout(state, renderer, &unbounded(max_width))
+                        .size()
+                        .width
+                })
+                .fold(0.0f32, f32::max);
+
+            let label_width = widest_label.min((max_width - self.column_spacing) / 2.0);
+            let value_x = label_width + self.column_spacing;
+            let value_width = max_width - value_x;
+
+            let mut children = self.children.iter().zip(&mut tree.children);
+
+            while let Some((label, label_state)) = children.next() {
+                if !nodes.is_empty() {
+                    y += self.row_spacing;
+                }
+
+                let label = label
+                    .as_widget()
+                    .layout(label_state, renderer, &unbounded(label_width))
+                    .move_to(Point::new(0.0, y));
+
+                let mut height = label.size().height;
+                nodes.push(label);
+
+                if let Some((value, value_state)) = children.next() {
+                    let node =
+                        value
+                            .as_widget()
+                            .layout(value_state, renderer, &unbounded(value_width));
+
+                    let size = node.size();
+                    let x = match self.value_alignment {
+                        _ if !value_width.is_finite() => value_x,
+                        Alignment::Start => value_x,
+                        Alignment::Center => value_x + (value_width - size.width).max(0.0) / 2.0,
+                        Alignment::End => value_x + (value_width - size.width).max(0.0),
+                    };
+
+                    content_width = content_width.max(x + size.width);
+                    height = height.max(size.height);
+                    nodes.push(node.move_to(Point::new(x, y)));
+                }
+
+                y += height;
+            }
+        }
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(content_width, y));
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, renderer, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: 'static + Clone> From<KeyValue<'a, Message>> for Element<'a, Message> {
+    fn from(key_value: KeyValue<'a, Message>) -> Self {
+        Self::new(key_value)
+    }
+}
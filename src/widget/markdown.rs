@@ -0,0 +1,189 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Render markdown, optionally with code blocks which may be copied.
+//!
+//! Everything from [`iced::widget::markdown`] is available here, and [`view`] renders markdown as
+//! it does in iced. [`copyable`] additionally shows a copy button on code blocks.
+//!
+//! ```no_run
+//! # #[derive(Clone)]
+//! # enum Message { Open(cosmic::widget::markdown::Url), Copy(String) }
+//! use cosmic::widget::markdown;
+//!
+//! let items: Vec<markdown::Item> = markdown::parse("# Hello\n\n[COSMIC](https://system76.com)").collect();
+//!
+//! let view = markdown::copyable(
+//!     &items,
+//!     markdown::Settings::default(),
+//!     markdown::style(),
+//!     Message::Open,
+//!     Message::Copy,
+//! )
+//! .copy_tooltip("Copy to clipboard");
+//! ```
+
+use crate::widget::{button, container, icon, row, scrollable};
+use crate::Element;
+
+use iced::widget::scrollable::{Direction, Scrollbar};
+use iced::widget::{hover, rich_text, Space};
+use iced::{Alignment, Length};
+use std::borrow::Cow;
+
+#[doc(inline)]
+pub use iced::widget::markdown::*;
+
+/// The style of markdown in the active theme.
+pub fn style() -> Style {
+    let theme = crate::theme::active();
+    let cosmic = theme.cosmic();
+
+    Style::from_palette(iced::theme::Palette {
+        background: cosmic.background.base.into(),
+        text: cosmic.background.on.into(),
+        primary: cosmic.accent_color().into(),
+        success: cosmic.success_color().into(),
+        danger: cosmic.destructive_color().into(),
+    })
+}
+
+/// Displays parsed markdown items, with a copy button on each code block.
+///
+/// `on_link` receives the [`Url`] of a link when it is clicked, which may be opened with
+/// `open::that_detached`. `on_copy` receives the contents of a code block when its copy
+/// button is pressed, which may be written with [`iced::clipboard::write`].
+pub fn copyable<'a, Message, I, L, C>(
+    items: I,
+    settings: Settings,
+    style: Style,
+    on_link: L,
+    on_copy: C,
+) -> Copyable<'a, Message, I, L, C>
+where
+    I: IntoIterator<Item = &'a Item>,
+    L: Fn(Url) -> Message + Clone + 'static,
+    C: Fn(String) -> Message + 'a,
+{
+    Copyable {
+        items,
+        settings,
+        style,
+        on_link,
+        on_copy,
+        copy_tooltip: Cow::Borrowed(""),
+    }
+}
+
+/// Markdown with a copy button on each code block.
+#[must_use]
+pub struct Copyable<'a, Message, I, L, C>
+where
+    I: IntoIterator<Item = &'a Item>,
+    L: Fn(Url) -> Message + Clone + 'static,
+    C: Fn(String) -> Message + 'a,
+{
+    items: I,
+    settings: Settings,
+    style: Style,
+    on_link: L,
+    on_copy: C,
+    copy_tooltip: Cow<'a, str>,
+}
+
+impl<'a, Message, I, L, C> Copyable<'a, Message, I, L, C>
+where
+    I: IntoIterator<Item = &'a Item>,
+    L: Fn(Url) -> Message + Clone + 'static,
+    C: Fn(String) -> Message + 'a,
+{
+    /// The translated tooltip of the copy buttons, such as "Copy to clipboard".
+    ///
+    /// The copy buttons have no tooltip unless one is given.
+    pub fn copy_tooltip(mut self, tooltip: impl Into<Cow<'a, str>>) -> Self {
+        self.copy_tooltip = tooltip.into();
+        self
+    }
+}
+
+impl<'a, Message, I, L, C> From<Copyable<'a, Message, I, L, C>> for Element<'a, Message>
+where
+    Message: Clone + 'static,
+    I: IntoIterator<Item = &'a Item>,
+    L: Fn(Url) -> Message + Clone + 'static,
+    C: Fn(String) -> Message + 'a,
+{
+    fn from(markdown: Copyable<'a, Message, I, L, C>) -> Self {
+        let Copyable {
+            items,
+            settings,
+            style,
+            on_link,
+            on_copy,
+            copy_tooltip,
+        } = markdown;
+
+        let blocks = items.into_iter().map(|item| match item {
+            Item::CodeBlock(code) => copyable_code_block(
+                code,
+                settings,
+                style,
+                on_link.clone(),
+                &on_copy,
+                copy_tooltip.clone(),
+            ),
+            item => view(std::iter::once(item), settings, style).map(on_link.clone()),
+        });
+
+        crate::widget::column::with_children(blocks.collect())
+            .spacing(settings.spacing.0)
+            .width(Length::Fill)
+            .into()
+    }
+}
+
+/// A monospaced code block with a copy button which is shown while it is hovered.
+fn copyable_code_block<'a, Message: Clone + 'static>(
+    code: &'a Text,
+    settings: Settings,
+    style: Style,
+    on_link: impl Fn(Url) -> Message + 'static,
+    on_copy: impl Fn(String) -> Message,
+    tooltip: Cow<'a, str>,
+) -> Element<'a, Message> {
+    let spans = code.spans(style);
+    let contents = spans
+        .iter()
+        .map(|span| span.text.as_ref())
+        .collect::<String>();
+
+    let text = Element::<Url>::from(
+        rich_text(spans)
+            .font(crate::font::mono())
+            .size(settings.code_size),
+    )
+    .map(on_link);
+
+    let block = container(
+        scrollable(container(text).padding(settings.code_size.0)).direction(Direction::Horizontal(
+            Scrollbar::default()
+                .width(settings.code_size.0 / 2.0)
+                .scroller_width(settings.code_size.0 / 2.0),
+        )),
+    )
+    .width(Length::Fill)
+    .padding(settings.code_size.0 / 4.0)
+    .class(<crate::Theme as iced_widget::markdown::Catalog>::code_block());
+
+    let copy = row::with_capacity(2)
+        .push(Space::with_width(Length::Fill))
+        .push(
+            button::icon(icon::from_name("edit-copy-symbolic"))
+                .tooltip(tooltip)
+                .on_press(on_copy(contents)),
+        )
+        .align_y(Alignment::Start)
+        .padding(settings.code_size.0 / 4.0);
+
+    hover(block, copy)
+}
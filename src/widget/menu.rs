@@ -59,11 +59,11 @@ pub use action::MenuAction as Action;
 
 mod flex;
 pub mod key_bind;
-pub use key_bind::KeyBind;
+pub use key_bind::{KeyBind, KeySequence, Shortcut};
 
 mod menu_bar;
 pub(crate) use menu_bar::MenuBarState;
-pub use menu_bar::{menu_bar as bar, MenuBar};
+pub use menu_bar::{menu_bar as bar, responsive_menu_bar as responsive_bar, MenuBar};
 
 mod menu_inner;
 mod menu_tree;
@@ -75,3 +75,4 @@ pub use crate::style::menu_bar::{Appearance, StyleSheet};
 pub(crate) use menu_bar::{menu_roots_children, menu_roots_diff};
 pub(crate) use menu_inner::Menu;
 pub use menu_inner::{CloseCondition, ItemHeight, ItemWidth, PathHighlight};
+pub(crate) use menu_tree::parse_mnemonic;
@@ -1,5 +1,7 @@
 use iced_core::keyboard::{Key, Modifiers};
+use std::collections::HashMap;
 use std::fmt;
+use std::time::{Duration, Instant};
 
 /// Represents the modifier keys on a keyboard.
 ///
@@ -18,6 +20,8 @@ pub enum Modifier {
 
 /// Represents a combination of a key and modifiers.
 /// It is used to define keyboard shortcuts.
+///
+/// Use a [`Shortcut`] for two-step sequences, such as `Ctrl + K, Ctrl + C`.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct KeyBind {
     /// A vector of modifiers for the key binding.
@@ -27,6 +31,20 @@ pub struct KeyBind {
 }
 
 impl KeyBind {
+    /// Creates a key binding of a key and modifiers.
+    pub fn new(modifiers: Vec<Modifier>, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Requires the `prefix` to be pressed before this key binding.
+    #[must_use]
+    pub fn with_prefix(self, prefix: KeyBind) -> Shortcut {
+        Shortcut::Sequence {
+            prefix,
+            key_bind: self,
+        }
+    }
+
     /// Checks if the given key and modifiers match the `KeyBind`.
     ///
     /// # Arguments
@@ -63,3 +81,192 @@ impl fmt::Display for KeyBind {
         }
     }
 }
+
+/// A key binding, or a two-step sequence of key bindings such as `Ctrl + K, Ctrl + C`.
+///
+/// Use these in place of [`KeyBind`] as the keys of the key binds given to menus, so that
+/// sequences are displayed in full, and match them with a [`KeySequence`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Shortcut {
+    /// A single key binding.
+    Key(KeyBind),
+    /// A key binding which must be preceded by the `prefix`.
+    Sequence { prefix: KeyBind, key_bind: KeyBind },
+}
+
+impl From<KeyBind> for Shortcut {
+    fn from(key_bind: KeyBind) -> Self {
+        Self::Key(key_bind)
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Key(key_bind) => key_bind.fmt(f),
+            Self::Sequence { prefix, key_bind } => write!(f, "{prefix}, {key_bind}"),
+        }
+    }
+}
+
+/// Tracks the progress of key presses through sequenced key bindings.
+///
+/// Store this in the application, and pass every key press from the keyboard
+/// subscription to [`KeySequence::matches`].
+#[derive(Clone, Debug, Default)]
+pub struct KeySequence {
+    pending: Option<(Modifiers, Key, Instant)>,
+}
+
+impl KeySequence {
+    /// How long to wait for the next key of a sequence before it is abandoned.
+    pub const TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Returns the action bound to this key press, if any.
+    ///
+    /// A key press which begins a sequence is remembered until the next key press,
+    /// or until the [`TIMEOUT`](Self::TIMEOUT) elapses. If the next key press does
+    /// not complete the sequence, it is matched as an ordinary key press. Presses of
+    /// modifier keys alone are ignored, so that they may be held for the next step.
+    pub fn matches<'a, A>(
+        &mut self,
+        shortcuts: &'a HashMap<Shortcut, A>,
+        modifiers: Modifiers,
+        key: &Key,
+    ) -> Option<&'a A> {
+        if is_modifier(key) {
+            return None;
+        }
+
+        if let Some((prefix_modifiers, prefix_key, _)) = self
+            .pending
+            .take()
+            .filter(|(_, _, pressed)| pressed.elapsed() < Self::TIMEOUT)
+        {
+            let completed = shortcuts
+                .iter()
+                .find_map(|(shortcut, action)| match shortcut {
+                    Shortcut::Sequence { prefix, key_bind } => (prefix
+                        .matches(prefix_modifiers, &prefix_key)
+                        && key_bind.matches(modifiers, key))
+                    .then_some(action),
+                    Shortcut::Key(_) => None,
+                });
+
+            if completed.is_some() {
+                return completed;
+            }
+        }
+
+        let action = shortcuts
+            .iter()
+            .find_map(|(shortcut, action)| match shortcut {
+                Shortcut::Key(key_bind) => key_bind.matches(modifiers, key).then_some(action),
+                Shortcut::Sequence { .. } => None,
+            });
+
+        if action.is_none()
+            && shortcuts.keys().any(|shortcut| {
+                matches!(shortcut, Shortcut::Sequence { prefix, .. } if prefix.matches(modifiers, key))
+            })
+        {
+            self.pending = Some((modifiers, key.clone(), Instant::now()));
+        }
+
+        action
+    }
+
+    /// Whether the first key of a sequence has been pressed, and the next is awaited.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+            .as_ref()
+            .is_some_and(|(_, _, pressed)| pressed.elapsed() < Self::TIMEOUT)
+    }
+
+    /// Abandons any sequence in progress.
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// Whether the key is a modifier, which is pressed on its own before the key it modifies.
+fn is_modifier(key: &Key) -> bool {
+    use iced_core::keyboard::key::Named;
+
+    matches!(
+        key,
+        Key::Named(
+            Named::Alt
+                | Named::AltGraph
+                | Named::Control
+                | Named::Fn
+                | Named::Hyper
+                | Named::Meta
+                | Named::Shift
+                | Named::Super
+                | Named::Symbol
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyBind, KeySequence, Modifier, Shortcut};
+    use iced_core::keyboard::key::Named;
+    use iced_core::keyboard::{Key, Modifiers};
+    use std::collections::HashMap;
+
+    fn shortcuts() -> HashMap<Shortcut, &'static str> {
+        let ctrl = |c: &str| KeyBind::new(vec![Modifier::Ctrl], Key::Character(c.into()));
+
+        HashMap::from([
+            (Shortcut::from(ctrl("s")), "save"),
+            (ctrl("c").with_prefix(ctrl("k")), "comment"),
+        ])
+    }
+
+    #[test]
+    fn sequence_survives_modifier_presses() {
+        let shortcuts = shortcuts();
+        let mut sequence = KeySequence::default();
+        let k = Key::Character("k".into());
+        let c = Key::Character("c".into());
+
+        assert_eq!(sequence.matches(&shortcuts, Modifiers::CTRL, &k), None);
+        assert!(sequence.is_pending());
+
+        // Ctrl is released and pressed again before the second step.
+        let ctrl = Key::Named(Named::Control);
+        assert_eq!(sequence.matches(&shortcuts, Modifiers::CTRL, &ctrl), None);
+        assert!(sequence.is_pending());
+
+        assert_eq!(
+            sequence.matches(&shortcuts, Modifiers::CTRL, &c),
+            Some(&"comment")
+        );
+        assert!(!sequence.is_pending());
+    }
+
+    #[test]
+    fn incomplete_sequence_matches_as_key_press() {
+        let shortcuts = shortcuts();
+        let mut sequence = KeySequence::default();
+
+        sequence.matches(&shortcuts, Modifiers::CTRL, &Key::Character("k".into()));
+
+        assert_eq!(
+            sequence.matches(&shortcuts, Modifiers::CTRL, &Key::Character("s".into())),
+            Some(&"save")
+        );
+    }
+
+    #[test]
+    fn sequence_display() {
+        let sequence = KeyBind::new(vec![], Key::Character("h".into())).with_prefix(KeyBind::new(
+            vec![Modifier::Ctrl],
+            Key::Character("k".into()),
+        ));
+
+        assert_eq!(sequence.to_string(), "Ctrl + K, H");
+    }
+}
@@ -2,12 +2,16 @@
 
 //! A widget that handles menu trees
 use super::{
+    action::MenuAction,
     menu_inner::{
         CloseCondition, Direction, ItemHeight, ItemWidth, Menu, MenuState, PathHighlight,
     },
-    menu_tree::MenuTree,
+    menu_tree::{menu_items, menu_root, MenuItem, MenuTree},
 };
 use crate::style::menu_bar::StyleSheet;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 
 use iced::{Point, Vector};
 use iced_core::Border;
@@ -27,6 +31,106 @@ pub fn menu_bar<Message, Renderer: iced_core::Renderer>(
     MenuBar::new(menu_roots)
 }
 
+/// Horizontal padding of a menu root, from [`menu_root`].
+const ROOT_PADDING: f32 = 24.0;
+/// Spacing between the menu roots of a [`responsive_menu_bar`].
+const ROOT_SPACING: f32 = 4.0;
+/// Label of the menu which holds the menus that do not fit.
+const OVERFLOW_LABEL: &str = "»";
+
+/// A menu bar which collapses the menus that do not fit in its width into a trailing
+/// "»" menu.
+///
+/// Each menu is given as its label and items, which are rebuilt whenever the available
+/// width changes.
+///
+/// Labels may mark their mnemonics with `&`, so a literal `&` before a character must be
+/// written as `&&`, as described in [`MenuItem`].
+pub fn responsive_menu_bar<'a, A, L, Message>(
+    key_binds: &'a HashMap<impl fmt::Display, A>,
+    menus: Vec<(L, Vec<MenuItem<A, L>>)>,
+) -> crate::Element<'a, Message>
+where
+    A: MenuAction<Message = Message> + 'a,
+    L: Into<Cow<'static, str>> + Clone + 'static,
+    Message: 'static,
+{
+    crate::widget::responsive(move |size| {
+        let widths = menus
+            .iter()
+            .map(|(label, _)| {
+                let label: Cow<'static, str> = label.clone().into();
+                root_width(&label)
+            })
+            .collect::<Vec<_>>();
+
+        let total = widths.iter().sum::<f32>() + ROOT_SPACING * widths.len() as f32;
+        let visible = if total <= size.width {
+            menus.len()
+        } else {
+            // Reserve space for the overflow menu, then fit as many menus as possible.
+            let mut remaining = size.width - root_width(OVERFLOW_LABEL) - ROOT_SPACING;
+            widths
+                .iter()
+                .take_while(|width| {
+                    remaining -= *width + ROOT_SPACING;
+                    remaining >= 0.0
+                })
+                .count()
+        };
+
+        let (shown, collapsed) = menus.split_at(visible);
+
+        let mut roots = shown
+            .iter()
+            .map(|(label, items)| {
+                MenuTree::with_children(
+                    menu_root(label.clone().into()),
+                    menu_items(key_binds, items.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if !collapsed.is_empty() {
+            let folders = collapsed
+                .iter()
+                .map(|(label, items)| MenuItem::Folder(label.clone(), items.clone()))
+                .collect();
+
+            roots.push(MenuTree::with_children(
+                menu_root(OVERFLOW_LABEL),
+                menu_items(key_binds, folders),
+            ));
+        }
+
+        menu_bar(roots)
+            .item_height(ItemHeight::Dynamic(40))
+            .item_width(ItemWidth::Uniform(240))
+            .spacing(ROOT_SPACING)
+            .into()
+    })
+    .into()
+}
+
+/// The width of a menu root with the given label.
+fn root_width(label: &str) -> f32 {
+    use iced_core::text::{self, Paragraph};
+
+    let paragraph = <crate::Renderer as text::Renderer>::Paragraph::with_text(text::Text {
+        content: label,
+        bounds: iced::Size::INFINITY,
+        size: iced::Pixels(14.0),
+        line_height: text::LineHeight::default(),
+        font: crate::font::default(),
+        horizontal_alignment: iced::alignment::Horizontal::Left,
+        vertical_alignment: iced::alignment::Vertical::Top,
+        shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::None,
+    });
+
+    paragraph.min_width() + ROOT_PADDING
+}
+
 pub(crate) struct MenuBarState {
     pub(crate) pressed: bool,
     pub(crate) view_cursor: Cursor,
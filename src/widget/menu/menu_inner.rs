@@ -6,7 +6,7 @@ use crate::style::menu_bar::StyleSheet;
 
 use iced_core::{Border, Shadow};
 use iced_widget::core::{
-    event,
+    event, keyboard,
     layout::{Limits, Node},
     mouse::{self, Cursor},
     overlay, renderer, touch,
@@ -262,7 +262,14 @@ impl MenuBounds {
 
         // overlay space children position
         let (children_position, offset_position) = {
-            let (cp, op) = aod.resolve(view_parent_bounds, children_size, viewport_size);
+            let (mut cp, op) = aod.resolve(view_parent_bounds, children_size, viewport_size);
+
+            // Shift the menu back into the viewport if there is not enough space on either side.
+            cp.x = cp.x.min(viewport_size.width - children_size.width).max(0.0);
+            cp.y =
+                cp.y.min(viewport_size.height - children_size.height)
+                    .max(0.0);
+
             (cp - overlay_offset, op - overlay_offset)
         };
 
@@ -511,7 +518,7 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         use event::{
-            Event::{Mouse, Touch},
+            Event::{Keyboard, Mouse, Touch},
             Status::{Captured, Ignored},
         };
         use mouse::{
@@ -603,6 +610,20 @@ where
                             Mouse(ButtonReleased(Left)) | Touch(FingerLifted { .. })
                         )
                     {
+                        // Clicking a folder opens its submenu, rather than closing the menu.
+                        if opens_submenu(self, overlay_cursor) {
+                            return process_overlay_events(
+                                self,
+                                renderer,
+                                viewport_size,
+                                overlay_offset,
+                                view_cursor,
+                                overlay_cursor,
+                                self.cross_offset as f32,
+                            );
+                        }
+
+                        let state = self.tree.state.downcast_mut::<MenuBarState>();
                         state.reset();
                         return Captured;
                     }
@@ -622,6 +643,21 @@ where
                 }
             }
 
+            Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if !modifiers.control() && !modifiers.logo() => process_mnemonic_events(
+                self,
+                renderer,
+                shell,
+                viewport_size,
+                overlay_offset,
+                &c,
+                self.cross_offset as f32,
+            )
+            .merge(menu_status),
+
             _ => menu_status,
         }
     }
@@ -734,6 +770,32 @@ where
 
                 renderer.with_layer(render_bounds, draw_menu);
 
+                // draw the tooltip of the hovered item in the last menu
+                if let (true, Some(active)) = (i == state.menu_states.len() - 1, ms.index) {
+                    let start_index = ms
+                        .slice(viewport_size, overlay_offset, self.item_height)
+                        .start_index;
+
+                    let item_bounds = children_layout
+                        .children()
+                        .nth(active.saturating_sub(start_index))
+                        .map(|layout| layout.bounds());
+
+                    if let (Some(tooltip), Some(item_bounds)) =
+                        (&menu_root.children[active].tooltip, item_bounds)
+                    {
+                        draw_tooltip(
+                            tooltip,
+                            renderer,
+                            theme,
+                            style,
+                            viewport,
+                            item_bounds,
+                            view_cursor,
+                        );
+                    }
+                }
+
                 // only the last menu can have a None active index
                 ms.index
                     .map_or(menu_root, |active| &menu_root.children[active])
@@ -741,6 +803,54 @@ where
     }
 }
 
+/// Draws the tooltip of an item beside it, on whichever side has space for it.
+fn draw_tooltip<Message, Renderer>(
+    tooltip: &iced_core::Element<'_, Message, crate::Theme, Renderer>,
+    renderer: &mut Renderer,
+    theme: &crate::Theme,
+    style: &renderer::Style,
+    viewport: Rectangle,
+    item_bounds: Rectangle,
+    view_cursor: Cursor,
+) where
+    Renderer: renderer::Renderer,
+{
+    const GAP: f32 = 4.0;
+
+    let mut tree = Tree::new(tooltip);
+    let node = tooltip.as_widget().layout(
+        &mut tree,
+        renderer,
+        &Limits::new(Size::ZERO, viewport.size()),
+    );
+    let size = node.size();
+
+    let mut x = item_bounds.x + item_bounds.width + GAP;
+    if x + size.width > viewport.x + viewport.width {
+        x = item_bounds.x - size.width - GAP;
+    }
+
+    let position = Point::new(
+        x.max(viewport.x),
+        item_bounds
+            .y
+            .min(viewport.y + viewport.height - size.height)
+            .max(viewport.y),
+    );
+
+    renderer.with_layer(viewport, |renderer| {
+        tooltip.as_widget().draw(
+            &tree,
+            renderer,
+            theme,
+            style,
+            Layout::with_offset(position - Point::ORIGIN, &node),
+            view_cursor,
+            &viewport,
+        );
+    });
+}
+
 fn pad_rectangle(rect: Rectangle, padding: Padding) -> Rectangle {
     Rectangle {
         x: rect.x - padding.left,
@@ -1030,6 +1140,74 @@ where
     // set new index
     last_menu_state.index = Some(new_index);
 
+    push_item_menu(
+        menu,
+        renderer,
+        viewport_size,
+        overlay_offset,
+        cross_offset,
+        active_root,
+        &indices[0..indices.len().saturating_sub(1)],
+        new_index,
+    );
+
+    Captured
+}
+
+/// Whether the cursor is over an item which opens a submenu.
+fn opens_submenu<Message, Renderer>(
+    menu: &Menu<'_, '_, Message, Renderer>,
+    overlay_cursor: Point,
+) -> bool
+where
+    Renderer: renderer::Renderer,
+{
+    let state = menu.tree.state.downcast_ref::<MenuBarState>();
+    let Some(active_root) = state.active_root else {
+        return false;
+    };
+
+    let mut menu_tree = &menu.menu_roots[active_root];
+    for ms in &state.menu_states {
+        let Some(index) = ms.index else {
+            return false;
+        };
+
+        menu_tree = &menu_tree.children[index];
+        if ms.menu_bounds.children_bounds.contains(overlay_cursor) {
+            return !menu_tree.children.is_empty();
+        }
+    }
+
+    false
+}
+
+/// Opens the menu of the item at `new_index` in the last menu, if the item is a menu.
+///
+/// `path` holds the indices of the active items leading to the last menu.
+#[allow(clippy::too_many_arguments)]
+fn push_item_menu<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    renderer: &Renderer,
+    viewport_size: Size,
+    overlay_offset: Vector,
+    cross_offset: f32,
+    active_root: usize,
+    path: &[Option<usize>],
+    new_index: usize,
+) where
+    Renderer: renderer::Renderer,
+{
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let Some(last_menu_state) = state.menu_states.last() else {
+        return;
+    };
+    let last_menu_bounds = &last_menu_state.menu_bounds;
+
+    let active_menu = path.iter().fold(&menu.menu_roots[active_root], |mt, i| {
+        &mt.children[i.expect("missing active child index in menu")]
+    });
+
     // get new active item
     let item = &active_menu.children[new_index];
 
@@ -1073,6 +1251,75 @@ where
             ),
         });
     }
+}
+
+#[allow(unused_results)]
+fn process_mnemonic_events<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    renderer: &Renderer,
+    shell: &mut Shell<'_, Message>,
+    viewport_size: Size,
+    overlay_offset: Vector,
+    key: &str,
+    cross_offset: f32,
+) -> event::Status
+where
+    Renderer: renderer::Renderer,
+{
+    use event::Status::{Captured, Ignored};
+
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+
+    let Some(active_root) = state.active_root else {
+        return Ignored;
+    };
+
+    let key = key.to_lowercase();
+
+    // get indices
+    let indices = state
+        .menu_states
+        .iter()
+        .map(|ms| ms.index)
+        .collect::<Vec<_>>();
+    let path = &indices[0..indices.len().saturating_sub(1)];
+
+    let active_menu = path
+        .iter()
+        .fold(&mut menu.menu_roots[active_root], |mt, i| {
+            &mut mt.children[i.expect("missing active child index in menu")]
+        });
+
+    let Some(new_index) = active_menu.children.iter().position(|mt| {
+        mt.mnemonic
+            .is_some_and(|mnemonic| mnemonic.to_lowercase().eq(key.chars()))
+    }) else {
+        return Ignored;
+    };
+
+    if let Some(message) = active_menu.children[new_index].on_mnemonic.take() {
+        shell.publish(message);
+        state.reset();
+        return Captured;
+    }
+
+    let Some(last_menu_state) = state.menu_states.last_mut() else {
+        return Ignored;
+    };
+
+    // * open the menu of the item
+    last_menu_state.index = Some(new_index);
+
+    push_item_menu(
+        menu,
+        renderer,
+        viewport_size,
+        overlay_offset,
+        cross_offset,
+        active_root,
+        path,
+        new_index,
+    );
 
     Captured
 }
@@ -4,6 +4,7 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt;
 use std::rc::Rc;
 
 use iced_widget::core::{renderer, Element};
@@ -11,7 +12,6 @@ use iced_widget::core::{renderer, Element};
 use crate::iced_core::{Alignment, Length};
 use crate::widget::icon;
 use crate::widget::menu::action::MenuAction;
-use crate::widget::menu::key_bind::KeyBind;
 use crate::{theme, widget};
 
 /// Nested menu is essentially a tree of items, a menu is a collection of items
@@ -36,6 +36,12 @@ pub struct MenuTree<'a, Message, Renderer = crate::Renderer> {
     pub(crate) width: Option<u16>,
     /// The height of the menu tree
     pub(crate) height: Option<u16>,
+    /// The key which activates the menu tree while its menu is open
+    pub(crate) mnemonic: Option<char>,
+    /// The message to emit when activated by its mnemonic
+    pub(crate) on_mnemonic: Option<Message>,
+    /// Shown beside the item while it is hovered
+    pub(crate) tooltip: Option<Element<'a, Message, crate::Theme, Renderer>>,
 }
 
 impl<'a, Message, Renderer> MenuTree<'a, Message, Renderer>
@@ -50,6 +56,9 @@ where
             children: Vec::new(),
             width: None,
             height: None,
+            mnemonic: None,
+            on_mnemonic: None,
+            tooltip: None,
         }
     }
 
@@ -64,6 +73,9 @@ where
             children: children.into_iter().map(Into::into).collect(),
             width: None,
             height: None,
+            mnemonic: None,
+            on_mnemonic: None,
+            tooltip: None,
         }
     }
 
@@ -87,6 +99,27 @@ where
         self
     }
 
+    /// Sets the mnemonic of the menu tree.
+    ///
+    /// Pressing the mnemonic key while its menu is open emits the message, or opens
+    /// the children of the menu tree when there is no message.
+    #[must_use]
+    pub fn mnemonic(mut self, mnemonic: char, message: Option<Message>) -> Self {
+        self.mnemonic = Some(mnemonic);
+        self.on_mnemonic = message;
+        self
+    }
+
+    /// Shows a tooltip beside the item while it is hovered.
+    #[must_use]
+    pub fn tooltip(
+        mut self,
+        tooltip: impl Into<Element<'a, Message, crate::Theme, Renderer>>,
+    ) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     /* Keep `set_index()` and `flattern()` recurse in the same order */
 
     /// Set the index of each item
@@ -159,24 +192,47 @@ pub fn menu_button<'a, Message: 'a>(
 
 /// Represents a menu item that performs an action when selected or a separator between menu items.
 ///
+/// A label may mark its mnemonic with an `&` before the character, such as `&File`, which
+/// underlines the character and activates the item when it is pressed while the menu is open.
+///
+/// Because of this, an `&` which is followed by another character is no longer displayed as
+/// it is: a label such as `R&D` must be written as `R&&D`. An `&` which is followed by
+/// whitespace or ends the label, as in `Save & Close`, is displayed unchanged.
+///
 /// - `Action` - Represents a menu item that performs an action when selected.
 ///     - `L` - The label of the menu item.
 ///     - `A` - The action to perform when the menu item is selected, the action must implement the `MenuAction` trait.
+/// - `ButtonDisabled` - Represents a menu item which is shown, but cannot be activated.
+///     - `L` - The label of the menu item.
+///     - `A` - The action of the menu item, which is not performed while it is disabled.
+/// - `ButtonDisabledTooltip` - Represents a disabled menu item, with a tooltip explaining why.
+///     - `L` - The label of the menu item.
+///     - `A` - The action of the menu item, which is not performed while it is disabled.
+///     - `L` - The explanation, which is shown while the menu item is hovered.
 /// - `CheckBox` - Represents a checkbox menu item.
 ///     - `L` - The label of the menu item.
 ///     - `bool` - The state of the checkbox.
 ///     - `A` - The action to perform when the menu item is selected, the action must implement the `MenuAction` trait.
+/// - `Radio` - Represents a radio menu item, of which one in a group is selected.
+///     - `L` - The label of the menu item.
+///     - `bool` - Whether the option is selected.
+///     - `A` - The action to perform when the menu item is selected, the action must implement the `MenuAction` trait.
 /// - `Folder` - Represents a folder menu item.
 ///     - `L` - The label of the menu item.
 ///     - `Vec<MenuItem<A, L>>` - A vector of menu items.
 /// - `Divider` - Represents a divider between menu items.
+#[derive(Clone)]
 pub enum MenuItem<A: MenuAction, L: Into<Cow<'static, str>>> {
     /// Represents a button menu item.
     Button(L, Option<icon::Handle>, A),
     /// Represents a button menu item that is disabled.
     ButtonDisabled(L, Option<icon::Handle>, A),
+    /// Represents a button menu item that is disabled, with a tooltip explaining why.
+    ButtonDisabledTooltip(L, Option<icon::Handle>, A, L),
     /// Represents a checkbox menu item.
     CheckBox(L, Option<icon::Handle>, bool, A),
+    /// Represents a radio menu item.
+    Radio(L, Option<icon::Handle>, bool, A),
     /// Represents a folder menu item.
     Folder(L, Vec<MenuItem<A, L>>),
     /// Represents a divider between menu items.
@@ -206,8 +262,11 @@ where
 ///
 /// The `MenuItem` can be either an action or a separator.
 ///
+/// Labels are parsed for mnemonics, so a literal `&` before a character must be written as
+/// `&&`, as described in [`MenuItem`].
+///
 /// # Arguments
-/// - `key_binds` - A reference to a `HashMap` that maps `KeyBind` to `A`.
+/// - `key_binds` - A reference to a `HashMap` that maps a `KeyBind` or `Shortcut` to `A`.
 /// - `children` - A vector of `MenuItem`.
 ///
 /// # Returns
@@ -219,13 +278,14 @@ pub fn menu_items<
     Message: 'a,
     Renderer: renderer::Renderer + 'a,
 >(
-    key_binds: &HashMap<KeyBind, A>,
+    key_binds: &HashMap<impl fmt::Display, A>,
     children: Vec<MenuItem<A, L>>,
 ) -> Vec<MenuTree<'a, Message, Renderer>>
 where
-    Element<'a, Message, crate::Theme, Renderer>: From<widget::button::Button<'a, Message>>,
+    Element<'a, Message, crate::Theme, Renderer>: From<widget::button::Button<'a, Message>>
+        + From<widget::Container<'a, Message, crate::Theme, crate::Renderer>>,
 {
-    fn find_key<A: MenuAction>(action: &A, key_binds: &HashMap<KeyBind, A>) -> String {
+    fn find_key<A: MenuAction, K: fmt::Display>(action: &A, key_binds: &HashMap<K, A>) -> String {
         for (key_bind, key_action) in key_binds {
             if action == key_action {
                 return key_bind.to_string();
@@ -234,6 +294,35 @@ where
         String::new()
     }
 
+    // Disabled items have no message, so their action is never performed.
+    let disabled_item = |label: L, icon: Option<icon::Handle>, action: &A, reason: Option<L>| {
+        let spacing = crate::theme::active().cosmic().spacing;
+        let key = find_key(action, key_binds);
+        let (label, _) = menu_label(label.into());
+
+        let mut items = vec![
+            label,
+            widget::horizontal_space().into(),
+            widget::text(key).into(),
+        ];
+
+        if let Some(icon) = icon {
+            items.insert(0, widget::icon::icon(icon).size(14).into());
+            items.insert(1, widget::Space::with_width(spacing.space_xxs).into());
+        }
+
+        let mut tree = MenuTree::<Message, Renderer>::new(menu_button(items));
+        if let Some(reason) = reason {
+            tree = tree.tooltip(
+                widget::container(widget::text::body(reason.into()))
+                    .class(theme::Container::Tooltip)
+                    .padding(spacing.space_xxs),
+            );
+        }
+
+        tree
+    };
+
     let size = children.len();
 
     children
@@ -246,8 +335,9 @@ where
             match item {
                 MenuItem::Button(label, icon, action) => {
                     let key = find_key(&action, key_binds);
+                    let (label, mnemonic) = menu_label(label.into());
                     let mut items = vec![
-                        widget::text(label).into(),
+                        label,
                         widget::horizontal_space().into(),
                         widget::text(key).into(),
                     ];
@@ -259,28 +349,21 @@ where
 
                     let menu_button = menu_button(items).on_press(action.message());
 
-                    trees.push(MenuTree::<Message, Renderer>::new(menu_button));
+                    let mut tree = MenuTree::<Message, Renderer>::new(menu_button);
+                    if let Some(mnemonic) = mnemonic {
+                        tree = tree.mnemonic(mnemonic, Some(action.message()));
+                    }
+                    trees.push(tree);
                 }
                 MenuItem::ButtonDisabled(label, icon, action) => {
-                    let key = find_key(&action, key_binds);
-
-                    let mut items = vec![
-                        widget::text(label).into(),
-                        widget::horizontal_space().into(),
-                        widget::text(key).into(),
-                    ];
-
-                    if let Some(icon) = icon {
-                        items.insert(0, widget::icon::icon(icon).size(14).into());
-                        items.insert(1, widget::Space::with_width(spacing.space_xxs).into());
-                    }
-
-                    let menu_button = menu_button(items);
-
-                    trees.push(MenuTree::<Message, Renderer>::new(menu_button));
+                    trees.push(disabled_item(label, icon, &action, None));
+                }
+                MenuItem::ButtonDisabledTooltip(label, icon, action, reason) => {
+                    trees.push(disabled_item(label, icon, &action, Some(reason)));
                 }
                 MenuItem::CheckBox(label, icon, value, action) => {
                     let key = find_key(&action, key_binds);
+                    let (label, mnemonic) = menu_label(label.into());
                     let mut items = vec![
                         if value {
                             widget::icon::from_name("object-select-symbolic")
@@ -297,7 +380,7 @@ where
                             widget::Space::with_width(Length::Fixed(16.0)).into()
                         },
                         widget::Space::with_width(spacing.space_xxs).into(),
-                        widget::text(label).align_x(iced::Alignment::Start).into(),
+                        label,
                         widget::horizontal_space().into(),
                         widget::text(key).into(),
                     ];
@@ -307,12 +390,39 @@ where
                         items.insert(2, widget::icon::icon(icon).size(14).into());
                     }
 
-                    trees.push(MenuTree::new(menu_button(items).on_press(action.message())));
+                    let mut tree = MenuTree::new(menu_button(items).on_press(action.message()));
+                    if let Some(mnemonic) = mnemonic {
+                        tree = tree.mnemonic(mnemonic, Some(action.message()));
+                    }
+                    trees.push(tree);
+                }
+                MenuItem::Radio(label, icon, selected, action) => {
+                    let key = find_key(&action, key_binds);
+                    let (label, mnemonic) = menu_label(label.into());
+                    let mut items = vec![
+                        radio_indicator(selected),
+                        widget::Space::with_width(spacing.space_xxs).into(),
+                        label,
+                        widget::horizontal_space().into(),
+                        widget::text(key).into(),
+                    ];
+
+                    if let Some(icon) = icon {
+                        items.insert(1, widget::Space::with_width(spacing.space_xxs).into());
+                        items.insert(2, widget::icon::icon(icon).size(14).into());
+                    }
+
+                    let mut tree = MenuTree::new(menu_button(items).on_press(action.message()));
+                    if let Some(mnemonic) = mnemonic {
+                        tree = tree.mnemonic(mnemonic, Some(action.message()));
+                    }
+                    trees.push(tree);
                 }
                 MenuItem::Folder(label, children) => {
-                    trees.push(MenuTree::<Message, Renderer>::with_children(
+                    let (label, mnemonic) = menu_label(label.into());
+                    let mut tree = MenuTree::<Message, Renderer>::with_children(
                         menu_button(vec![
-                            widget::text(label).into(),
+                            label,
                             widget::horizontal_space().into(),
                             widget::icon::from_name("pan-end-symbolic")
                                 .size(16)
@@ -330,7 +440,11 @@ where
                             },
                         ),
                         menu_items(key_binds, children),
-                    ));
+                    );
+                    if let Some(mnemonic) = mnemonic {
+                        tree = tree.mnemonic(mnemonic, None);
+                    }
+                    trees.push(tree);
                 }
                 MenuItem::Divider => {
                     if i != size - 1 {
@@ -344,3 +458,115 @@ where
         })
         .collect()
 }
+
+/// Splits the mnemonic from a label, returning the label without its `&` markers, and the
+/// byte position of the mnemonic in it.
+///
+/// An `&` which is followed by whitespace or ends the label is kept as it is, so that labels
+/// such as "Save & Close" are displayed unchanged.
+pub(crate) fn parse_mnemonic(label: &str) -> (String, Option<(usize, char)>) {
+    let mut text = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.peek().copied() {
+            Some('&') => {
+                chars.next();
+                text.push('&');
+            }
+            Some(c) if !c.is_whitespace() => {
+                chars.next();
+                if mnemonic.is_none() {
+                    mnemonic = Some((text.len(), c));
+                }
+                text.push(c);
+            }
+            _ => text.push('&'),
+        }
+    }
+
+    (text, mnemonic)
+}
+
+/// The label of a menu item, with its mnemonic underlined.
+fn menu_label<'a, Message: 'a>(
+    label: Cow<'static, str>,
+) -> (crate::Element<'a, Message>, Option<char>) {
+    if !label.contains('&') {
+        return (widget::text(label).into(), None);
+    }
+
+    let (text, mnemonic) = parse_mnemonic(&label);
+
+    let Some((position, mnemonic)) = mnemonic else {
+        return (widget::text(text).into(), None);
+    };
+
+    let end = position + mnemonic.len_utf8();
+    let spans: Vec<iced_widget::text::Span<'static, (), iced::Font>> = vec![
+        iced_widget::span(text[..position].to_owned()),
+        iced_widget::span(text[position..end].to_owned()).underline(true),
+        iced_widget::span(text[end..].to_owned()),
+    ];
+
+    (iced_widget::rich_text(spans).into(), Some(mnemonic))
+}
+
+/// A dot which marks the selected option of a radio group.
+fn radio_indicator<'a, Message: 'a>(selected: bool) -> crate::Element<'a, Message> {
+    if !selected {
+        return widget::Space::with_width(Length::Fixed(16.0)).into();
+    }
+
+    widget::container(
+        widget::container(widget::Space::new(Length::Fixed(6.0), Length::Fixed(6.0))).class(
+            theme::Container::custom(|theme| widget::container::Style {
+                background: Some(iced::Background::Color(
+                    theme.cosmic().accent_color().into(),
+                )),
+                border: iced::Border {
+                    radius: 3.0.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        ),
+    )
+    .center(Length::Fixed(16.0))
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_mnemonic;
+
+    #[test]
+    fn parse_mnemonic_marks_the_first_character() {
+        assert_eq!(parse_mnemonic("&Open"), ("Open".into(), Some((0, 'O'))));
+        assert_eq!(
+            parse_mnemonic("Save &As"),
+            ("Save As".into(), Some((5, 'A')))
+        );
+        assert_eq!(parse_mnemonic("Open"), ("Open".into(), None));
+    }
+
+    #[test]
+    fn parse_mnemonic_keeps_literal_ampersands() {
+        assert_eq!(
+            parse_mnemonic("Save & Close"),
+            ("Save & Close".into(), None)
+        );
+        assert_eq!(parse_mnemonic("Find &"), ("Find &".into(), None));
+        assert_eq!(
+            parse_mnemonic("Cut && &Paste"),
+            ("Cut & Paste".into(), Some((6, 'P')))
+        );
+        assert_eq!(parse_mnemonic("R&&D"), ("R&D".into(), None));
+    }
+}
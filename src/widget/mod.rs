@@ -62,8 +62,9 @@ pub use iced::widget::{container, Container};
 #[doc(inline)]
 pub use iced::widget::{horizontal_space, vertical_space, Space};
 
+pub mod image;
 #[doc(inline)]
-pub use iced::widget::{image, Image};
+pub use image::{image, Image};
 
 #[doc(inline)]
 pub use iced::widget::{lazy, Lazy};
@@ -74,9 +75,6 @@ pub use iced::widget::{mouse_area, MouseArea};
 #[doc(inline)]
 pub use iced::widget::{pane_grid, PaneGrid};
 
-#[doc(inline)]
-pub use iced::widget::{progress_bar, ProgressBar};
-
 #[doc(inline)]
 pub use iced::widget::{responsive, Responsive};
 
@@ -111,6 +109,14 @@ pub mod card;
 #[doc(inline)]
 pub use card::*;
 
+pub mod chip;
+#[doc(inline)]
+pub use chip::{chip, Chip};
+
+pub mod command_palette;
+#[doc(inline)]
+pub use command_palette::{command_palette, CommandPalette};
+
 pub mod color_picker;
 #[doc(inline)]
 pub use color_picker::{ColorPicker, ColorPickerModel};
@@ -230,7 +236,7 @@ pub mod grid;
 #[doc(inline)]
 pub use grid::{grid, Grid};
 
-mod header_bar;
+pub(crate) mod header_bar;
 #[doc(inline)]
 pub use header_bar::{header_bar, HeaderBar};
 
@@ -247,6 +253,10 @@ pub mod frames;
 
 pub use taffy::JustifyContent;
 
+pub mod key_value;
+#[doc(inline)]
+pub use key_value::{key_value, KeyValue};
+
 pub mod list;
 #[doc(inline)]
 pub use list::{list_column, ListColumn};
@@ -261,10 +271,18 @@ pub mod nav_bar_toggle;
 #[doc(inline)]
 pub use nav_bar_toggle::{nav_bar_toggle, NavBarToggle};
 
+pub mod number_input;
+#[doc(inline)]
+pub use number_input::{number_input, NumberInput};
+
 pub mod popover;
 #[doc(inline)]
 pub use popover::{popover, Popover};
 
+pub mod progress_bar;
+#[doc(inline)]
+pub use progress_bar::{progress_bar, ProgressBar};
+
 pub mod radio;
 #[doc(inline)]
 pub use radio::{radio, Radio};
@@ -300,9 +318,13 @@ pub mod row {
     }
 }
 
-mod scrollable;
+pub mod scrollable;
+#[doc(inline)]
+pub use scrollable::{scrollable, Scrollable};
+
+pub mod search_field;
 #[doc(inline)]
-pub use scrollable::*;
+pub use search_field::{search_field, SearchField};
 
 pub mod segmented_button;
 pub mod segmented_control;
@@ -313,6 +335,14 @@ pub mod spin_button;
 #[doc(inline)]
 pub use spin_button::{spin_button, vertical as vertical_spin_button, SpinButton};
 
+pub mod spinner;
+#[doc(inline)]
+pub use spinner::{spinner, Spinner};
+
+pub mod split;
+#[doc(inline)]
+pub use split::{split, Split};
+
 pub mod tab_bar;
 
 pub mod text;
@@ -333,38 +363,16 @@ mod toggler;
 #[doc(inline)]
 pub use toggler::toggler;
 
+pub mod tooltip;
 #[doc(inline)]
 pub use tooltip::{tooltip, Tooltip};
-pub mod tooltip {
-    use crate::Element;
-    use std::borrow::Cow;
-
-    pub use iced::widget::tooltip::Position;
-
-    pub type Tooltip<'a, Message> =
-        iced::widget::Tooltip<'a, Message, crate::Theme, crate::Renderer>;
-
-    pub fn tooltip<'a, Message>(
-        content: impl Into<Element<'a, Message>>,
-        tooltip: impl Into<Element<'a, Message>>,
-        position: Position,
-    ) -> Tooltip<'a, Message> {
-        let xxs = crate::theme::active().cosmic().space_xxs();
-
-        Tooltip::new(content, tooltip, position)
-            .class(crate::theme::Container::Tooltip)
-            .padding(xxs)
-            .gap(1)
-    }
-}
 
 pub mod warning;
 #[doc(inline)]
 pub use warning::*;
 
 #[cfg(feature = "markdown")]
-#[doc(inline)]
-pub use iced::widget::markdown;
+pub mod markdown;
 
 #[cfg(feature = "about")]
 pub mod about;
@@ -28,7 +28,9 @@ pub fn nav_bar<Message: Clone + 'static>(
     on_activate: fn(segmented_button::Entity) -> Message,
 ) -> NavBar<Message> {
     NavBar {
-        segmented_button: segmented_button::vertical(model).on_activate(on_activate),
+        segmented_button: segmented_button::vertical(model)
+            .on_activate(on_activate)
+            .type_to_search(true),
     }
 }
 
@@ -48,6 +50,7 @@ where
     NavBar {
         segmented_button: segmented_button::vertical(model)
             .on_activate(on_activate)
+            .type_to_search(true)
             .on_dnd_enter(on_dnd_enter)
             .on_dnd_leave(on_dnd_leave)
             .on_dnd_drop(on_dnd_drop)
@@ -83,6 +86,17 @@ impl<'a, Message: Clone + 'static> NavBar<'a, Message> {
         Container::from(self)
     }
 
+    /// Emitted when a group is expanded or collapsed, along with whether it is expanded.
+    ///
+    /// Apply the change with [`segmented_button::Model::expand`].
+    pub fn on_expand<T>(mut self, on_expand: T) -> Self
+    where
+        T: Fn(Id, bool) -> Message + 'static,
+    {
+        self.segmented_button = self.segmented_button.on_expand(on_expand);
+        self
+    }
+
     /// Emitted when a tab close button is pressed.
     pub fn on_close<T>(mut self, on_close: T) -> Self
     where
@@ -110,6 +124,26 @@ impl<'a, Message: Clone + 'static> NavBar<'a, Message> {
         self
     }
 
+    /// Enables reordering items by dragging them with the mouse.
+    ///
+    /// Emitted with the ID of the dragged item, and the position it was dropped at.
+    /// Apply the change with [`segmented_button::Model::move_item`].
+    pub fn on_reorder<T>(mut self, on_reorder: T) -> Self
+    where
+        T: Fn(Id, usize) -> Message + 'static,
+    {
+        self.segmented_button = self.segmented_button.on_reorder(on_reorder);
+        self
+    }
+
+    /// Attaches a small widget, such as a button or progress ring, to the end of an item.
+    ///
+    /// Clicks on the widget emit its own messages, and will not activate the item.
+    pub fn trailing(mut self, id: Id, element: impl Into<crate::Element<'a, Message>>) -> Self {
+        self.segmented_button = self.segmented_button.trailing(id, element);
+        self
+    }
+
     /// Handle the dnd drop event.
     pub fn on_dnd_drop<D: AllowedMimeTypes>(
         mut self,
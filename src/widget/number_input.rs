@@ -0,0 +1,270 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A numeric field which may be typed into, or stepped with buttons.
+//!
+//! The value is displayed with its digits grouped by the separators of the system locale,
+//! and optionally with a unit before or after it.
+//!
+//! ```no_run
+//! # use cosmic::widget::number_input;
+//! # #[derive(Clone)]
+//! # enum Message { Width(u32) }
+//! let width = number_input(150u32, 10, 0, 4096, Message::Width).suffix(" px");
+//! ```
+
+use super::spin_button::{self, SpinButton};
+use crate::Element;
+use iced::Length;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// A numeric field which may be typed into, or stepped with buttons.
+pub fn number_input<'a, T, M>(
+    value: T,
+    step: T,
+    min: T,
+    max: T,
+    on_change: impl Fn(T) -> M + 'static,
+) -> NumberInput<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd + Display + FromStr + 'static,
+{
+    NumberInput {
+        value,
+        step,
+        min,
+        max,
+        prefix: Cow::Borrowed(""),
+        suffix: Cow::Borrowed(""),
+        separators: Separators::system(),
+        page_step: None,
+        width: Length::Fixed(96.0),
+        on_change: Box::new(on_change),
+    }
+}
+
+/// A numeric field which may be typed into, or stepped with buttons.
+#[must_use]
+pub struct NumberInput<'a, T, M> {
+    value: T,
+    step: T,
+    min: T,
+    max: T,
+    prefix: Cow<'a, str>,
+    suffix: Cow<'a, str>,
+    separators: Separators,
+    page_step: Option<T>,
+    width: Length,
+    on_change: Box<dyn Fn(T) -> M>,
+}
+
+impl<'a, T, M: 'static> NumberInput<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd + Display + FromStr + 'static,
+{
+    /// A unit displayed before the value, such as a currency symbol.
+    pub fn prefix(mut self, prefix: impl Into<Cow<'a, str>>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// A unit displayed after the value, such as `" px"`.
+    pub fn suffix(mut self, suffix: impl Into<Cow<'a, str>>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Overrides the separators of the system locale.
+    pub fn separators(mut self, separators: Separators) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// The amount to increment or decrement by when page up or page down is pressed.
+    pub fn page_step(mut self, page_step: T) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    /// Sets the width of the field between the buttons.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// The value as it is displayed, with its unit.
+    fn label(&self) -> String {
+        [
+            self.prefix.as_ref(),
+            &self.separators.format(&self.value.to_string()),
+            self.suffix.as_ref(),
+        ]
+        .concat()
+    }
+
+    /// The spin button which displays the value.
+    fn into_spin_button(self) -> SpinButton<'a, T, M> {
+        let label = self.label();
+        let separators = self.separators;
+        let prefix = self.prefix.trim().to_owned();
+        let suffix = self.suffix.trim().to_owned();
+
+        let mut spin_button = spin_button::spin_button(
+            label,
+            self.value,
+            self.step,
+            self.min,
+            self.max,
+            self.on_change,
+        )
+        .label_width(self.width)
+        .editable_with(move |text| {
+            let text = text.trim();
+            let text = text.strip_prefix(prefix.as_str()).unwrap_or(text);
+            let text = text.strip_suffix(suffix.as_str()).unwrap_or(text);
+            separators.parse(text)
+        });
+
+        if let Some(page_step) = self.page_step {
+            spin_button = spin_button.page_step(page_step);
+        }
+
+        spin_button
+    }
+}
+
+impl<'a, T, Message> From<NumberInput<'a, T, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'static,
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd + Display + FromStr + 'static,
+{
+    fn from(number_input: NumberInput<'a, T, Message>) -> Self {
+        Self::from(number_input.into_spin_button())
+    }
+}
+
+/// The characters which group the digits of a number, and separate its fraction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Separators {
+    /// Separates each group of three digits, if digits are grouped.
+    pub grouping: Option<char>,
+    /// Separates the integer and fractional parts.
+    pub decimal: char,
+}
+
+impl Default for Separators {
+    fn default() -> Self {
+        Self {
+            grouping: Some(','),
+            decimal: '.',
+        }
+    }
+}
+
+impl Separators {
+    /// The separators of a locale, such as `de_DE.UTF-8`.
+    #[must_use]
+    pub fn from_locale(locale: &str) -> Self {
+        let language = locale
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default();
+
+        match language {
+            "C" | "POSIX" => Self {
+                grouping: None,
+                decimal: '.',
+            },
+            "da" | "de" | "el" | "es" | "id" | "it" | "nl" | "pt" | "tr" => Self {
+                grouping: Some('.'),
+                decimal: ',',
+            },
+            "cs" | "fi" | "fr" | "hu" | "nb" | "nn" | "pl" | "ru" | "sk" | "sv" | "uk" => Self {
+                grouping: Some('\u{202f}'),
+                decimal: ',',
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// The separators of the locale used for numbers, from `LC_ALL`, `LC_NUMERIC`, or `LANG`.
+    #[must_use]
+    pub fn system() -> Self {
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .into_iter()
+            .find_map(|var| std::env::var(var).ok().filter(|value| !value.is_empty()))
+            .map_or_else(Self::default, |locale| Self::from_locale(&locale))
+    }
+
+    /// Groups the digits of a number formatted by [`Display`], and localizes its decimal point.
+    #[must_use]
+    pub fn format(&self, number: &str) -> String {
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number),
+        };
+
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+
+        let mut formatted = String::with_capacity(number.len() + integer.len() / 3 + 1);
+        formatted.push_str(sign);
+
+        for (nth, digit) in integer.chars().enumerate() {
+            if nth > 0 && (integer.len() - nth) % 3 == 0 {
+                formatted.extend(self.grouping);
+            }
+
+            formatted.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+
+        formatted
+    }
+
+    /// Parses a number which was typed with these separators.
+    pub fn parse<T: FromStr>(&self, text: &str) -> Option<T> {
+        text.chars()
+            .filter(|&c| !c.is_whitespace() && Some(c) != self.grouping)
+            .map(|c| if c == self.decimal { '.' } else { c })
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Separators;
+
+    #[test]
+    fn format() {
+        let en = Separators::from_locale("en_US.UTF-8");
+        assert_eq!(en.format("150"), "150");
+        assert_eq!(en.format("1234567"), "1,234,567");
+        assert_eq!(en.format("-1234.5"), "-1,234.5");
+
+        let de = Separators::from_locale("de_DE.UTF-8");
+        assert_eq!(de.format("1234.5"), "1.234,5");
+    }
+
+    #[test]
+    fn parse() {
+        let de = Separators::from_locale("de_DE.UTF-8");
+        assert_eq!(de.parse::<f32>("1.234,5"), Some(1234.5));
+        assert_eq!(de.parse::<u32>(" 150 "), Some(150));
+        assert_eq!(de.parse::<u32>("abc"), None);
+
+        let c = Separators::from_locale("C");
+        assert_eq!(c.parse::<u32>("1,234"), None);
+    }
+}
@@ -37,6 +37,7 @@ pub struct Popover<'a, Message, Renderer> {
     popup: Option<Element<'a, Message, crate::Theme, Renderer>>,
     position: Position,
     on_close: Option<Message>,
+    on_popup: Option<Box<dyn Fn(bool) -> Message + 'a>>,
 }
 
 impl<'a, Message, Renderer> Popover<'a, Message, Renderer> {
@@ -47,6 +48,7 @@ impl<'a, Message, Renderer> Popover<'a, Message, Renderer> {
             popup: None,
             position: Position::Center,
             on_close: None,
+            on_popup: None,
         }
     }
 
@@ -62,6 +64,16 @@ impl<'a, Message, Renderer> Popover<'a, Message, Renderer> {
         self
     }
 
+    /// Emitted when a popup is shown or hidden, with whether it is shown.
+    ///
+    /// The change is detected when the popover handles its next event, rather than when the
+    /// view is rebuilt. This is usually the redraw which follows the rebuild, so the message
+    /// arrives a frame after the popup was added or removed.
+    pub fn on_popup(mut self, on_popup: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_popup = Some(Box::new(on_popup));
+        self
+    }
+
     pub fn popup(mut self, popup: impl Into<Element<'a, Message, crate::Theme, Renderer>>) -> Self {
         self.popup = Some(popup.into());
         self
@@ -85,7 +97,10 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State { is_open: true })
+        tree::State::new(State {
+            is_open: true,
+            popup_shown: false,
+        })
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -141,6 +156,17 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        // Widgets cannot publish messages from `diff`, so a change of popup is reported here.
+        if let Some(on_popup) = self.on_popup.as_ref() {
+            let state = tree.state.downcast_mut::<State>();
+            let popup_shown = self.popup.is_some();
+
+            if state.popup_shown != popup_shown {
+                state.popup_shown = popup_shown;
+                shell.publish(on_popup(popup_shown));
+            }
+        }
+
         if !self.modal
             && matches!(
                 event,
@@ -433,4 +459,6 @@ where
 #[derive(Debug, Default)]
 struct State {
     is_open: bool,
+    /// Whether a popup was shown when `on_popup` last emitted.
+    popup_shown: bool,
 }
@@ -0,0 +1,253 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A bar which displays the progress of a task.
+//!
+//! ```no_run
+//! # let (downloaded, size) = (512.0, 1024.0);
+//! // Progress of a task of known length.
+//! let download = cosmic::widget::progress_bar(0.0..=size, downloaded);
+//!
+//! // Progress of a task of unknown length.
+//! let search = cosmic::widget::progress_bar(0.0..=1.0, 0.0).indeterminate();
+//! ```
+
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+use crate::{Element, Renderer, Theme};
+
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, renderer, window, Clipboard, Layout, Length, Rectangle, Shadow, Shell, Size,
+    Widget,
+};
+
+#[doc(inline)]
+pub use iced::widget::progress_bar::{Catalog, Style};
+
+/// How long the stripe of an indeterminate bar takes to cross the bar.
+const PERIOD: Duration = Duration::from_millis(1500);
+
+/// The width of the stripe of an indeterminate bar, as a fraction of the bar's width.
+const STRIPE_WIDTH: f32 = 0.3;
+
+/// Creates a progress bar which is filled to `value`, within the given `range`.
+pub fn progress_bar<'a>(range: RangeInclusive<f32>, value: f32) -> ProgressBar<'a> {
+    ProgressBar::new(range, value)
+}
+
+/// A bar which displays the progress of a task.
+#[must_use]
+pub struct ProgressBar<'a> {
+    /// The fraction of the range which is filled, from `0.0` to `1.0`.
+    value: f32,
+    indeterminate: bool,
+    width: Length,
+    height: Length,
+    class: crate::theme::ProgressBar,
+    style: Option<Box<dyn Fn(&Theme) -> Style + 'a>>,
+}
+
+impl<'a> ProgressBar<'a> {
+    /// The default height of a progress bar.
+    pub const DEFAULT_HEIGHT: f32 = 4.0;
+
+    /// Creates a progress bar which is filled to `value`, within the given `range`.
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        let (start, end) = range.into_inner();
+        let length = end - start;
+
+        Self {
+            value: if length > 0.0 {
+                ((value - start) / length).clamp(0.0, 1.0)
+            } else {
+                // An empty range has no progress to show.
+                0.0
+            },
+            indeterminate: false,
+            width: Length::Fill,
+            height: Length::Fixed(Self::DEFAULT_HEIGHT),
+            class: crate::theme::ProgressBar::default(),
+            style: None,
+        }
+    }
+
+    /// Animates a stripe across the bar in place of the value, for tasks of unknown length.
+    pub fn indeterminate(mut self) -> Self {
+        self.indeterminate = true;
+        self
+    }
+
+    /// Sets the width of the bar.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the bar.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the bar with a function.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Sets the style class of the bar.
+    pub fn class(mut self, class: impl Into<crate::theme::ProgressBar>) -> Self {
+        self.class = class.into();
+        self.style = None;
+        self
+    }
+}
+
+/// The animation state of an indeterminate [`ProgressBar`].
+#[derive(Default)]
+struct State {
+    started: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl State {
+    /// The position of the stripe, from `0.0` to `1.0`, as it crosses the bar.
+    fn phase(&self) -> f32 {
+        match (self.started, self.now) {
+            (Some(started), Some(now)) => {
+                (now - started).as_secs_f32() % PERIOD.as_secs_f32() / PERIOD.as_secs_f32()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for ProgressBar<'a> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.indeterminate {
+            state.started = None;
+            return event::Status::Ignored;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.started.get_or_insert(now);
+            state.now = Some(now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = match &self.style {
+            Some(style) => style(theme),
+            None => Catalog::style(theme, &self.class),
+        };
+
+        iced_core::Renderer::fill_quad(
+            renderer,
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: Shadow::default(),
+            },
+            style.background,
+        );
+
+        let (x, width) = if self.indeterminate {
+            let phase = tree.state.downcast_ref::<State>().phase();
+            let stripe = bounds.width * STRIPE_WIDTH;
+
+            // The stripe enters from the leading edge, and leaves from the trailing edge.
+            let start = bounds.x - stripe + (bounds.width + stripe) * phase;
+            let end = start + stripe;
+            let start = start.max(bounds.x);
+
+            (start, end.min(bounds.x + bounds.width) - start)
+        } else {
+            (bounds.x, bounds.width * self.value)
+        };
+
+        if width > 0.0 {
+            iced_core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: Rectangle { x, width, ..bounds },
+                    border: style.border,
+                    shadow: Shadow::default(),
+                },
+                style.bar,
+            );
+        }
+    }
+}
+
+impl<'a, Message: 'a> From<ProgressBar<'a>> for Element<'a, Message> {
+    fn from(progress_bar: ProgressBar<'a>) -> Self {
+        Self::new(progress_bar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProgressBar;
+
+    #[test]
+    fn value_is_a_fraction_of_the_range() {
+        assert_eq!(ProgressBar::new(10.0..=20.0, 15.0).value, 0.5);
+        assert_eq!(ProgressBar::new(10.0..=20.0, 30.0).value, 1.0);
+        assert_eq!(ProgressBar::new(10.0..=20.0, 0.0).value, 0.0);
+    }
+
+    #[test]
+    fn empty_range_is_empty() {
+        assert_eq!(ProgressBar::new(5.0..=5.0, 5.0).value, 0.0);
+        assert_eq!(ProgressBar::new(5.0..=1.0, 5.0).value, 0.0);
+    }
+}
@@ -1,11 +1,198 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
+//! Scroll content which does not fit, and control the scroll position from the application.
+//!
+//! ```no_run
+//! # use cosmic::widget::{self, scrollable, Id};
+//! # #[derive(Clone)]
+//! # enum Message { Scrolled(scrollable::AbsoluteOffset) }
+//! # let (list_id, item_id) = (Id::new("list"), Id::new("item"));
+//! # let content = widget::text("content");
+//! // Report the offset as it is scrolled, so that it may be persisted.
+//! let view = widget::scrollable(content)
+//!     .id(list_id.clone())
+//!     .on_scroll(|viewport| Message::Scrolled(viewport.absolute_offset()));
+//!
+//! // Scroll an item wrapped in an `id_container` into view.
+//! let task = scrollable::scroll_to_child::<Message>(list_id, item_id);
+//! ```
+
 use crate::{Element, Renderer};
 use iced::widget;
+use iced_core::widget::operation::{self, Operation, Outcome};
+use iced_core::widget::Id;
+use iced_core::{Rectangle, Vector};
+use iced_runtime::{task, Action, Task};
+
+#[doc(inline)]
+pub use iced::widget::scrollable::{
+    scroll_to, snap_to, AbsoluteOffset, Direction, RelativeOffset, Scrollable, Scrollbar, Viewport,
+};
 
 pub fn scrollable<'a, Message>(
     element: impl Into<Element<'a, Message>>,
 ) -> widget::Scrollable<'a, Message, crate::Theme, Renderer> {
     widget::scrollable(element)
 }
+
+/// Scrolls the scrollable of the given [`Id`] just enough to show its child of the `child` [`Id`].
+///
+/// Give the child an [`Id`] by wrapping it in an [`id_container`](super::id_container).
+/// Children which are already visible are not scrolled to.
+pub fn scroll_to_child<Message>(scrollable: Id, child: Id) -> Task<Message> {
+    task::effect(Action::widget(FindChild {
+        scrollable,
+        child,
+        in_scope: false,
+        viewport: None,
+        target: None,
+    }))
+}
+
+/// Reads the current offset of the scrollable of the given [`Id`], such as to persist it.
+///
+/// Restore the offset later with [`scroll_to`].
+pub fn offset<Message: Send + 'static>(
+    scrollable: Id,
+    f: impl Fn(AbsoluteOffset) -> Message + Send + 'static,
+) -> Task<Message> {
+    task::widget(FindOffset {
+        scrollable,
+        offset: None,
+    })
+    .map(f)
+}
+
+/// Finds the bounds of a child within a scrollable, and then scrolls it into view.
+struct FindChild {
+    scrollable: Id,
+    child: Id,
+    in_scope: bool,
+    /// The bounds, content bounds, and translation of the scrollable.
+    viewport: Option<(Rectangle, Rectangle, Vector)>,
+    target: Option<Rectangle>,
+}
+
+impl Operation for FindChild {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        if self.in_scope && id == Some(&self.child) {
+            self.target = Some(bounds);
+        }
+
+        if self.in_scope || id != Some(&self.scrollable) {
+            operate_on_children(self);
+            return;
+        }
+
+        self.in_scope = true;
+        operate_on_children(self);
+        self.in_scope = false;
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if id == Some(&self.scrollable) {
+            self.viewport = Some((bounds, content_bounds, translation));
+        }
+    }
+
+    fn finish(&self) -> Outcome<()> {
+        let (Some((bounds, content_bounds, translation)), Some(target)) =
+            (self.viewport, self.target)
+        else {
+            return Outcome::None;
+        };
+
+        let offset = AbsoluteOffset {
+            x: reveal(
+                target.x - content_bounds.x,
+                target.width,
+                translation.x,
+                bounds.width,
+            ),
+            y: reveal(
+                target.y - content_bounds.y,
+                target.height,
+                translation.y,
+                bounds.height,
+            ),
+        };
+
+        if offset
+            == (AbsoluteOffset {
+                x: translation.x,
+                y: translation.y,
+            })
+        {
+            return Outcome::None;
+        }
+
+        Outcome::Chain(Box::new(operation::scrollable::scroll_to(
+            self.scrollable.clone(),
+            offset,
+        )))
+    }
+}
+
+/// The offset along an axis which shows a child from `start` to `start + length`, while
+/// scrolling as little as possible from the current `offset`.
+fn reveal(start: f32, length: f32, offset: f32, viewport: f32) -> f32 {
+    if start < offset || length > viewport {
+        start
+    } else if start + length > offset + viewport {
+        start + length - viewport
+    } else {
+        offset
+    }
+}
+
+/// Reads the offset of a scrollable.
+struct FindOffset {
+    scrollable: Id,
+    offset: Option<AbsoluteOffset>,
+}
+
+impl Operation<AbsoluteOffset> for FindOffset {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<AbsoluteOffset>),
+    ) {
+        if self.offset.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if id == Some(&self.scrollable) {
+            self.offset = Some(AbsoluteOffset {
+                x: translation.x,
+                y: translation.y,
+            });
+        }
+    }
+
+    fn finish(&self) -> Outcome<AbsoluteOffset> {
+        self.offset.map_or(Outcome::None, Outcome::Some)
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A search input which displays its results in a popover beneath it.
+//!
+//! ```no_run
+//! # use std::time::Duration;
+//! # #[derive(Clone)]
+//! # enum Message { Query(String), Search(String), Selected(usize) }
+//! # let (query, results) = (String::new(), Vec::<String>::new());
+//! let field = cosmic::widget::search_field("Search", &query, &results, Message::Selected)
+//!     .on_input(Message::Query)
+//!     .on_search(Duration::from_millis(250), Message::Search);
+//! ```
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::widget::dropdown::menu::{self, Menu};
+use crate::widget::text_input::{self, search_input, TextInput};
+use crate::{Element, Renderer, Theme};
+
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Id, Operation, Tree};
+use iced_core::{
+    keyboard, layout, mouse, overlay, renderer, Clipboard, Layout, Length, Padding, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+/// A search input which displays its results in a popover beneath it.
+///
+/// Results may be navigated with the up and down arrow keys, and selected with enter.
+pub fn search_field<'a, S: AsRef<str>, Message: Clone + 'static>(
+    placeholder: impl Into<Cow<'a, str>>,
+    value: impl Into<Cow<'a, str>>,
+    results: &'a [S],
+    on_select: impl Fn(usize) -> Message + 'a,
+) -> SearchField<'a, S, Message> {
+    SearchField::new(placeholder, value, results, on_select)
+}
+
+/// A search input which displays its results in a popover beneath it.
+#[must_use]
+pub struct SearchField<'a, S: AsRef<str>, Message> {
+    input: TextInput<'a, Message>,
+    results: &'a [S],
+    on_select: Box<dyn Fn(usize) -> Message + 'a>,
+}
+
+impl<'a, S: AsRef<str>, Message: Clone + 'static> SearchField<'a, S, Message> {
+    /// The padding of each result in the popover.
+    pub const RESULT_PADDING: Padding = Padding::new(8.0);
+
+    /// Creates a new [`SearchField`] which displays the given `results`.
+    pub fn new(
+        placeholder: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+        results: &'a [S],
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        Self {
+            input: search_input(placeholder, value),
+            results,
+            on_select: Box::new(on_select),
+        }
+    }
+
+    /// Sets the [`Id`] of the search input.
+    pub fn id(mut self, id: Id) -> Self {
+        self.input = self.input.id(id);
+        self
+    }
+
+    /// Emitted when the query is changed.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.input = self.input.on_input(on_input);
+        self
+    }
+
+    /// Emitted once the query has not changed for the `debounce` duration.
+    ///
+    /// Use this to request results asynchronously without searching on every keystroke.
+    pub fn on_search(
+        mut self,
+        debounce: Duration,
+        on_search: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.input = self.input.on_validate(debounce, on_search);
+        self
+    }
+
+    /// Emitted when enter is pressed while no result is highlighted.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.input = self.input.on_submit(message);
+        self
+    }
+
+    /// Displays a spinner in the search input while results are pending.
+    pub fn searching(mut self, searching: bool) -> Self {
+        self.input = self.input.validating(searching);
+        self
+    }
+
+    /// Sets the width of the search input.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+}
+
+/// The local state of a [`SearchField`].
+#[derive(Debug, Default)]
+struct State {
+    menu: menu::State,
+    highlighted: Option<usize>,
+    dismissed: bool,
+}
+
+impl<'a, S: AsRef<str>, Message: Clone + 'static> SearchField<'a, S, Message> {
+    fn input_is_focused(tree: &Tree) -> bool {
+        tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused()
+    }
+}
+
+impl<'a, S: AsRef<str>, Message: Clone + 'static> Widget<Message, Theme, Renderer>
+    for SearchField<'a, S, Message>
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input as &dyn Widget<Message, Theme, Renderer>,
+        )]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.children[0].diff(&mut self.input as &mut dyn Widget<Message, Theme, Renderer>);
+
+        // Forget the highlighted result if the results have changed.
+        let state = tree.state.downcast_mut::<State>();
+        if state
+            .highlighted
+            .is_some_and(|highlighted| highlighted >= self.results.len())
+        {
+            state.highlighted = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::size(&self.input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::layout(&self.input, &mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        Widget::operate(
+            &self.input,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let focused = Self::input_is_focused(tree);
+        let state = tree.state.downcast_mut::<State>();
+
+        if focused && !state.dismissed && !self.results.is_empty() {
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key),
+                ..
+            }) = &event
+            {
+                let last = self.results.len() - 1;
+
+                match key {
+                    keyboard::key::Named::ArrowDown => {
+                        state.highlighted = Some(match state.highlighted {
+                            Some(nth) if nth < last => nth + 1,
+                            _ => 0,
+                        });
+                        return event::Status::Captured;
+                    }
+
+                    keyboard::key::Named::ArrowUp => {
+                        state.highlighted = Some(match state.highlighted {
+                            Some(nth) if nth > 0 => nth - 1,
+                            _ => last,
+                        });
+                        return event::Status::Captured;
+                    }
+
+                    keyboard::key::Named::Enter => {
+                        if let Some(nth) = state.highlighted.take() {
+                            state.dismissed = true;
+                            shell.publish((self.on_select)(nth));
+                            return event::Status::Captured;
+                        }
+                    }
+
+                    keyboard::key::Named::Escape => {
+                        state.dismissed = true;
+                        state.highlighted = None;
+                    }
+
+                    _ => (),
+                }
+            }
+        }
+
+        let status = Widget::on_event(
+            &mut self.input,
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // Show the results again once the query is edited.
+        if let (
+            event::Status::Captured,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key:
+                    keyboard::Key::Character(_)
+                    | keyboard::Key::Named(
+                        keyboard::key::Named::Backspace
+                        | keyboard::key::Named::Delete
+                        | keyboard::key::Named::Space,
+                    ),
+                ..
+            }),
+        ) = (status, event)
+        {
+            let state = tree.state.downcast_mut::<State>();
+            state.dismissed = false;
+            state.highlighted = None;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::mouse_interaction(
+            &self.input,
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::draw(
+            &self.input,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        if self.results.is_empty() || !Self::input_is_focused(tree) {
+            return None;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        if state.dismissed {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let on_select = &self.on_select;
+
+        let menu = Menu::new(
+            &mut state.menu,
+            self.results,
+            &[],
+            &mut state.highlighted,
+            None,
+            on_select,
+            None,
+        )
+        .width(bounds.width)
+        .padding(Self::RESULT_PADDING);
+
+        Some(menu.overlay(layout.position() + translation, bounds.height))
+    }
+}
+
+impl<'a, S: AsRef<str>, Message: Clone + 'static> From<SearchField<'a, S, Message>>
+    for Element<'a, Message>
+{
+    fn from(field: SearchField<'a, S, Message>) -> Self {
+        Element::new(field)
+    }
+}
@@ -211,6 +211,17 @@ where
             state.buttons_offset = num - state.buttons_visible;
         }
 
+        // Keep a newly-activated button in view when buttons are collapsed.
+        if let Some(nth) = self.newly_active_position(state) {
+            if state.collapsed && state.buttons_visible > 0 {
+                if nth < state.buttons_offset {
+                    state.buttons_offset = nth;
+                } else if nth >= state.buttons_offset + state.buttons_visible {
+                    state.buttons_offset = nth + 1 - state.buttons_visible;
+                }
+            }
+        }
+
         size
     }
 }
@@ -76,13 +76,15 @@
 mod horizontal;
 mod model;
 mod style;
+mod tooltip;
 mod vertical;
 mod widget;
 
 pub use self::horizontal::{horizontal, HorizontalSegmentedButton};
 pub use self::model::{
-    BuilderEntity, Entity, EntityMut, Model, ModelBuilder, MultiSelect, MultiSelectEntityMut,
-    MultiSelectModel, Selectable, SingleSelect, SingleSelectEntityMut, SingleSelectModel,
+    Badge, BuilderEntity, Entity, EntityMut, ItemKind, Model, ModelBuilder, MultiSelect,
+    MultiSelectEntityMut, MultiSelectModel, Selectable, SingleSelect, SingleSelectEntityMut,
+    SingleSelectModel,
 };
 pub use self::style::{Appearance, ItemAppearance, ItemStatusAppearance, StyleSheet};
 pub use self::vertical::{vertical, VerticalSegmentedButton};
@@ -3,7 +3,7 @@
 
 use slotmap::{SecondaryMap, SparseSecondaryMap};
 
-use super::{Entity, Model, Selectable};
+use super::{Badge, Entity, Model, Selectable};
 use crate::widget::icon::Icon;
 use std::borrow::Cow;
 
@@ -113,6 +113,13 @@ where
         self
     }
 
+    /// Define a count drawn at the trailing edge of the item, which is hidden when zero.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn badge(mut self, count: u32) -> Self {
+        self.model.0.badge_set(self.id, Badge::Count(count));
+        self
+    }
+
     /// Define the position of the newly-inserted item.
     #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
     pub fn position(mut self, position: u16) -> Self {
@@ -134,6 +141,19 @@ where
         self
     }
 
+    /// Defines a tooltip for the item, which is shown while it is hovered.
+    ///
+    /// ```ignore
+    /// segmented_button::Model::builder()
+    ///     .insert(|b| b.icon("view-grid-symbolic").tooltip("Grid view"))
+    ///     .build()
+    /// ```
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn tooltip(mut self, tooltip: impl Into<Cow<'static, str>>) -> Self {
+        self.model.0.tooltip_set(self.id, tooltip);
+        self
+    }
+
     /// Calls a function with the ID
     #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
     pub fn with_id(self, func: impl FnOnce(Entity)) -> Self {
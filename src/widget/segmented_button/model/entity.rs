@@ -7,7 +7,7 @@ use slotmap::{SecondaryMap, SparseSecondaryMap};
 
 use crate::widget::Icon;
 
-use super::{Entity, Model, Selectable};
+use super::{Badge, Entity, Model, Selectable};
 
 /// A newly-inserted item which may have additional actions applied to it.
 pub struct EntityMut<'a, SelectionMode: Default> {
@@ -83,12 +83,41 @@ where
         self
     }
 
+    /// Define a count drawn at the trailing edge of the item, which is hidden when zero.
+    ///
+    /// ```ignore
+    /// model.insert().text("Inbox").badge(unread);
+    /// ```
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn badge(self, count: u32) -> Self {
+        self.model.badge_set(self.id, Badge::Count(count));
+        self
+    }
+
+    /// Define whether a dot is drawn at the trailing edge of the item.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn indicator(self, indicator: bool) -> Self {
+        if indicator {
+            self.model.badge_set(self.id, Badge::Indicator);
+        } else {
+            self.model.badge_remove(self.id);
+        }
+        self
+    }
+
     #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
     pub fn divider_above(self, divider_above: bool) -> Self {
         self.model.divider_above_set(self.id, divider_above);
         self
     }
 
+    /// Define whether the children of the item are displayed, when it is a group.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn expanded(self, expanded: bool) -> Self {
+        self.model.expand(self.id, expanded);
+        self
+    }
+
     /// Define an icon for the item.
     ///
     /// ```ignore
@@ -116,6 +145,20 @@ where
         self
     }
 
+    /// Places the item in a group, indented beneath it.
+    ///
+    /// Pressing a group emits the widget's `on_expand` message, rather than activating it.
+    ///
+    /// ```ignore
+    /// let network = model.insert().text("Network").id();
+    /// model.insert().text("Wi-Fi").parent(network);
+    /// ```
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn parent(self, parent: Entity) -> Self {
+        self.model.parent_set(self.id, parent);
+        self
+    }
+
     /// Define the position of the item.
     #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
     pub fn position(self, position: u16) -> Self {
@@ -137,6 +180,13 @@ where
         self
     }
 
+    /// Defines a tooltip for the item, which is shown while it is hovered.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn tooltip(self, tooltip: impl Into<Cow<'static, str>>) -> Self {
+        self.model.tooltip_set(self.id, tooltip);
+        self
+    }
+
     /// Calls a function with the ID without consuming the wrapper.
     #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
     pub fn with_id(self, func: impl FnOnce(Entity)) -> Self {
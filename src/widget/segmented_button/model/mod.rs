@@ -25,6 +25,7 @@ slotmap::new_key_type! {
 pub struct Settings {
     pub enabled: bool,
     pub closable: bool,
+    pub kind: ItemKind,
 }
 
 impl Default for Settings {
@@ -32,10 +33,39 @@ impl Default for Settings {
         Self {
             enabled: true,
             closable: false,
+            kind: ItemKind::Button,
         }
     }
 }
 
+/// Defines how an item in the [`Model`] is displayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ItemKind {
+    /// A selectable button.
+    #[default]
+    Button,
+    /// A non-activatable line separating items.
+    Divider,
+    /// A non-activatable caption for a group of items.
+    Header,
+}
+
+/// A decoration drawn at the trailing edge of an item, such as an unread count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Badge {
+    /// A count, which is hidden when zero.
+    Count(u32),
+    /// A dot without a count.
+    Indicator,
+}
+
+impl Badge {
+    /// Whether the badge is drawn.
+    pub fn is_visible(self) -> bool {
+        !matches!(self, Self::Count(0))
+    }
+}
+
 /// A model for single-select button selection.
 pub type SingleSelectModel = Model<SingleSelect>;
 
@@ -58,6 +88,18 @@ pub struct Model<SelectionMode: Default> {
     /// The content used for drawing segmented items.
     pub(super) items: SlotMap<Entity, Settings>,
 
+    /// Badge optionally-defined for each item.
+    pub(super) badges: SecondaryMap<Entity, Badge>,
+
+    /// Children of each group, in the order that they are displayed when expanded.
+    pub(super) children: SecondaryMap<Entity, Vec<Entity>>,
+
+    /// Whether each group is expanded.
+    pub(super) expanded: SecondaryMap<Entity, bool>,
+
+    /// The group that each child belongs to.
+    pub(super) parents: SecondaryMap<Entity, Entity>,
+
     /// Divider optionally-defined for each item.
     pub(super) divider_aboves: SecondaryMap<Entity, bool>,
 
@@ -70,6 +112,9 @@ pub struct Model<SelectionMode: Default> {
     /// Text optionally-defined for each item.
     pub(super) text: SecondaryMap<Entity, Cow<'static, str>>,
 
+    /// Tooltip optionally-defined for each item.
+    pub(super) tooltips: SecondaryMap<Entity, Cow<'static, str>>,
+
     /// Order which the items will be displayed.
     pub(super) order: VecDeque<Entity>,
 
@@ -94,10 +139,14 @@ where
     }
 
     /// Activates the item at the given position, returning true if it was activated.
+    ///
+    /// Dividers, headers, and groups cannot be activated.
     pub fn activate_position(&mut self, position: u16) -> bool {
         if let Some(entity) = self.entity_at(position) {
-            self.activate(entity);
-            return true;
+            if self.is_activatable(entity) && !self.is_group(entity) {
+                self.activate(entity);
+                return true;
+            }
         }
 
         false
@@ -203,6 +252,29 @@ where
             .and_then(|storage| storage.remove(id));
     }
 
+    /// The badge drawn at the trailing edge of the item.
+    pub fn badge(&self, id: Entity) -> Option<Badge> {
+        self.badges.get(id).copied()
+    }
+
+    /// Sets the badge drawn at the trailing edge of an item.
+    ///
+    /// ```ignore
+    /// model.badge_set(id, Badge::Count(unread));
+    /// ```
+    pub fn badge_set(&mut self, id: Entity, badge: Badge) -> Option<Badge> {
+        if !self.contains_item(id) {
+            return None;
+        }
+
+        self.badges.insert(id, badge)
+    }
+
+    /// Removes the badge from an item.
+    pub fn badge_remove(&mut self, id: Entity) -> Option<Badge> {
+        self.badges.remove(id)
+    }
+
     pub fn divider_above(&self, id: Entity) -> Option<bool> {
         self.divider_aboves.get(id).copied()
     }
@@ -219,6 +291,160 @@ where
         self.divider_aboves.remove(id)
     }
 
+    /// The children of a group, in the order that they are displayed when expanded.
+    pub fn children(&self, id: Entity) -> &[Entity] {
+        self.children.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether the item has children which may be expanded or collapsed.
+    pub fn is_group(&self, id: Entity) -> bool {
+        !self.children(id).is_empty()
+    }
+
+    /// Whether the children of a group are displayed.
+    pub fn is_expanded(&self, id: Entity) -> bool {
+        self.expanded.get(id).copied().unwrap_or(false)
+    }
+
+    /// Displays or hides the children of a group.
+    ///
+    /// Hidden children keep their data, and are displayed again when the group is expanded.
+    ///
+    /// ```ignore
+    /// model.expand(id, true);
+    /// ```
+    pub fn expand(&mut self, id: Entity, expand: bool) {
+        if !self.contains_item(id) || self.is_expanded(id) == expand {
+            return;
+        }
+
+        self.expanded.insert(id, expand);
+
+        if self.position(id).is_none() {
+            // The group is itself hidden, so its children will be shown with it.
+            return;
+        }
+
+        if expand {
+            self.show_children(id);
+        } else {
+            self.hide_descendants(id);
+        }
+    }
+
+    /// The group that the item belongs to.
+    pub fn parent(&self, id: Entity) -> Option<Entity> {
+        self.parents.get(id).copied()
+    }
+
+    /// Places an item in a group, indented beneath it.
+    ///
+    /// The item is displayed after the group's other children, if the group is expanded.
+    ///
+    /// ```ignore
+    /// model.parent_set(child, group);
+    /// ```
+    pub fn parent_set(&mut self, id: Entity, parent: Entity) {
+        if id == parent || !self.contains_item(id) || !self.contains_item(parent) {
+            return;
+        }
+
+        // Prevent cycles by refusing to place a group beneath its own descendant.
+        let mut ancestor = Some(parent);
+        while let Some(entity) = ancestor {
+            if entity == id {
+                return;
+            }
+            ancestor = self.parent(entity);
+        }
+
+        self.parent_remove(id);
+
+        let indent = self.indent(parent).unwrap_or(0) + 1;
+        self.indents.insert(id, indent);
+        self.parents.insert(id, parent);
+
+        if let Some(position) = self.position(id) {
+            self.order.remove(position as usize);
+        }
+        self.hide_descendants(id);
+
+        let children = self.children.entry(parent).unwrap().or_default();
+        children.push(id);
+
+        if self.is_expanded(parent) && self.position(parent).is_some() {
+            let position = self.position_after_descendants(parent);
+            self.order.insert(position, id);
+            if self.is_expanded(id) {
+                self.show_children(id);
+            }
+        }
+    }
+
+    /// Removes an item from its group, displaying it at the end of the model.
+    pub fn parent_remove(&mut self, id: Entity) -> Option<Entity> {
+        let parent = self.parents.remove(id)?;
+
+        if let Some(children) = self.children.get_mut(parent) {
+            children.retain(|&child| child != id);
+        }
+
+        self.indents.remove(id);
+
+        if self.position(id).is_none() {
+            self.order.push_back(id);
+            if self.is_expanded(id) {
+                self.show_children(id);
+            }
+        }
+
+        Some(parent)
+    }
+
+    /// Inserts the children of an expanded group after it, along with their expanded children.
+    fn show_children(&mut self, id: Entity) {
+        let Some(mut position) = self.position(id).map(usize::from) else {
+            return;
+        };
+
+        for child in self.children(id).to_vec() {
+            if self.position(child).is_none() {
+                position += 1;
+                self.order.insert(position, child);
+            }
+
+            if self.is_expanded(child) {
+                self.show_children(child);
+            }
+
+            position = self.position_after_descendants(child) - 1;
+        }
+    }
+
+    /// Removes the displayed descendants of a group from the display order.
+    fn hide_descendants(&mut self, id: Entity) {
+        for child in self.children(id).to_vec() {
+            self.hide_descendants(child);
+            if let Some(position) = self.position(child) {
+                self.order.remove(position as usize);
+            }
+        }
+    }
+
+    /// The position following the last displayed descendant of an item.
+    fn position_after_descendants(&self, id: Entity) -> usize {
+        let Some(position) = self.position(id).map(usize::from) else {
+            return self.order.len();
+        };
+
+        self.children(id)
+            .iter()
+            .filter(|&&child| self.position(child).is_some())
+            .map(|&child| self.position_after_descendants(child))
+            .max()
+            .unwrap_or(position + 1)
+    }
+
     /// Enable or disable an item.
     ///
     /// ```ignore
@@ -284,6 +510,36 @@ where
         EntityMut { model: self, id }
     }
 
+    /// Inserts a divider in the model, which separates the items around it.
+    ///
+    /// ```ignore
+    /// model.insert_divider();
+    /// ```
+    pub fn insert_divider(&mut self) -> EntityMut<SelectionMode> {
+        self.insert_kind(ItemKind::Divider)
+    }
+
+    /// Inserts a header in the model, which is displayed as a caption for a group of items.
+    ///
+    /// ```ignore
+    /// model.insert_header("Appearance");
+    /// ```
+    pub fn insert_header(
+        &mut self,
+        text: impl Into<Cow<'static, str>>,
+    ) -> EntityMut<SelectionMode> {
+        self.insert_kind(ItemKind::Header).text(text)
+    }
+
+    fn insert_kind(&mut self, kind: ItemKind) -> EntityMut<SelectionMode> {
+        let id = self.items.insert(Settings {
+            kind,
+            ..Settings::default()
+        });
+        self.order.push_back(id);
+        EntityMut { model: self, id }
+    }
+
     /// Check if the given ID is the active ID.
     #[must_use]
     pub fn is_active(&self, id: Entity) -> bool {
@@ -310,6 +566,32 @@ where
         self.items.get(id).map_or(false, |e| e.enabled)
     }
 
+    /// Check if the item is an enabled button, which can be activated.
+    #[must_use]
+    pub fn is_activatable(&self, id: Entity) -> bool {
+        self.items
+            .get(id)
+            .map_or(false, |e| e.enabled && e.kind == ItemKind::Button)
+    }
+
+    /// Check if the item is a divider.
+    #[must_use]
+    pub fn is_divider(&self, id: Entity) -> bool {
+        self.kind(id) == Some(ItemKind::Divider)
+    }
+
+    /// Check if the item is a header.
+    #[must_use]
+    pub fn is_header(&self, id: Entity) -> bool {
+        self.kind(id) == Some(ItemKind::Header)
+    }
+
+    /// The kind of item that the ID refers to.
+    #[must_use]
+    pub fn kind(&self, id: Entity) -> Option<ItemKind> {
+        self.items.get(id).map(|e| e.kind)
+    }
+
     /// Iterates across items in the model in the order that they are displayed.
     pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
         self.order.iter().copied()
@@ -363,6 +645,71 @@ where
         Some(position)
     }
 
+    /// Moves an item to a new position, shifting the items in between.
+    ///
+    /// A group is moved together with its displayed descendants, and an item in a group is
+    /// kept among the other children of its group.
+    ///
+    /// Returns the position that the item was placed at.
+    ///
+    /// ```ignore
+    /// if let Some(new_position) = model.move_item(id, 2) {
+    ///     println!("moved item to {}", new_position);
+    /// }
+    /// ```
+    pub fn move_item(&mut self, id: Entity, position: usize) -> Option<usize> {
+        let index = self.position(id)? as usize;
+        let parent = self.parent(id);
+
+        let siblings: Vec<Entity> = match parent {
+            Some(parent) => self.children(parent).to_vec(),
+            None => self
+                .order
+                .iter()
+                .copied()
+                .filter(|&entity| self.parent(entity).is_none())
+                .collect(),
+        };
+
+        // Take the place of the sibling displayed at, or last displayed before, the position.
+        let target = siblings
+            .iter()
+            .copied()
+            .take_while(|&sibling| {
+                self.position(sibling)
+                    .is_some_and(|sibling| sibling as usize <= position)
+            })
+            .last()
+            .or_else(|| siblings.first().copied())?;
+
+        if target == id {
+            return Some(index);
+        }
+
+        let end = self.position_after_descendants(id);
+        let moved: Vec<Entity> = self.order.drain(index..end).collect();
+
+        let target_index = self.position(target)? as usize;
+        let position = if target_index >= index {
+            self.position_after_descendants(target)
+        } else {
+            target_index
+        };
+
+        for (offset, entity) in moved.into_iter().enumerate() {
+            self.order.insert(position + offset, entity);
+        }
+
+        // Keep the group's children in the order that they are displayed.
+        if let Some(parent) = parent {
+            let mut children = self.children(parent).to_vec();
+            children.sort_by_key(|&child| self.position(child));
+            self.children.insert(parent, children);
+        }
+
+        Some(position)
+    }
+
     /// Swap the position of two items in the model.
     ///
     /// Returns false if the swap cannot be performed.
@@ -391,6 +738,18 @@ where
     /// longer be usable with the map. Subsequent attempts to get values from the map
     /// with this ID will return `None` and failed to assign values.
     pub fn remove(&mut self, id: Entity) {
+        // Children are removed along with their group.
+        for child in self.children.remove(id).unwrap_or_default() {
+            self.parents.remove(child);
+            self.remove(child);
+        }
+
+        if let Some(parent) = self.parents.remove(id) {
+            if let Some(children) = self.children.get_mut(parent) {
+                children.retain(|&child| child != id);
+            }
+        }
+
         self.items.remove(id);
         self.deactivate(id);
 
@@ -437,4 +796,174 @@ where
     pub fn text_remove(&mut self, id: Entity) -> Option<Cow<'static, str>> {
         self.text.remove(id)
     }
+
+    /// Immutable reference to the tooltip assigned to the item.
+    ///
+    /// ```ignore
+    /// if let Some(tooltip) = model.tooltip(id) {
+    ///     println!("{:?} has tooltip {tooltip}", id);
+    /// }
+    /// ```
+    pub fn tooltip(&self, id: Entity) -> Option<&str> {
+        self.tooltips.get(id).map(Cow::as_ref)
+    }
+
+    /// Sets a tooltip for an item, which is shown while it is hovered.
+    ///
+    /// Useful for describing items which only have an icon.
+    ///
+    /// ```ignore
+    /// model.tooltip_set(id, "Grid view");
+    /// ```
+    pub fn tooltip_set(
+        &mut self,
+        id: Entity,
+        tooltip: impl Into<Cow<'static, str>>,
+    ) -> Option<Cow<str>> {
+        if !self.contains_item(id) {
+            return None;
+        }
+
+        self.tooltips.insert(id, tooltip.into())
+    }
+
+    /// Removes the tooltip from an item.
+    ///
+    /// ```ignore
+    /// model.tooltip_remove(id);
+    /// ```
+    pub fn tooltip_remove(&mut self, id: Entity) -> Option<Cow<'static, str>> {
+        self.tooltips.remove(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entity, Model, SingleSelect};
+
+    fn displayed(model: &Model<SingleSelect>) -> Vec<Entity> {
+        model.iter().collect()
+    }
+
+    /// A collapsed group `a` with children `a1` and `a2`, followed by `b`.
+    fn grouped() -> (Model<SingleSelect>, [Entity; 4]) {
+        let mut model = Model::<SingleSelect>::default();
+        let a = model.insert().id();
+        let a1 = model.insert().parent(a).id();
+        let a2 = model.insert().parent(a).id();
+        let b = model.insert().id();
+        (model, [a, a1, a2, b])
+    }
+
+    #[test]
+    fn expand_shows_children_in_order() {
+        let (mut model, [a, a1, a2, b]) = grouped();
+        assert_eq!(displayed(&model), [a, b]);
+
+        model.expand(a, true);
+        assert_eq!(displayed(&model), [a, a1, a2, b]);
+        assert_eq!(model.indent(a1), Some(1));
+
+        model.expand(a, false);
+        assert_eq!(displayed(&model), [a, b]);
+    }
+
+    #[test]
+    fn nested_groups_keep_their_expansion() {
+        let (mut model, [a, a1, a2, b]) = grouped();
+        let nested = model.insert().parent(a1).id();
+
+        // Expanding a child of a collapsed group shows nothing yet.
+        model.expand(a1, true);
+        assert_eq!(displayed(&model), [a, b]);
+
+        model.expand(a, true);
+        assert_eq!(displayed(&model), [a, a1, nested, a2, b]);
+        assert_eq!(model.indent(nested), Some(2));
+
+        // Collapsing the outer group hides every descendant.
+        model.expand(a, false);
+        assert_eq!(displayed(&model), [a, b]);
+
+        model.expand(a, true);
+        assert_eq!(displayed(&model), [a, a1, nested, a2, b]);
+    }
+
+    #[test]
+    fn removing_group_removes_children() {
+        let (mut model, [a, a1, a2, b]) = grouped();
+        let nested = model.insert().parent(a1).id();
+        model.expand(a, true);
+
+        model.remove(a);
+        assert_eq!(displayed(&model), [b]);
+
+        for id in [a, a1, a2, nested] {
+            assert!(!model.contains_item(id));
+        }
+    }
+
+    #[test]
+    fn groups_are_not_activated() {
+        let (mut model, [a, _, _, b]) = grouped();
+
+        assert!(!model.activate_position(0));
+        assert!(model.activate_position(1));
+        assert!(model.is_active(b));
+        assert!(!model.is_expanded(a));
+    }
+
+    #[test]
+    fn move_item_within_group() {
+        let (mut model, [a, a1, a2, b]) = grouped();
+        let a3 = model.insert().parent(a).id();
+        model.expand(a, true);
+
+        assert_eq!(model.move_item(a3, 1), Some(1));
+        assert_eq!(displayed(&model), [a, a3, a1, a2, b]);
+        assert_eq!(model.children(a), [a3, a1, a2]);
+
+        // The new order is kept when the group is collapsed and expanded again.
+        model.expand(a, false);
+        model.expand(a, true);
+        assert_eq!(displayed(&model), [a, a3, a1, a2, b]);
+    }
+
+    #[test]
+    fn move_item_stays_in_its_group() {
+        let (mut model, [a, a1, a2, b]) = grouped();
+        model.expand(a, true);
+
+        // Moving past the end of the group places the child last in its group.
+        assert_eq!(model.move_item(a1, 3), Some(2));
+        assert_eq!(displayed(&model), [a, a2, a1, b]);
+        assert_eq!(model.parent(a1), Some(a));
+
+        // Moving before the group places the child first in its group.
+        assert_eq!(model.move_item(a1, 0), Some(1));
+        assert_eq!(displayed(&model), [a, a1, a2, b]);
+
+        // Top-level items are not moved into a group.
+        assert_eq!(model.move_item(b, 1), Some(0));
+        assert_eq!(displayed(&model), [b, a, a1, a2]);
+    }
+
+    #[test]
+    fn move_item_moves_group_with_descendants() {
+        let (mut model, [a, a1, a2, b]) = grouped();
+        let c = model.insert().id();
+        let nested = model.insert().parent(a1).id();
+        model.expand(a1, true);
+        model.expand(a, true);
+        assert_eq!(displayed(&model), [a, a1, nested, a2, b, c]);
+
+        assert_eq!(model.move_item(a, 4), Some(1));
+        assert_eq!(displayed(&model), [b, a, a1, nested, a2, c]);
+
+        assert_eq!(model.move_item(a, 5), Some(2));
+        assert_eq!(displayed(&model), [b, c, a, a1, nested, a2]);
+
+        assert_eq!(model.move_item(c, 4), Some(5));
+        assert_eq!(displayed(&model), [b, a, a1, nested, a2, c]);
+    }
 }
@@ -0,0 +1,86 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+use crate::{widget, Element};
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::{overlay, renderer};
+use iced::{mouse, Point, Rectangle, Size};
+use iced_core::widget::Tree;
+
+/// Gap between the tooltip and the item it describes.
+const GAP: f32 = 4.0;
+
+/// Shows the tooltip of a hovered item below it, or above it if there is no space below.
+pub(super) struct Tooltip<'a, Message> {
+    content: Element<'a, Message>,
+    tree: Tree,
+    /// Bounds of the hovered item, in the coordinates of the overlay.
+    target: Rectangle,
+}
+
+impl<'a, Message: 'static> Tooltip<'a, Message> {
+    pub(super) fn new(text: &'a str, target: Rectangle) -> Self {
+        let padding = crate::theme::active().cosmic().space_xxs();
+        let content = Element::from(
+            widget::container(widget::text::body(text))
+                .class(crate::theme::Container::Tooltip)
+                .padding(padding),
+        );
+
+        Self {
+            tree: Tree::new(content.as_widget()),
+            content,
+            target,
+        }
+    }
+}
+
+impl<'a, Message> overlay::Overlay<Message, crate::Theme, crate::Renderer>
+    for Tooltip<'a, Message>
+{
+    fn layout(&mut self, renderer: &crate::Renderer, bounds: Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let node = self
+            .content
+            .as_widget()
+            .layout(&mut self.tree, renderer, &limits);
+        let size = node.size();
+
+        let below = self.target.y + self.target.height + GAP;
+        let y = if below + size.height <= bounds.height {
+            below
+        } else {
+            self.target.y - GAP - size.height
+        };
+
+        let x = (self.target.center_x() - size.width / 2.0)
+            .clamp(0.0, (bounds.width - size.width).max(0.0));
+
+        node.move_to(Point::new(x.round(), y.max(0.0).round()))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut crate::Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        self.content
+            .as_widget()
+            .draw(&self.tree, renderer, theme, style, layout, cursor, &bounds);
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &crate::Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The tooltip never captures the cursor from the item beneath it.
+        false
+    }
+}
@@ -10,6 +10,9 @@ use super::widget::{ItemBounds, LocalState, SegmentedButton, SegmentedVariant};
 use iced::{Length, Rectangle, Size};
 use iced_core::layout;
 
+/// Height of a divider entry in the model.
+const DIVIDER_HEIGHT: f32 = 1.0;
+
 /// A type marker defining the vertical variant of a [`SegmentedButton`].
 pub struct Vertical;
 
@@ -50,6 +53,7 @@ where
         mut bounds: Rectangle,
     ) -> Box<dyn Iterator<Item = ItemBounds> + 'b> {
         let spacing = f32::from(self.spacing);
+        bounds.y -= state.scroll_offset;
 
         Box::new(
             self.model
@@ -104,6 +108,8 @@ where
         }
 
         let spacing = f32::from(self.spacing);
+        let active_position = self.newly_active_position(state);
+        let mut active_span = None;
         let mut height = 0.0;
         for (nth, key) in self.model.order.iter().copied().enumerate() {
             if nth > 0 {
@@ -112,13 +118,42 @@ where
                     height += 1.0 + spacing;
                 }
             }
-            height += item_height;
+
+            let start = height;
+
+            // Divider entries only need room for their line.
+            if self.model.is_divider(key) {
+                state.internal_layout[nth].0.height = DIVIDER_HEIGHT;
+                state.internal_layout[nth].1.height = DIVIDER_HEIGHT;
+                height += DIVIDER_HEIGHT;
+            } else {
+                height += item_height;
+            }
+
+            if active_position == Some(nth) {
+                active_span = Some((start, height));
+            }
         }
 
-        limits.height(Length::Fixed(height)).resolve(
+        let size = limits.height(Length::Fixed(height)).resolve(
             self.width,
             self.height,
             Size::new(width, height),
-        )
+        );
+
+        // Items which do not fit within the widget are scrolled.
+        state.scroll_overflow = (height - size.height).max(0.0);
+        state.scroll_offset = state.scroll_offset.min(state.scroll_overflow);
+
+        // Keep a newly-activated item in view.
+        if let Some((start, end)) = active_span {
+            if start < state.scroll_offset {
+                state.scroll_offset = start;
+            } else if end > state.scroll_offset + size.height {
+                state.scroll_offset = (end - size.height).min(state.scroll_overflow);
+            }
+        }
+
+        size
     }
 }
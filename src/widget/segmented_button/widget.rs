@@ -1,7 +1,8 @@
 // Copyright 2022 System76 <info@system76.com>
 // SPDX-License-Identifier: MPL-2.0
 
-use super::model::{Entity, Model, Selectable};
+use super::model::{Badge, Entity, Model, Selectable};
+use super::tooltip::Tooltip;
 use crate::iced_core::id::Internal;
 use crate::theme::{SegmentedButton as Style, THEME};
 use crate::widget::dnd_destination::DragId;
@@ -19,11 +20,12 @@ use iced::{
     alignment, event, keyboard, mouse, touch, Alignment, Background, Color, Event, Length, Padding,
     Rectangle, Size, Task, Vector,
 };
+use iced_core::gradient::Linear;
 use iced_core::mouse::ScrollDelta;
 use iced_core::text::{LineHeight, Paragraph, Renderer as TextRenderer, Shaping, Wrapping};
 use iced_core::widget::{self, operation, tree};
 use iced_core::{layout, renderer, widget::Tree, Clipboard, Layout, Shell, Widget};
-use iced_core::{Border, Gradient, Point, Renderer as IcedRenderer, Shadow, Text};
+use iced_core::{Border, Gradient, Point, Radians, Renderer as IcedRenderer, Shadow, Text};
 use iced_runtime::{task, Action};
 use slotmap::{Key, SecondaryMap};
 use std::borrow::Cow;
@@ -34,6 +36,21 @@ use std::marker::PhantomData;
 use std::mem;
 use std::time::{Duration, Instant};
 
+/// Size of the fade drawn at edges which have items scrolled out of view.
+const EDGE_FADE: f32 = 16.0;
+
+/// Height of a badge which displays a count.
+const BADGE_HEIGHT: f32 = 16.0;
+
+/// Diameter of a badge which is drawn as a dot.
+const BADGE_DOT: f32 = 8.0;
+
+/// Size of the icon which indicates whether a group is expanded.
+const EXPANDER_SIZE: f32 = 16.0;
+
+/// Distance the cursor must move while pressed before an item is dragged to reorder it.
+const REORDER_THRESHOLD: f32 = 8.0;
+
 /// A command that focuses a segmented item stored in a widget.
 pub fn focus<Message: 'static>(id: Id) -> Task<Message> {
     task::effect(Action::Widget(Box::new(operation::focusable::focus(id.0))))
@@ -85,6 +102,8 @@ where
     pub(super) close_icon: Icon,
     /// Scrolling switches focus between tabs.
     pub(super) scrollable_focus: bool,
+    /// Typing while focused activates the first item whose text starts with the typed prefix.
+    pub(super) type_to_search: bool,
     /// Show the close icon only when item is hovered.
     pub(super) show_close_icon_on_hover: bool,
     /// Padding of the whole widget.
@@ -131,6 +150,9 @@ where
     /// Emits the ID of the item that was activated.
     #[setters(skip)]
     pub(super) on_activate: Option<Box<dyn Fn(Entity) -> Message + 'static>>,
+    /// Emits the ID of the group that was expanded or collapsed, and whether it is expanded.
+    #[setters(skip)]
+    pub(super) on_expand: Option<Box<dyn Fn(Entity, bool) -> Message + 'static>>,
     #[setters(skip)]
     pub(super) on_close: Option<Box<dyn Fn(Entity) -> Message + 'static>>,
     #[setters(skip)]
@@ -138,6 +160,8 @@ where
     #[setters(skip)]
     pub(super) on_middle_press: Option<Box<dyn Fn(Entity) -> Message + 'static>>,
     #[setters(skip)]
+    pub(super) on_reorder: Option<Box<dyn Fn(Entity, usize) -> Message + 'static>>,
+    #[setters(skip)]
     pub(super) on_dnd_drop:
         Option<Box<dyn Fn(Entity, Vec<u8>, String, DndAction) -> Message + 'static>>,
     pub(super) mimes: Vec<String>,
@@ -147,6 +171,9 @@ where
     pub(super) on_dnd_leave: Option<Box<dyn Fn(Entity) -> Message + 'static>>,
     #[setters(strip_option)]
     pub(super) drag_id: Option<DragId>,
+    /// Widgets attached to the trailing edge of items.
+    #[setters(skip)]
+    pub(super) trailing: Vec<(Entity, Element<'a, Message>)>,
     #[setters(skip)]
     /// Defines the implementation of this struct
     variant: PhantomData<Variant>,
@@ -164,6 +191,7 @@ where
             id: Id::unique(),
             close_icon: icon::from_name("window-close-symbolic").size(16).icon(),
             scrollable_focus: false,
+            type_to_search: false,
             show_close_icon_on_hover: false,
             button_alignment: Alignment::Start,
             padding: Padding::from(0.0),
@@ -185,15 +213,18 @@ where
             style: Style::default(),
             context_menu: None,
             on_activate: None,
+            on_expand: None,
             on_close: None,
             on_context: None,
             on_middle_press: None,
+            on_reorder: None,
             on_dnd_drop: None,
             on_dnd_enter: None,
             on_dnd_leave: None,
             mimes: Vec::new(),
             variant: PhantomData,
             drag_id: None,
+            trailing: Vec::new(),
         }
     }
 
@@ -224,7 +255,22 @@ where
         self
     }
 
+    /// Emitted when a group is expanded or collapsed, instead of [`Self::on_activate`].
+    ///
+    /// Apply it to the model with [`Model::expand`].
+    pub fn on_expand<T>(mut self, on_expand: T) -> Self
+    where
+        T: Fn(Entity, bool) -> Message + 'static,
+    {
+        self.on_expand = Some(Box::new(on_expand));
+        self
+    }
+
     /// Emitted when a tab close button is pressed.
+    ///
+    /// Close buttons are shown on items marked as closable, and pressing them will not
+    /// activate the item. Middle-clicking a closable item will also emit this message,
+    /// unless [`Self::on_middle_press`] is set.
     pub fn on_close<T>(mut self, on_close: T) -> Self
     where
         T: Fn(Entity) -> Message + 'static,
@@ -251,9 +297,41 @@ where
         self
     }
 
-    /// Check if an item is enabled.
+    /// Enables reordering items by dragging them with the mouse.
+    ///
+    /// Emitted with the ID of the dragged item, and the position it was dropped at.
+    pub fn on_reorder<T>(mut self, on_reorder: T) -> Self
+    where
+        T: Fn(Entity, usize) -> Message + 'static,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Attaches a small widget, such as a button or progress ring, to the trailing edge of an item.
+    ///
+    /// Events on the widget are handled by it, and will not activate the item.
+    pub fn trailing(mut self, id: Entity, element: impl Into<Element<'a, Message>>) -> Self {
+        self.trailing.push((id, element.into()));
+        self
+    }
+
+    /// Check if an item is enabled, and is not a divider or header.
     fn is_enabled(&self, key: Entity) -> bool {
-        self.model.items.get(key).map_or(false, |item| item.enabled)
+        self.model.is_activatable(key)
+    }
+
+    /// The message to emit when the item is pressed.
+    ///
+    /// Groups are expanded or collapsed, rather than activated.
+    fn press_message(&self, key: Entity) -> Option<Message> {
+        if self.model.is_group(key) {
+            let on_expand = self.on_expand.as_ref()?;
+            Some(on_expand(key, !self.model.is_expanded(key)))
+        } else {
+            let on_activate = self.on_activate.as_ref()?;
+            Some(on_activate(key))
+        }
     }
 
     /// Handle the dnd drop event.
@@ -386,6 +464,49 @@ where
         event::Status::Ignored
     }
 
+    /// Activates the first enabled item whose text starts with the typed prefix.
+    fn type_ahead(
+        &self,
+        state: &mut LocalState,
+        input: &str,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let current = Instant::now();
+
+        // Start a new search after a pause in typing.
+        if state.type_ahead_timestamp.map_or(true, |previous| {
+            current.duration_since(previous) > Duration::from_millis(1000)
+        }) {
+            state.type_ahead.clear();
+        }
+
+        state.type_ahead_timestamp = Some(current);
+        state.type_ahead.push_str(&input.to_lowercase());
+
+        let found = self.model.order.iter().copied().find(|&key| {
+            self.is_enabled(key)
+                && self
+                    .model
+                    .text(key)
+                    .is_some_and(|text| text.to_lowercase().starts_with(&state.type_ahead))
+        });
+
+        let Some(key) = found else {
+            return event::Status::Ignored;
+        };
+
+        state.focused_item = Item::Tab(key);
+
+        // Groups are only focused, so that typing does not expand them.
+        if let Some(on_activate) = self.on_activate.as_ref() {
+            if !self.model.is_group(key) {
+                shell.publish(on_activate(key));
+            }
+        }
+
+        event::Status::Captured
+    }
+
     fn iterate_visible_tabs<'b>(
         &'b self,
         state: &LocalState,
@@ -409,7 +530,61 @@ where
             .copied()
     }
 
-    #[allow(clippy::unused_self)]
+    /// Position of the active item, if it changed since it was last scrolled into view.
+    pub(super) fn newly_active_position(&self, state: &mut LocalState) -> Option<usize> {
+        let position = self
+            .model
+            .order
+            .iter()
+            .position(|&key| self.model.is_active(key));
+
+        let active = position.map(|nth| self.model.order[nth]);
+        if state.last_active == active {
+            return None;
+        }
+
+        state.last_active = active;
+        position
+    }
+
+    /// Scrolls items which overflow the widget, returning whether they were scrolled.
+    fn scroll(&self, state: &mut LocalState, delta: ScrollDelta) -> bool {
+        let step = f32::from(self.button_height);
+        let (ScrollDelta::Lines { x, y } | ScrollDelta::Pixels { x, y }) = delta;
+        let lines = matches!(delta, ScrollDelta::Lines { .. });
+
+        if state.collapsed {
+            let delta = if x.abs() > y.abs() { x } else { y };
+            state.scroll_accumulated += if lines { delta * step } else { delta };
+
+            if state.scroll_accumulated >= step {
+                state.scroll_accumulated = 0.0;
+                if self.prev_tab_sensitive(state) {
+                    state.buttons_offset -= 1;
+                    return true;
+                }
+            } else if state.scroll_accumulated <= -step {
+                state.scroll_accumulated = 0.0;
+                if self.next_tab_sensitive(state) {
+                    state.buttons_offset += 1;
+                    return true;
+                }
+            }
+
+            return false;
+        }
+
+        if state.scroll_overflow > 0.0 {
+            let delta = if lines { y * step } else { y };
+            let offset = (state.scroll_offset - delta).clamp(0.0, state.scroll_overflow);
+            let scrolled = offset != state.scroll_offset;
+            state.scroll_offset = offset;
+            return scrolled;
+        }
+
+        false
+    }
+
     fn prev_tab_sensitive(&self, state: &LocalState) -> bool {
         state.buttons_offset > 0
     }
@@ -475,6 +650,26 @@ where
             width += f32::from(self.close_icon.size) + f32::from(self.button_spacing);
         }
 
+        // Add trailing widget to measurement if attached.
+        if let Some(node) = self
+            .trailing
+            .iter()
+            .position(|(entity, _)| *entity == button)
+            .and_then(|nth| state.trailing_nodes.get(nth))
+        {
+            width += node.size().width + f32::from(self.button_spacing);
+        }
+
+        // Add badge to measurement if visible.
+        if let Some(badge) = self.model.badge(button).filter(|badge| badge.is_visible()) {
+            width += badge_width(badge) + f32::from(self.button_spacing);
+        }
+
+        // Add the expander of a group to measurement.
+        if self.model.is_group(button) {
+            width += EXPANDER_SIZE + f32::from(self.button_spacing);
+        }
+
         // Add button padding to the max size found
         width += f32::from(self.button_padding[0]) + f32::from(self.button_padding[2]);
         width = width.min(f32::from(self.maximum_button_width));
@@ -516,6 +711,31 @@ where
         (width, height)
     }
 
+    /// The index and layout of the trailing widget of an item, which is placed at the end of the
+    /// item, before the space of its close button.
+    fn trailing_layout(
+        &self,
+        state: &LocalState,
+        key: Entity,
+        bounds: Rectangle,
+    ) -> Option<(usize, layout::Node)> {
+        let nth = self
+            .trailing
+            .iter()
+            .position(|(entity, _)| *entity == key)?;
+        let node = state.trailing_nodes.get(nth)?;
+        let size = node.size();
+
+        let end = if self.model.is_closable(key) {
+            close_bounds(bounds, f32::from(self.close_icon.size)).x - f32::from(self.button_spacing)
+        } else {
+            bounds.x + bounds.width - f32::from(self.button_padding[2])
+        };
+
+        let position = Point::new(end - size.width, bounds.center_y() - size.height / 2.0);
+        Some((nth, node.clone().move_to(position)))
+    }
+
     fn button_is_focused(&self, state: &LocalState, key: Entity) -> bool {
         self.on_activate.is_some() && Item::Tab(key) == state.focused_item
     }
@@ -556,17 +776,21 @@ where
     Message: 'static + Clone,
 {
     fn children(&self) -> Vec<Tree> {
-        let mut children = Vec::new();
+        // The context menu is always the first child, followed by the trailing widgets.
+        let mut menu_tree = Tree::empty();
+        menu_tree.state = tree::State::new(MenuBarState::default());
 
-        // Assign the context menu's elements as this widget's children.
         if let Some(ref context_menu) = self.context_menu {
-            let mut tree = Tree::empty();
-            tree.state = tree::State::new(MenuBarState::default());
-            tree.children = menu_roots_children(&context_menu);
-            children.push(tree);
+            menu_tree.children = menu_roots_children(context_menu);
         }
 
-        children
+        std::iter::once(menu_tree)
+            .chain(
+                self.trailing
+                    .iter()
+                    .map(|(_, element)| Tree::new(element.as_widget())),
+            )
+            .collect()
     }
 
     fn tag(&self) -> tree::Tag {
@@ -590,8 +814,17 @@ where
             context_cursor: Point::default(),
             show_context: Default::default(),
             wheel_timestamp: Default::default(),
+            type_ahead: Default::default(),
+            type_ahead_timestamp: Default::default(),
+            reorder_drag: Default::default(),
+            reorder_dragging: false,
             dnd_state: Default::default(),
             fingers_pressed: Default::default(),
+            last_active: Default::default(),
+            scroll_offset: Default::default(),
+            scroll_overflow: Default::default(),
+            scroll_accumulated: Default::default(),
+            trailing_nodes: Vec::new(),
         })
     }
 
@@ -642,18 +875,29 @@ where
             }
         }
 
-        // Diff the context menu
+        // Diff the context menu, which is always the first child.
+        if tree.children.is_empty() {
+            let mut child_tree = Tree::empty();
+            child_tree.state = tree::State::new(MenuBarState::default());
+            tree.children.push(child_tree);
+        }
+
         if let Some(context_menu) = &mut self.context_menu {
-            if tree.children.is_empty() {
-                let mut child_tree = Tree::empty();
-                child_tree.state = tree::State::new(MenuBarState::default());
-                tree.children.push(child_tree);
-            } else {
-                tree.children.truncate(1);
-            }
             menu_roots_diff(context_menu, &mut tree.children[0]);
         } else {
-            tree.children.clear();
+            tree.children[0].state = tree::State::new(MenuBarState::default());
+            tree.children[0].children.clear();
+        }
+
+        // Diff the trailing widgets, which follow the context menu.
+        tree.children.truncate(self.trailing.len() + 1);
+
+        for (nth, (_, element)) in self.trailing.iter_mut().enumerate() {
+            if let Some(child) = tree.children.get_mut(nth + 1) {
+                child.diff(element.as_widget_mut());
+            } else {
+                tree.children.push(Tree::new(element.as_widget()));
+            }
         }
     }
 
@@ -668,6 +912,25 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let state = tree.state.downcast_mut::<LocalState>();
+
+        // Measure the trailing widgets, which are included in the widths of their items.
+        let trailing_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                f32::from(self.maximum_button_width),
+                f32::from(self.button_height),
+            ),
+        );
+
+        state.trailing_nodes = self
+            .trailing
+            .iter()
+            .zip(&mut tree.children[1..])
+            .map(|((_, element), tree)| {
+                element.as_widget().layout(tree, renderer, &trailing_limits)
+            })
+            .collect();
+
         let limits = limits.shrink(self.padding);
         let size = self
             .variant_layout(state, renderer, &limits)
@@ -682,15 +945,53 @@ where
         mut event: Event,
         layout: Layout<'_>,
         cursor_position: mouse::Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &iced::Rectangle,
+        viewport: &iced::Rectangle,
     ) -> event::Status {
         let bounds = layout.bounds();
         let state = tree.state.downcast_mut::<LocalState>();
         state.hovered = Item::None;
 
+        // Trailing widgets handle their own events, and clicks on them do not reach their items.
+        if !self.trailing.is_empty() {
+            let trailing = self
+                .variant_bounds(state, bounds)
+                .filter_map(|item| match item {
+                    ItemBounds::Button(entity, bounds) => {
+                        self.trailing_layout(state, entity, bounds)
+                    }
+                    ItemBounds::Divider(..) => None,
+                })
+                .collect::<Vec<_>>();
+
+            for (nth, node) in trailing {
+                let status = self.trailing[nth].1.as_widget_mut().on_event(
+                    &mut tree.children[nth + 1],
+                    event.clone(),
+                    Layout::new(&node),
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+
+                let is_press = matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed(_) | mouse::Event::ButtonReleased(_))
+                        | Event::Touch(_)
+                );
+
+                if status == event::Status::Captured
+                    || (is_press && cursor_position.is_over(node.bounds()))
+                {
+                    return event::Status::Captured;
+                }
+            }
+        }
+
         let my_id = self.get_drag_id();
 
         if let Event::Dnd(e) = &mut event {
@@ -823,6 +1124,47 @@ where
             }
         }
 
+        if let Some(on_reorder) = self.on_reorder.as_ref() {
+            let hovered_key = self
+                .variant_bounds(state, bounds)
+                .filter_map(|item| match item {
+                    ItemBounds::Button(entity, bounds) => Some((entity, bounds)),
+                    _ => None,
+                })
+                .find(|(key, bounds)| cursor_position.is_over(*bounds) && self.is_enabled(*key))
+                .map(|(key, _)| key);
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                    state.reorder_drag = hovered_key.zip(cursor_position.position());
+                    state.reorder_dragging = false;
+                }
+
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    if let Some((_, origin)) = state.reorder_drag {
+                        if position.distance(origin) > REORDER_THRESHOLD {
+                            state.reorder_dragging = true;
+                        }
+                    }
+                }
+
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    let dragging = mem::take(&mut state.reorder_dragging);
+                    if let Some((dragged, _)) = state.reorder_drag.take().filter(|_| dragging) {
+                        // Dropping an item onto itself is handled as a click.
+                        if let Some(target) = hovered_key.filter(|&target| target != dragged) {
+                            if let Some(position) = self.model.position(target) {
+                                shell.publish(on_reorder(dragged, position as usize));
+                                return event::Status::Captured;
+                            }
+                        }
+                    }
+                }
+
+                _ => (),
+            }
+        }
+
         if cursor_position.is_over(bounds) {
             let fingers_pressed = state.fingers_pressed.len();
 
@@ -876,7 +1218,7 @@ where
                 .collect::<Vec<_>>()
             {
                 if cursor_position.is_over(bounds) {
-                    if self.model.items[key].enabled {
+                    if self.is_enabled(key) {
                         // Record that the mouse is hovering over this button.
                         state.hovered = Item::Tab(key);
 
@@ -917,11 +1259,11 @@ where
                             state.focused_item = Item::None;
                         }
 
-                        if let Some(on_activate) = self.on_activate.as_ref() {
-                            if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                            | Event::Touch(touch::Event::FingerLifted { .. }) = event
-                            {
-                                shell.publish(on_activate(key));
+                        if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                        | Event::Touch(touch::Event::FingerLifted { .. }) = event
+                        {
+                            if let Some(message) = self.press_message(key) {
+                                shell.publish(message);
                                 return event::Status::Captured;
                             }
                         }
@@ -962,6 +1304,15 @@ where
                 }
             }
 
+            // Scroll through items which overflow the widget.
+            if !self.scrollable_focus {
+                if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+                    if self.scroll(state, delta) {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+
             if self.scrollable_focus {
                 if let Some(on_activate) = self.on_activate.as_ref() {
                     if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
@@ -985,7 +1336,7 @@ where
                                                 activate_key = Some(prev_key);
                                             }
 
-                                            if self.model.is_enabled(key) {
+                                            if self.is_enabled(key) && !self.model.is_group(key) {
                                                 prev_key = key;
                                             }
                                         }
@@ -994,7 +1345,9 @@ where
                                         while let Some(key) = buttons.next() {
                                             if self.model.is_active(key) {
                                                 for key in buttons {
-                                                    if self.model.is_enabled(key) {
+                                                    if self.is_enabled(key)
+                                                        && !self.model.is_group(key)
+                                                    {
                                                         activate_key = Some(key);
                                                         break;
                                                     }
@@ -1030,7 +1383,68 @@ where
                 };
             }
 
-            if let Some(on_activate) = self.on_activate.as_ref() {
+            if self.type_to_search {
+                if let Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: keyboard::Key::Character(ref c),
+                    modifiers,
+                    ..
+                }) = event
+                {
+                    if !(modifiers.control() || modifiers.alt() || modifiers.logo()) {
+                        return self.type_ahead(state, c, shell);
+                    }
+                }
+            }
+
+            // Present a context menu beside the focused item with the menu key, or Shift+F10.
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                modifiers,
+                ..
+            }) = event
+            {
+                if named == keyboard::key::Named::ContextMenu
+                    || (named == keyboard::key::Named::F10 && modifiers.shift())
+                {
+                    if let (Some(_), Some(on_context)) =
+                        (self.context_menu.as_ref(), self.on_context.as_ref())
+                    {
+                        let key = match state.focused_item {
+                            Item::Tab(key) => Some(key),
+                            _ => self
+                                .model
+                                .order
+                                .iter()
+                                .copied()
+                                .find(|&key| self.model.is_active(key)),
+                        };
+
+                        let bounds = key.and_then(|key| {
+                            self.variant_bounds(state, layout.bounds()).find_map(
+                                |item| match item {
+                                    ItemBounds::Button(e, bounds) if e == key => Some(bounds),
+                                    _ => None,
+                                },
+                            )
+                        });
+
+                        if let (Some(key), Some(bounds)) = (key, bounds) {
+                            let position = Point::new(bounds.x + bounds.width, bounds.y);
+                            state.show_context = Some(key);
+                            state.context_cursor = position;
+
+                            let menu_state = tree.children[0].state.downcast_mut::<MenuBarState>();
+                            menu_state.open = true;
+                            menu_state.view_cursor = mouse::Cursor::Available(position);
+
+                            shell.publish(on_context(key));
+                            return event::Status::Captured;
+                        }
+                    }
+                }
+            }
+
+            if self.on_activate.is_some() {
                 if let Event::Keyboard(keyboard::Event::KeyReleased {
                     key: keyboard::Key::Named(keyboard::key::Named::Enter),
                     ..
@@ -1038,7 +1452,9 @@ where
                 {
                     match state.focused_item {
                         Item::Tab(entity) => {
-                            shell.publish(on_activate(entity));
+                            if let Some(message) = self.press_message(entity) {
+                                shell.publish(message);
+                            }
                         }
 
                         Item::PrevButton => {
@@ -1072,6 +1488,27 @@ where
 
                     return event::Status::Captured;
                 }
+
+                // Collapse a group with left, and expand it with right.
+                if let Event::Keyboard(keyboard::Event::KeyPressed {
+                    key:
+                        keyboard::Key::Named(
+                            named @ (keyboard::key::Named::ArrowLeft
+                            | keyboard::key::Named::ArrowRight),
+                        ),
+                    ..
+                }) = event
+                {
+                    if let (Item::Tab(entity), Some(on_expand)) =
+                        (state.focused_item, self.on_expand.as_ref())
+                    {
+                        let expand = named == keyboard::key::Named::ArrowRight;
+                        if self.model.is_group(entity) && self.model.is_expanded(entity) != expand {
+                            shell.publish(on_expand(entity, expand));
+                            return event::Status::Captured;
+                        }
+                    }
+                }
             }
         }
 
@@ -1102,8 +1539,8 @@ where
         tree: &Tree,
         layout: Layout<'_>,
         cursor_position: mouse::Cursor,
-        _viewport: &iced::Rectangle,
-        _renderer: &Renderer,
+        viewport: &iced::Rectangle,
+        renderer: &Renderer,
     ) -> iced_core::mouse::Interaction {
         if self.on_activate.is_none() {
             return iced_core::mouse::Interaction::default();
@@ -1111,6 +1548,10 @@ where
         let state = tree.state.downcast_ref::<LocalState>();
         let bounds = layout.bounds();
 
+        if state.reorder_dragging {
+            return iced_core::mouse::Interaction::Grabbing;
+        }
+
         if cursor_position.is_over(bounds) {
             let hovered_button = self
                 .variant_bounds(state, bounds)
@@ -1120,8 +1561,20 @@ where
                 })
                 .find(|(_key, bounds)| cursor_position.is_over(*bounds));
 
-            if let Some((key, _bounds)) = hovered_button {
-                return if self.model.items[key].enabled {
+            if let Some((key, bounds)) = hovered_button {
+                if let Some((nth, node)) = self.trailing_layout(state, key, bounds) {
+                    if cursor_position.is_over(node.bounds()) {
+                        return self.trailing[nth].1.as_widget().mouse_interaction(
+                            &tree.children[nth + 1],
+                            Layout::new(&node),
+                            cursor_position,
+                            viewport,
+                            renderer,
+                        );
+                    }
+                }
+
+                return if self.is_enabled(key) {
                     iced_core::mouse::Interaction::Pointer
                 } else {
                     iced_core::mouse::Interaction::Idle
@@ -1295,238 +1748,443 @@ where
             );
         }
 
-        // Draw each of the items in the widget.
-        let mut nth = 0;
-        self.variant_bounds(state, bounds).for_each(move |item| {
-            let (key, mut bounds) = match item {
-                // Draw a button
-                ItemBounds::Button(entity, bounds) => (entity, bounds),
+        // Clip items which are scrolled out of view.
+        let clip_bounds = if state.scroll_overflow > 0.0 {
+            bounds
+        } else {
+            *viewport
+        };
+
+        renderer.with_layer(clip_bounds, |renderer| {
+            // Draw each of the items in the widget.
+            let mut nth = 0;
+            self.variant_bounds(state, bounds).for_each(move |item| {
+                let (key, mut bounds) = match item {
+                    // Draw a button
+                    ItemBounds::Button(entity, bounds) => (entity, bounds),
+
+                    // Draw a divider between buttons
+                    ItemBounds::Divider(bounds, accented) => {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border: Border::default(),
+                                shadow: Shadow::default(),
+                            },
+                            {
+                                let theme = crate::theme::active();
+                                if accented {
+                                    Background::Color(theme.cosmic().small_widget_divider().into())
+                                } else {
+                                    Background::Color(
+                                        theme.cosmic().primary_container_divider().into(),
+                                    )
+                                }
+                            },
+                        );
+
+                        return;
+                    }
+                };
+
+                // Draw a divider entry as a line through its bounds.
+                if self.model.is_divider(key) {
+                    let line_bounds = if bounds.width >= bounds.height {
+                        Rectangle {
+                            x: bounds.x + f32::from(self.button_padding[0]),
+                            y: bounds.center_y().floor(),
+                            width: bounds.width
+                                - f32::from(self.button_padding[0])
+                                - f32::from(self.button_padding[2]),
+                            height: 1.0,
+                        }
+                    } else {
+                        Rectangle {
+                            x: bounds.center_x().floor(),
+                            width: 1.0,
+                            ..bounds
+                        }
+                    };
 
-                // Draw a divider between buttons
-                ItemBounds::Divider(bounds, accented) => {
                     renderer.fill_quad(
                         renderer::Quad {
-                            bounds,
+                            bounds: line_bounds,
                             border: Border::default(),
                             shadow: Shadow::default(),
                         },
-                        {
-                            let theme = crate::theme::active();
-                            if accented {
-                                Background::Color(theme.cosmic().small_widget_divider().into())
-                            } else {
-                                Background::Color(theme.cosmic().primary_container_divider().into())
-                            }
-                        },
+                        Background::Color(theme.cosmic().primary_container_divider().into()),
                     );
 
+                    nth += 1;
                     return;
                 }
-            };
-
-            let center_y = bounds.center_y();
-
-            let menu_open = !tree.children.is_empty()
-                && tree.children[0].state.downcast_ref::<MenuBarState>().open;
-
-            let key_is_active = self.model.is_active(key);
-            let key_is_hovered = self.button_is_hovered(state, key);
-            let key_has_context_menu_open = menu_open && state.show_context == Some(key);
-            let status_appearance = if self.button_is_focused(state, key) {
-                appearance.focus
-            } else if key_is_active {
-                appearance.active
-            } else if key_is_hovered || key_has_context_menu_open {
-                appearance.hover
-            } else {
-                appearance.inactive
-            };
 
-            let button_appearance = if nth == 0 {
-                status_appearance.first
-            } else if nth + 1 == button_amount {
-                status_appearance.last
-            } else {
-                status_appearance.middle
-            };
+                let is_header = self.model.is_header(key);
 
-            // Render the background of the button.
-            if status_appearance.background.is_some() {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds,
-                        border: Border {
-                            radius: button_appearance.border_radius,
-                            ..Default::default()
+                let center_y = bounds.center_y();
+
+                let menu_open = !tree.children.is_empty()
+                    && tree.children[0].state.downcast_ref::<MenuBarState>().open;
+
+                let key_is_active = self.model.is_active(key);
+                let key_is_hovered = self.button_is_hovered(state, key);
+                let key_has_context_menu_open = menu_open && state.show_context == Some(key);
+                let mut status_appearance = if is_header {
+                    appearance.inactive
+                } else if self.button_is_focused(state, key) {
+                    appearance.focus
+                } else if key_is_active {
+                    appearance.active
+                } else if key_is_hovered || key_has_context_menu_open {
+                    appearance.hover
+                } else {
+                    appearance.inactive
+                };
+
+                // Headers are drawn as dimmed captions without a background.
+                if is_header {
+                    status_appearance.background = None;
+                    status_appearance.text_color.a *= 0.7;
+                }
+
+                let button_appearance = if nth == 0 {
+                    status_appearance.first
+                } else if nth + 1 == button_amount {
+                    status_appearance.last
+                } else {
+                    status_appearance.middle
+                };
+
+                // Render the background of the button.
+                if status_appearance.background.is_some() {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border {
+                                radius: button_appearance.border_radius,
+                                ..Default::default()
+                            },
+                            shadow: Shadow::default(),
                         },
-                        shadow: Shadow::default(),
-                    },
-                    status_appearance
-                        .background
-                        .map_or(Background::Color(Color::TRANSPARENT), bg_with_alpha),
-                );
-            }
+                        status_appearance
+                            .background
+                            .map_or(Background::Color(Color::TRANSPARENT), bg_with_alpha),
+                    );
+                }
 
-            // Draw the bottom border defined for this button.
-            if let Some((width, background)) = button_appearance.border_bottom {
-                let mut bounds = bounds;
-                bounds.y = bounds.y + bounds.height - width;
-                bounds.height = width;
+                // Draw the bottom border defined for this button.
+                if let Some((width, background)) =
+                    button_appearance.border_bottom.filter(|_| !is_header)
+                {
+                    let mut bounds = bounds;
+                    bounds.y = bounds.y + bounds.height - width;
+                    bounds.height = width;
 
-                let rad_0 = THEME.lock().unwrap().cosmic().corner_radii.radius_0;
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds,
-                        border: Border {
-                            radius: rad_0.into(),
-                            ..Default::default()
+                    let rad_0 = THEME.lock().unwrap().cosmic().corner_radii.radius_0;
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: Border {
+                                radius: rad_0.into(),
+                                ..Default::default()
+                            },
+                            shadow: Shadow::default(),
                         },
-                        shadow: Shadow::default(),
-                    },
-                    bg_with_alpha(background.into()),
-                );
-            }
+                        bg_with_alpha(background.into()),
+                    );
+                }
 
-            let original_bounds = bounds;
+                let original_bounds = bounds;
 
-            bounds.x += f32::from(self.button_padding[0]);
-            bounds.width -= f32::from(self.button_padding[0]) - f32::from(self.button_padding[2]);
+                bounds.x += f32::from(self.button_padding[0]);
+                bounds.width -=
+                    f32::from(self.button_padding[0]) - f32::from(self.button_padding[2]);
 
-            // Adjust bounds by indent
-            if let Some(indent) = self.model.indent(key) {
-                let adjustment = f32::from(indent) * f32::from(self.indent_spacing);
-                bounds.x += adjustment;
-                bounds.width -= adjustment;
-            }
+                // Adjust bounds by indent
+                if let Some(indent) = self.model.indent(key) {
+                    let adjustment = f32::from(indent) * f32::from(self.indent_spacing);
+                    bounds.x += adjustment;
+                    bounds.width -= adjustment;
+                }
 
-            // Align contents of the button to the requested `button_alignment`.
-            {
-                let actual_width = state.internal_layout[nth].1.width;
+                // Align contents of the button to the requested `button_alignment`.
+                {
+                    let actual_width = state.internal_layout[nth].1.width;
+
+                    let offset = match self.button_alignment {
+                        Alignment::Start => None,
+                        Alignment::Center => Some((bounds.width - actual_width) / 2.0),
+                        Alignment::End => Some(bounds.width - actual_width),
+                    };
+
+                    if let Some(offset) = offset {
+                        bounds.x += offset - f32::from(self.button_padding[0]);
+                        bounds.width = actual_width;
+                    }
+                }
+
+                // Draw the image beside the text.
+                if let Some(icon) = self.model.icon(key) {
+                    let mut image_bounds = bounds;
+                    let width = f32::from(icon.size);
+                    let offset = width + f32::from(self.button_spacing);
+                    image_bounds.y = center_y - width / 2.0;
+
+                    draw_icon::<Message>(
+                        renderer,
+                        theme,
+                        style,
+                        cursor,
+                        viewport,
+                        apply_alpha(status_appearance.text_color),
+                        Rectangle {
+                            width,
+                            height: width,
+                            ..image_bounds
+                        },
+                        icon.clone(),
+                    );
+
+                    bounds.x += offset;
+                } else {
+                    // Draw the selection indicator if widget is a segmented selection, and the item is selected.
+                    if key_is_active {
+                        if let crate::theme::SegmentedButton::Control = self.style {
+                            let mut image_bounds = bounds;
+                            image_bounds.y = center_y - 16.0 / 2.0;
+
+                            draw_icon::<Message>(
+                                renderer,
+                                theme,
+                                style,
+                                cursor,
+                                viewport,
+                                apply_alpha(status_appearance.text_color),
+                                Rectangle {
+                                    width: 16.0,
+                                    height: 16.0,
+                                    ..image_bounds
+                                },
+                                crate::widget::icon(
+                                    match crate::widget::common::object_select().data() {
+                                        crate::iced_core::svg::Data::Bytes(bytes) => {
+                                            crate::widget::icon::from_svg_bytes(bytes.as_ref())
+                                        }
+                                        crate::iced_core::svg::Data::Path(path) => {
+                                            crate::widget::icon::from_path(path.clone())
+                                        }
+                                    },
+                                ),
+                            );
+
+                            let offset = 16.0 + f32::from(self.button_spacing);
+
+                            bounds.x += offset;
+                        }
+                    }
+                }
+
+                // Whether to show the close button on this tab.
+                let show_close_button =
+                    (key_is_active || !self.show_close_icon_on_hover || key_is_hovered)
+                        && self.model.is_closable(key);
+
+                // Width of the icon used by the close button, which we will subtract from the text bounds.
+                let close_icon_width = if show_close_button {
+                    f32::from(self.close_icon.size)
+                } else {
+                    0.0
+                };
+
+                let badge = self.model.badge(key).filter(|badge| badge.is_visible());
 
-                let offset = match self.button_alignment {
-                    Alignment::Start => None,
-                    Alignment::Center => Some((bounds.width - actual_width) / 2.0),
-                    Alignment::End => Some(bounds.width - actual_width),
+                // Width of the badge and its spacing, which we will also subtract from the text bounds.
+                let badge_space = badge.map_or(0.0, |badge| {
+                    badge_width(badge) + f32::from(self.button_spacing)
+                });
+
+                // Width of the expander of a group and its spacing.
+                let expander_space = if self.model.is_group(key) {
+                    EXPANDER_SIZE + f32::from(self.button_spacing)
+                } else {
+                    0.0
+                };
+
+                bounds.width = original_bounds.width
+                    - (bounds.x - original_bounds.x)
+                    - close_icon_width
+                    - badge_space
+                    - expander_space
+                    - f32::from(self.button_padding[2]);
+
+                let trailing_node = self.trailing_layout(state, key, original_bounds);
+
+                // Trailing edge of the decorations drawn before the trailing widget and close button.
+                let trailing = if let Some((_, node)) = &trailing_node {
+                    node.bounds().x - f32::from(self.button_spacing)
+                } else if show_close_button {
+                    close_bounds(original_bounds, close_icon_width).x
+                        - f32::from(self.button_spacing)
+                } else {
+                    original_bounds.x + original_bounds.width - f32::from(self.button_padding[2])
                 };
 
-                if let Some(offset) = offset {
-                    bounds.x += offset - f32::from(self.button_padding[0]);
-                    bounds.width = actual_width;
+                // Shorten the text to end before the trailing widget.
+                if trailing_node.is_some() {
+                    bounds.width = bounds
+                        .width
+                        .min(trailing - badge_space - expander_space - bounds.x);
                 }
-            }
 
-            // Draw the image beside the text.
-            if let Some(icon) = self.model.icon(key) {
-                let mut image_bounds = bounds;
-                let width = f32::from(icon.size);
-                let offset = width + f32::from(self.button_spacing);
-                image_bounds.y = center_y - width / 2.0;
+                bounds.y = center_y;
+
+                if self.model.text(key).is_some_and(|text| !text.is_empty()) {
+                    // Draw the text for this segmented button or tab.
+                    renderer.fill_paragraph(
+                        state.paragraphs[key].raw(),
+                        bounds.position(),
+                        apply_alpha(status_appearance.text_color),
+                        Rectangle {
+                            x: bounds.x,
+                            width: bounds.width,
+                            ..original_bounds
+                        },
+                    );
+                }
 
-                draw_icon::<Message>(
-                    renderer,
-                    theme,
-                    style,
-                    cursor,
-                    viewport,
-                    apply_alpha(status_appearance.text_color),
-                    Rectangle {
-                        width,
-                        height: width,
-                        ..image_bounds
-                    },
-                    icon.clone(),
-                );
+                // Draw the expander of a group at the trailing edge.
+                if self.model.is_group(key) {
+                    let name = if self.model.is_expanded(key) {
+                        "go-down-symbolic"
+                    } else {
+                        "go-next-symbolic"
+                    };
 
-                bounds.x += offset;
-            } else {
-                // Draw the selection indicator if widget is a segmented selection, and the item is selected.
-                if key_is_active {
-                    if let crate::theme::SegmentedButton::Control = self.style {
-                        let mut image_bounds = bounds;
-                        image_bounds.y = center_y - 16.0 / 2.0;
+                    draw_icon::<Message>(
+                        renderer,
+                        theme,
+                        style,
+                        cursor,
+                        viewport,
+                        apply_alpha(status_appearance.text_color),
+                        Rectangle {
+                            x: trailing - EXPANDER_SIZE,
+                            y: center_y - EXPANDER_SIZE / 2.0,
+                            width: EXPANDER_SIZE,
+                            height: EXPANDER_SIZE,
+                        },
+                        icon::from_name(name).size(16).icon(),
+                    );
+                }
 
-                        draw_icon::<Message>(
-                            renderer,
-                            theme,
-                            style,
-                            cursor,
-                            viewport,
-                            apply_alpha(status_appearance.text_color),
-                            Rectangle {
-                                width: 16.0,
-                                height: 16.0,
-                                ..image_bounds
-                            },
-                            crate::widget::icon(
-                                match crate::widget::common::object_select().data() {
-                                    crate::iced_core::svg::Data::Bytes(bytes) => {
-                                        crate::widget::icon::from_svg_bytes(bytes.as_ref())
-                                    }
-                                    crate::iced_core::svg::Data::Path(path) => {
-                                        crate::widget::icon::from_path(path.clone())
-                                    }
-                                },
-                            ),
-                        );
+                // Draw the badge before the expander and close button.
+                if let Some(badge) = badge {
+                    let right = trailing - expander_space;
+
+                    let accent = &theme.cosmic().accent;
+                    draw_badge(
+                        renderer,
+                        badge,
+                        Point::new(right, center_y),
+                        apply_alpha(accent.base.into()),
+                        apply_alpha(accent.on.into()),
+                        *viewport,
+                    );
+                }
 
-                        let offset = 16.0 + f32::from(self.button_spacing);
+                // Draw the trailing widget of the item.
+                if let Some((nth, node)) = &trailing_node {
+                    self.trailing[*nth].1.as_widget().draw(
+                        &tree.children[nth + 1],
+                        renderer,
+                        theme,
+                        style,
+                        Layout::new(node),
+                        cursor,
+                        viewport,
+                    );
+                }
 
-                        bounds.x += offset;
-                    }
+                // Draw a close button if set.
+                if show_close_button {
+                    let close_button_bounds = close_bounds(original_bounds, close_icon_width);
+
+                    draw_icon::<Message>(
+                        renderer,
+                        theme,
+                        style,
+                        cursor,
+                        viewport,
+                        apply_alpha(status_appearance.text_color),
+                        close_button_bounds,
+                        self.close_icon.clone(),
+                    );
                 }
-            }
 
-            // Whether to show the close button on this tab.
-            let show_close_button =
-                (key_is_active || !self.show_close_icon_on_hover || key_is_hovered)
-                    && self.model.is_closable(key);
+                nth += 1;
+            });
+        });
+
+        // Indicate that items are scrolled out of view with a fade at those edges.
+        let fade_color = match appearance.background {
+            Some(Background::Color(color)) if color.a > 0.0 => color,
+            _ => theme.current_container().base.into(),
+        };
 
-            // Width of the icon used by the close button, which we will subtract from the text bounds.
-            let close_icon_width = if show_close_button {
-                f32::from(self.close_icon.size)
-            } else {
-                0.0
-            };
-
-            bounds.width = original_bounds.width
-                - (bounds.x - original_bounds.x)
-                - close_icon_width
-                - f32::from(self.button_padding[2]);
-
-            bounds.y = center_y;
-
-            if self.model.text(key).is_some_and(|text| !text.is_empty()) {
-                // Draw the text for this segmented button or tab.
-                renderer.fill_paragraph(
-                    state.paragraphs[key].raw(),
-                    bounds.position(),
-                    apply_alpha(status_appearance.text_color),
-                    Rectangle {
-                        x: bounds.x,
-                        width: bounds.width,
-                        ..original_bounds
-                    },
+        if state.collapsed {
+            let button_height = f32::from(self.button_height);
+
+            if self.prev_tab_sensitive(state) {
+                let fade_bounds = Rectangle {
+                    x: bounds.x + button_height,
+                    width: EDGE_FADE,
+                    ..bounds
+                };
+
+                draw_edge_fade(
+                    renderer,
+                    fade_bounds,
+                    std::f32::consts::FRAC_PI_2,
+                    fade_color,
                 );
             }
 
-            // Draw a close button if set.
-            if show_close_button {
-                let close_button_bounds = close_bounds(original_bounds, close_icon_width);
+            if self.next_tab_sensitive(state) {
+                let fade_bounds = Rectangle {
+                    x: bounds.x + bounds.width - button_height - EDGE_FADE,
+                    width: EDGE_FADE,
+                    ..bounds
+                };
 
-                draw_icon::<Message>(
+                draw_edge_fade(
                     renderer,
-                    theme,
-                    style,
-                    cursor,
-                    viewport,
-                    apply_alpha(status_appearance.text_color),
-                    close_button_bounds,
-                    self.close_icon.clone(),
+                    fade_bounds,
+                    3.0 * std::f32::consts::FRAC_PI_2,
+                    fade_color,
                 );
             }
+        }
 
-            nth += 1;
-        });
+        if state.scroll_overflow > 0.0 {
+            if state.scroll_offset > 0.0 {
+                let fade_bounds = Rectangle {
+                    height: EDGE_FADE,
+                    ..bounds
+                };
+
+                draw_edge_fade(renderer, fade_bounds, std::f32::consts::PI, fade_color);
+            }
+
+            if state.scroll_offset < state.scroll_overflow {
+                let fade_bounds = Rectangle {
+                    y: bounds.y + bounds.height - EDGE_FADE,
+                    height: EDGE_FADE,
+                    ..bounds
+                };
+
+                draw_edge_fade(renderer, fade_bounds, 0.0, fade_color);
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -1538,8 +2196,31 @@ where
     ) -> Option<iced_core::overlay::Element<'b, Message, crate::Theme, Renderer>> {
         let state = tree.state.downcast_ref::<LocalState>();
 
-        let Some(entity) = state.show_context else {
-            return None;
+        let menu_open = self.context_menu.is_some()
+            && tree.children[0].state.downcast_ref::<MenuBarState>().open;
+
+        // Describe the hovered item with its tooltip while no context menu is shown.
+        let entity = match state.show_context {
+            Some(entity) if menu_open => entity,
+            _ => {
+                let Item::Tab(entity) = state.hovered else {
+                    return None;
+                };
+
+                let model: &'a Model<SelectionMode> = self.model;
+                let text = model.tooltip(entity)?;
+                let bounds =
+                    self.variant_bounds(state, layout.bounds())
+                        .find_map(|item| match item {
+                            ItemBounds::Button(e, bounds) if e == entity => Some(bounds),
+                            _ => None,
+                        })?;
+
+                return Some(iced_core::overlay::Element::new(Box::new(Tooltip::new(
+                    text,
+                    bounds + translation,
+                ))));
+            }
         };
 
         let bounds = self
@@ -1556,10 +2237,6 @@ where
             return None;
         };
 
-        if !tree.children[0].state.downcast_ref::<MenuBarState>().open {
-            return None;
-        }
-
         bounds.x = state.context_cursor.x;
         bounds.y = state.context_cursor.y;
 
@@ -1662,10 +2339,28 @@ pub struct LocalState {
     show_context: Option<Entity>,
     /// Time since last tab activation from wheel movements.
     wheel_timestamp: Option<Instant>,
+    /// Prefix typed for type-ahead search.
+    type_ahead: String,
+    /// Time of the last type-ahead keypress.
+    type_ahead_timestamp: Option<Instant>,
+    /// The item pressed for reordering, and where the cursor pressed it.
+    reorder_drag: Option<(Entity, Point)>,
+    /// Whether the pressed item has moved past the threshold, and is being dragged.
+    reorder_dragging: bool,
     /// Dnd state
     pub dnd_state: crate::widget::dnd_destination::State<Option<Entity>>,
     /// Tracks multi-touch events
     fingers_pressed: HashSet<Finger>,
+    /// The active item which was last scrolled into view.
+    last_active: Option<Entity>,
+    /// Vertical scroll offset, in pixels.
+    pub(super) scroll_offset: f32,
+    /// Height of the vertical items which exceeds the available height.
+    pub(super) scroll_overflow: f32,
+    /// Wheel movement which has not yet paged collapsed horizontal items.
+    scroll_accumulated: f32,
+    /// Layouts of the trailing widgets, measured before the items which contain them.
+    trailing_nodes: Vec<layout::Node>,
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -1730,6 +2425,95 @@ fn close_bounds(area: Rectangle<f32>, icon_size: f32) -> Rectangle<f32> {
     }
 }
 
+/// The text displayed by a badge, which caps large counts.
+fn badge_text(badge: Badge) -> Option<String> {
+    match badge {
+        Badge::Count(count) if count > 99 => Some(String::from("99+")),
+        Badge::Count(count) => Some(count.to_string()),
+        Badge::Indicator => None,
+    }
+}
+
+/// The width of a badge, which grows with the number of digits in its count.
+fn badge_width(badge: Badge) -> f32 {
+    match badge_text(badge) {
+        Some(text) => (text.len() as f32).mul_add(6.0, 8.0).max(BADGE_HEIGHT),
+        None => BADGE_DOT,
+    }
+}
+
+/// Draws a badge whose trailing edge is vertically centered on `right`.
+fn draw_badge(
+    renderer: &mut Renderer,
+    badge: Badge,
+    right: Point,
+    background: Color,
+    text_color: Color,
+    viewport: Rectangle,
+) {
+    let width = badge_width(badge);
+    let height = if matches!(badge, Badge::Indicator) {
+        BADGE_DOT
+    } else {
+        BADGE_HEIGHT
+    };
+
+    let bounds = Rectangle {
+        x: right.x - width,
+        y: right.y - height / 2.0,
+        width,
+        height,
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                radius: (height / 2.0).into(),
+                ..Default::default()
+            },
+            shadow: Shadow::default(),
+        },
+        Background::Color(background),
+    );
+
+    if let Some(content) = badge_text(badge) {
+        let font = renderer.default_font();
+        renderer.fill_text(
+            Text {
+                content,
+                size: iced::Pixels(10.0),
+                font,
+                bounds: bounds.size(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                line_height: LineHeight::default(),
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            bounds.center(),
+            text_color,
+            viewport,
+        );
+    }
+}
+
+/// Draws a gradient from `color` to transparent in the direction of `angle`.
+fn draw_edge_fade(renderer: &mut Renderer, bounds: Rectangle, angle: f32, color: Color) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border::default(),
+            shadow: Shadow::default(),
+        },
+        Background::Gradient(Gradient::Linear(
+            Linear::new(Radians(angle))
+                .add_stop(0.0, color)
+                .add_stop(1.0, Color { a: 0.0, ..color }),
+        )),
+    );
+}
+
 /// Calculate the bounds of the `next_tab` button.
 fn next_tab_bounds(bounds: &Rectangle, button_height: f32) -> Rectangle {
     Rectangle {
@@ -0,0 +1,243 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! An inline text input which holds its edits until they are submitted.
+
+use crate::widget::text_input::{self, TextInput};
+use crate::{Element, Renderer, Theme};
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, renderer, Clipboard, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+use std::borrow::Cow;
+
+/// Messages emitted by the inner [`TextInput`].
+#[derive(Clone, Debug)]
+enum EntryEvent {
+    Input(String),
+    Submit,
+}
+
+/// Text being edited, which has not yet been submitted.
+#[derive(Default)]
+struct State {
+    editing: Option<String>,
+}
+
+/// Displays the label of a spin button as an editable value.
+///
+/// Edits are kept in the widget state until the input is submitted or loses focus,
+/// at which point `on_submit` is called with the edited text. The label is displayed
+/// again afterwards, which reverts the input if the edited text was rejected.
+pub(super) struct Entry<'a, Message> {
+    label: Cow<'a, str>,
+    input: TextInput<'a, EntryEvent>,
+    on_submit: Box<dyn Fn(&str) -> Option<Message> + 'a>,
+}
+
+impl<'a, Message> Entry<'a, Message> {
+    pub(super) fn new(
+        label: Cow<'a, str>,
+        width: Length,
+        on_submit: impl Fn(&str) -> Option<Message> + 'a,
+    ) -> Self {
+        let input = text_input::inline_input("", label.clone())
+            .width(width)
+            .select_on_focus(true)
+            .on_input(EntryEvent::Input)
+            .on_submit(EntryEvent::Submit);
+
+        Self {
+            label,
+            input,
+            on_submit: Box::new(on_submit),
+        }
+    }
+
+    /// Submits the edited text, and displays the label again.
+    fn submit(&mut self, tree: &mut Tree, shell: &mut Shell<'_, Message>) {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(editing) = state.editing.take() {
+            if let Some(message) = (self.on_submit)(&editing) {
+                shell.publish(message);
+            }
+        }
+
+        self.input.set_value(&self.label);
+        tree.children[0].diff(&mut self.input as &mut dyn Widget<EntryEvent, Theme, Renderer>);
+        shell.invalidate_layout();
+    }
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Entry<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input as &dyn Widget<EntryEvent, Theme, Renderer>,
+        )]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        let state = tree.state.downcast_ref::<State>();
+
+        // Keep displaying the edited text while the view is rebuilt.
+        if let Some(editing) = state.editing.as_deref() {
+            self.input.set_value(editing);
+        }
+
+        tree.children[0].diff(&mut self.input as &mut dyn Widget<EntryEvent, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<EntryEvent, Theme, Renderer>::size(&self.input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::<EntryEvent, Theme, Renderer>::layout(
+            &self.input,
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn iced_core::widget::Operation<()>,
+    ) {
+        Widget::<EntryEvent, Theme, Renderer>::operate(
+            &self.input,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut events = Vec::new();
+        let mut local_shell = Shell::new(&mut events);
+
+        let status = self.input.on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            viewport,
+        );
+
+        if local_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if local_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        for event in events {
+            match event {
+                EntryEvent::Input(text) => {
+                    self.input.set_value(&text);
+                    tree.state.downcast_mut::<State>().editing = Some(text);
+                    tree.children[0]
+                        .diff(&mut self.input as &mut dyn Widget<EntryEvent, Theme, Renderer>);
+                    shell.invalidate_layout();
+                }
+
+                EntryEvent::Submit => self.submit(tree, shell),
+            }
+        }
+
+        // Submit the edited text when the input loses focus.
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        if !is_focused && tree.state.downcast_ref::<State>().editing.is_some() {
+            self.submit(tree, shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<EntryEvent, Theme, Renderer>::mouse_interaction(
+            &self.input,
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<EntryEvent, Theme, Renderer>::draw(
+            &self.input,
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message: 'a> From<Entry<'a, Message>> for Element<'a, Message> {
+    fn from(entry: Entry<'a, Message>) -> Self {
+        Element::new(entry)
+    }
+}
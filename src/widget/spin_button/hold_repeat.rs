@@ -0,0 +1,232 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Repeats a message while its content is held, stepping faster the longer it is held.
+
+use crate::{Element, Renderer, Theme};
+use iced_core::event::{self, Event};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, touch, window, Clipboard, Layout, Length, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+/// How long the content must be held before the message starts repeating.
+const DELAY: Duration = Duration::from_millis(400);
+
+/// The interval between the first repeats, which shrinks as they continue.
+const INITIAL_INTERVAL: Duration = Duration::from_millis(150);
+
+/// The shortest interval between repeats.
+const MIN_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Wraps a button, emitting `on_repeat` repeatedly while it is held.
+pub(super) struct HoldRepeat<'a, Message> {
+    content: Element<'a, Message>,
+    on_repeat: Message,
+}
+
+impl<'a, Message> HoldRepeat<'a, Message> {
+    pub(super) fn new(content: impl Into<Element<'a, Message>>, on_repeat: Message) -> Self {
+        Self {
+            content: content.into(),
+            on_repeat,
+        }
+    }
+}
+
+/// When the next repeat is due, and how many have been emitted, while held.
+#[derive(Default)]
+struct State {
+    held: Option<(Instant, u32)>,
+}
+
+/// The interval after the given number of repeats, shrinking by 15% with each one.
+fn interval(repeats: u32) -> Duration {
+    INITIAL_INTERVAL
+        .mul_f32(0.85_f32.powi(repeats.min(64) as i32))
+        .max(MIN_INTERVAL)
+}
+
+impl<'a, Message: Clone> Widget<Message, Theme, Renderer> for HoldRepeat<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn iced_core::widget::Operation<()>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let mut cursor = cursor_position;
+
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor_position.is_over(layout.bounds()) =>
+            {
+                let next = Instant::now() + DELAY;
+                state.held = Some((next, 0));
+                shell.request_redraw(window::RedrawRequest::At(next));
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if !cursor_position.is_over(layout.bounds()) =>
+            {
+                state.held = None;
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                // Release the button without pressing it, if the message has already repeated.
+                if let Some((_, repeats)) = state.held.take() {
+                    if repeats > 0 {
+                        cursor = mouse::Cursor::Unavailable;
+                    }
+                }
+            }
+
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some((next, repeats)) = state.held.as_mut() {
+                    if *now >= *next {
+                        shell.publish(self.on_repeat.clone());
+                        *repeats += 1;
+                        *next = *now + interval(*repeats);
+                    }
+
+                    shell.request_redraw(window::RedrawRequest::At(*next));
+                }
+            }
+
+            _ => (),
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content
+            .as_widget()
+            .a11y_nodes(layout, &state.children[0], p)
+    }
+}
+
+impl<'a, Message: Clone + 'static> From<HoldRepeat<'a, Message>> for Element<'a, Message> {
+    fn from(hold_repeat: HoldRepeat<'a, Message>) -> Self {
+        Element::new(hold_repeat)
+    }
+}
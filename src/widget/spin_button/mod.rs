@@ -0,0 +1,392 @@
+// Copyright 2022 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A control for incremental adjustments of a value.
+
+mod entry;
+use entry::Entry;
+
+mod hold_repeat;
+use hold_repeat::HoldRepeat;
+
+mod page_keys;
+use page_keys::PageKeys;
+
+use crate::{
+    theme,
+    widget::{button, column, container, icon, row, text},
+    Element,
+};
+use apply::Apply;
+use derive_setters::Setters;
+use iced::{alignment::Horizontal, Border, Shadow};
+use iced::{Alignment, Length};
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use std::{borrow::Cow, fmt::Display};
+
+/// Horizontal spin button widget.
+pub fn spin_button<'a, T, M>(
+    label: impl Into<Cow<'a, str>>,
+    value: T,
+    step: T,
+    min: T,
+    max: T,
+    on_press: impl Fn(T) -> M + 'static,
+) -> SpinButton<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    SpinButton::new(
+        label,
+        value,
+        step,
+        min,
+        max,
+        Orientation::Horizontal,
+        on_press,
+    )
+}
+
+/// Vertical spin button widget.
+pub fn vertical<'a, T, M>(
+    label: impl Into<Cow<'a, str>>,
+    value: T,
+    step: T,
+    min: T,
+    max: T,
+    on_press: impl Fn(T) -> M + 'static,
+) -> SpinButton<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    SpinButton::new(
+        label,
+        value,
+        step,
+        min,
+        max,
+        Orientation::Vertical,
+        on_press,
+    )
+}
+
+#[derive(Clone, Copy)]
+enum Orientation {
+    Horizontal,
+    Vertical,
+}
+
+pub struct SpinButton<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    /// The formatted value of the spin button.
+    label: Cow<'a, str>,
+    /// The current value of the spin button.
+    value: T,
+    /// The amount to increment or decrement the value.
+    step: T,
+    /// The minimum value permitted.
+    min: T,
+    /// The maximum value permitted.
+    max: T,
+    /// Whether the value wraps around when stepping past `min` or `max`.
+    wrap: bool,
+    /// The amount to increment or decrement the value on page up and page down.
+    page_step: Option<T>,
+    /// Parses a value typed into the label, when it is editable.
+    parse: Option<Box<dyn Fn(&str) -> Option<T>>>,
+    /// The width of the label between the buttons.
+    label_width: Length,
+    orientation: Orientation,
+    on_press: Box<dyn Fn(T) -> M>,
+}
+
+impl<'a, T, M> SpinButton<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    /// Create a new new button
+    fn new(
+        label: impl Into<Cow<'a, str>>,
+        value: T,
+        step: T,
+        min: T,
+        max: T,
+        orientation: Orientation,
+        on_press: impl Fn(T) -> M + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            step,
+            value: if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            },
+            min,
+            max,
+            wrap: false,
+            page_step: None,
+            parse: None,
+            label_width: Length::Fixed(48.0),
+            orientation,
+            on_press: Box::from(on_press),
+        }
+    }
+
+    /// Wrap around to `min` when incrementing past `max`, and to `max` when
+    /// decrementing past `min`.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// The amount to increment or decrement by when page up or page down is pressed.
+    pub fn page_step(mut self, page_step: T) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    /// Sets the width of the label between the buttons.
+    pub fn label_width(mut self, width: impl Into<Length>) -> Self {
+        self.label_width = width.into();
+        self
+    }
+
+    /// The value after incrementing by `step`.
+    fn incremented(&self, step: T) -> T {
+        if self.wrap && self.value > self.max - step {
+            self.min
+        } else {
+            increment(self.value, step, self.min, self.max)
+        }
+    }
+
+    /// The value after decrementing by `step`.
+    fn decremented(&self, step: T) -> T {
+        if self.wrap && self.value < self.min + step {
+            self.max
+        } else {
+            decrement(self.value, step, self.min, self.max)
+        }
+    }
+}
+
+impl<'a, T, M> SpinButton<'a, T, M>
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd + FromStr + 'static,
+{
+    /// Allow the value to be typed into the label.
+    ///
+    /// The text is parsed when submitted or when the input loses focus. Values outside of
+    /// `min` and `max` are clamped, and text which fails to parse is reverted.
+    pub fn editable(self) -> Self {
+        self.editable_with(|text| text.trim().parse::<T>().ok())
+    }
+
+    /// Allow the value to be typed into the label, which is parsed by `parse`.
+    ///
+    /// Like [`SpinButton::editable`], but for labels which are formatted, such as with units.
+    pub fn editable_with(mut self, parse: impl Fn(&str) -> Option<T> + 'static) -> Self {
+        let (min, max) = (self.min, self.max);
+        self.parse = Some(Box::new(move |text| {
+            let value = parse(text)?;
+            Some(if value < min {
+                min
+            } else if value > max {
+                max
+            } else {
+                value
+            })
+        }));
+        self
+    }
+}
+
+fn increment<T>(value: T, step: T, min: T, max: T) -> T
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    if value > max - step {
+        max
+    } else {
+        value + step
+    }
+}
+
+fn decrement<T>(value: T, step: T, min: T, max: T) -> T
+where
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    if value < min + step {
+        min
+    } else {
+        value - step
+    }
+}
+
+impl<'a, T, Message> From<SpinButton<'a, T, Message>> for Element<'a, Message>
+where
+    Message: Clone + 'static,
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    fn from(this: SpinButton<'a, T, Message>) -> Self {
+        let page_messages = this.page_step.map(|page_step| {
+            (
+                (this.on_press)(this.incremented(page_step)),
+                (this.on_press)(this.decremented(page_step)),
+            )
+        });
+
+        let content = match this.orientation {
+            Orientation::Horizontal => horizontal_variant(this),
+            Orientation::Vertical => vertical_variant(this),
+        };
+
+        match page_messages {
+            Some((page_up, page_down)) => {
+                PageKeys::new(content, Some(page_up), Some(page_down)).into()
+            }
+            None => content,
+        }
+    }
+}
+
+fn horizontal_variant<'a, T, Message>(
+    spin_button: SpinButton<'a, T, Message>,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'static,
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    let (decrement_button, increment_button) = step_buttons(&spin_button);
+
+    let label = label(spin_button);
+
+    row::with_capacity(3)
+        .push(decrement_button)
+        .push(label)
+        .push(increment_button)
+        .align_y(Alignment::Center)
+        .apply(container)
+        .class(theme::Container::custom(container_style))
+        .into()
+}
+
+fn vertical_variant<'a, T, Message>(spin_button: SpinButton<'a, T, Message>) -> Element<'a, Message>
+where
+    Message: Clone + 'static,
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    let (decrement_button, increment_button) = step_buttons(&spin_button);
+
+    let label = label(spin_button);
+
+    column::with_capacity(3)
+        .push(increment_button)
+        .push(label)
+        .push(decrement_button)
+        .align_x(Alignment::Center)
+        .apply(container)
+        .class(theme::Container::custom(container_style))
+        .into()
+}
+
+/// Buttons which decrement and increment the value, repeating while they are held.
+fn step_buttons<'a, T, Message>(
+    spin_button: &SpinButton<'a, T, Message>,
+) -> (HoldRepeat<'a, Message>, HoldRepeat<'a, Message>)
+where
+    Message: Clone + 'static,
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    let decrement = (spin_button.on_press)(spin_button.decremented(spin_button.step));
+    let increment = (spin_button.on_press)(spin_button.incremented(spin_button.step));
+
+    let decrement_button = icon::from_name("list-remove-symbolic")
+        .apply(button::icon)
+        .on_press(decrement.clone());
+
+    let increment_button = icon::from_name("list-add-symbolic")
+        .apply(button::icon)
+        .on_press(increment.clone());
+
+    (
+        HoldRepeat::new(decrement_button, decrement),
+        HoldRepeat::new(increment_button, increment),
+    )
+}
+
+fn label<'a, T, Message>(spin_button: SpinButton<'a, T, Message>) -> Element<'a, Message>
+where
+    Message: Clone + 'static,
+    T: Copy + Sub<Output = T> + Add<Output = T> + PartialOrd,
+{
+    let Some(parse) = spin_button.parse else {
+        return text::title4(spin_button.label)
+            .apply(container)
+            .center_x(spin_button.label_width)
+            .align_y(Alignment::Center)
+            .into();
+    };
+
+    let on_press = spin_button.on_press;
+    Entry::new(spin_button.label, spin_button.label_width, move |text| {
+        parse(text).map(&on_press)
+    })
+    .apply(container)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn container_style(theme: &crate::Theme) -> iced_widget::container::Style {
+    let cosmic_theme = &theme.cosmic();
+    let mut neutral_10 = cosmic_theme.palette.neutral_10;
+    neutral_10.alpha = 0.1;
+    let accent = &cosmic_theme.accent;
+    let corners = &cosmic_theme.corner_radii;
+    iced_widget::container::Style {
+        icon_color: Some(accent.base.into()),
+        text_color: Some(cosmic_theme.palette.neutral_10.into()),
+        background: None,
+        border: Border {
+            radius: corners.radius_s.into(),
+            width: 0.0,
+            color: accent.base.into(),
+        },
+        shadow: Shadow::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn decrement() {
+        assert_eq!(super::decrement(0i32, 10, 15, 35), 15);
+    }
+
+    #[test]
+    fn wrap() {
+        let spin_button = |value| super::spin_button("", value, 1u8, 0, 59, |v| v).wrap(true);
+        assert_eq!(spin_button(59).incremented(1), 0);
+        assert_eq!(spin_button(0).decremented(1), 59);
+        assert_eq!(spin_button(30).incremented(10), 40);
+        assert_eq!(spin_button(55).incremented(10), 0);
+    }
+
+    #[test]
+    fn editable() {
+        let spin_button = super::spin_button("", 5i32, 1, 0, 10, |v| v).editable();
+        let parse = spin_button.parse.as_ref().unwrap();
+        assert_eq!(parse(" 7 "), Some(7));
+        assert_eq!(parse("42"), Some(10));
+        assert_eq!(parse("-3"), Some(0));
+        assert_eq!(parse("seven"), None);
+    }
+}
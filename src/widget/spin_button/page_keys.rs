@@ -0,0 +1,187 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Emits messages for page up and page down key presses over its content.
+
+use crate::{Element, Renderer, Theme};
+use iced_core::event::{self, Event};
+use iced_core::widget::Tree;
+use iced_core::{
+    keyboard, layout, mouse, overlay, renderer, Clipboard, Layout, Length, Rectangle, Shell, Size,
+    Vector, Widget,
+};
+
+/// Wraps content, emitting messages when page up or page down is pressed while hovered.
+pub(super) struct PageKeys<'a, Message> {
+    content: Element<'a, Message>,
+    on_page_up: Option<Message>,
+    on_page_down: Option<Message>,
+}
+
+impl<'a, Message> PageKeys<'a, Message> {
+    pub(super) fn new(
+        content: impl Into<Element<'a, Message>>,
+        on_page_up: Option<Message>,
+        on_page_down: Option<Message>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_page_up,
+            on_page_down,
+        }
+    }
+}
+
+impl<'a, Message: Clone> Widget<Message, Theme, Renderer> for PageKeys<'a, Message> {
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_mut(&mut self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn iced_core::widget::Operation<()>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let event::Status::Captured = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        ) {
+            return event::Status::Captured;
+        }
+
+        if !cursor_position.is_over(layout.bounds()) {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(named),
+            ..
+        }) = event
+        {
+            let message = match named {
+                keyboard::key::Named::PageUp => self.on_page_up.clone(),
+                keyboard::key::Named::PageDown => self.on_page_down.clone(),
+                _ => None,
+            };
+
+            if let Some(message) = message {
+                shell.publish(message);
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        self.content
+            .as_widget()
+            .a11y_nodes(layout, &state.children[0], p)
+    }
+}
+
+impl<'a, Message: Clone + 'static> From<PageKeys<'a, Message>> for Element<'a, Message> {
+    fn from(page_keys: PageKeys<'a, Message>) -> Self {
+        Element::new(page_keys)
+    }
+}
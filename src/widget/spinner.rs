@@ -0,0 +1,186 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! A spinning icon which shows that a task is in progress.
+//!
+//! ```no_run
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! let loading: cosmic::Element<Message> = cosmic::widget::spinner().size(24).into();
+//! ```
+
+use std::f32::consts::TAU;
+use std::time::{Duration, Instant};
+
+use crate::{Element, Renderer, Theme};
+
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, renderer, svg, window, Clipboard, Layout, Length, Radians, Rectangle, Shell,
+    Size, Widget,
+};
+
+/// How long the spinner takes to complete a turn.
+const PERIOD: Duration = Duration::from_millis(1000);
+
+/// Creates a spinner of the default size.
+pub fn spinner() -> Spinner {
+    Spinner::new()
+}
+
+/// A spinning icon which shows that a task is in progress.
+#[must_use]
+pub struct Spinner {
+    handle: svg::Handle,
+    size: u16,
+}
+
+impl Spinner {
+    /// Creates a spinner of the default size.
+    pub fn new() -> Self {
+        Self {
+            handle: handle(),
+            size: 16,
+        }
+    }
+
+    /// Sets the width and height of the spinner.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The icon which is rotated by a spinner.
+pub(crate) fn handle() -> svg::Handle {
+    crate::widget::icon::from_name("process-working-symbolic")
+        .size(16)
+        .icon()
+        .into_svg_handle()
+        .unwrap_or_else(|| {
+            let bytes: &'static [u8] = &[];
+            svg::Handle::from_memory(bytes)
+        })
+}
+
+/// The rotation of a spinner, which advances on every frame while it is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Animation {
+    started: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl Animation {
+    /// Advances the rotation on redraws, and requests the next frame.
+    pub(crate) fn on_event<Message>(&mut self, event: &Event, shell: &mut Shell<'_, Message>) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.started.get_or_insert(*now);
+            self.now = Some(*now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    /// Stops the animation, so that it restarts from the beginning when next shown.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// The current rotation of the spinner.
+    pub(crate) fn rotation(&self) -> Radians {
+        match (self.started, self.now) {
+            (Some(started), Some(now)) => {
+                let turn = (now - started).as_secs_f32() % PERIOD.as_secs_f32();
+                Radians(turn / PERIOD.as_secs_f32() * TAU)
+            }
+            _ => Radians(0.0),
+        }
+    }
+
+    /// Draws the spinner's icon within `bounds`, rotated to the current frame.
+    pub(crate) fn draw(
+        &self,
+        renderer: &mut Renderer,
+        handle: &svg::Handle,
+        color: iced_core::Color,
+        bounds: Rectangle,
+    ) {
+        let svg = svg::Svg::new(handle.clone())
+            .color(color)
+            .rotation(self.rotation());
+        svg::Renderer::draw_svg(renderer, svg, bounds);
+    }
+}
+
+impl<Message> Widget<Message, Theme, Renderer> for Spinner {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<Animation>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(Animation::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        let size = Length::Fixed(f32::from(self.size));
+        Size::new(size, size)
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = f32::from(self.size);
+        layout::Node::new(limits.resolve(size, size, Size::new(size, size)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        tree.state
+            .downcast_mut::<Animation>()
+            .on_event(&event, shell);
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        tree.state.downcast_ref::<Animation>().draw(
+            renderer,
+            &self.handle,
+            style.text_color,
+            layout.bounds(),
+        );
+    }
+}
+
+impl<'a, Message: 'a> From<Spinner> for Element<'a, Message> {
+    fn from(spinner: Spinner) -> Self {
+        Self::new(spinner)
+    }
+}
@@ -0,0 +1,497 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Two panes separated by a divider which may be dragged to resize them.
+//!
+//! The ratio is owned by the application, so it may be stored with `cosmic-config` and
+//! restored on launch. Store it on [`Split::on_release`] to write it once per drag.
+//!
+//! ```no_run
+//! # #[derive(Clone)]
+//! # enum Message { Resize(f32), SaveRatio }
+//! # let ratio = 0.5;
+//! use cosmic::widget::{split, text};
+//!
+//! let panes = split(text("Editor"), text("Preview"), split::Orientation::Horizontal, ratio)
+//!     .min_sizes(200.0, 120.0)
+//!     .on_resize(Message::Resize)
+//!     .on_release(Message::SaveRatio);
+//! ```
+
+use crate::{Element, Renderer, Theme};
+use derive_setters::Setters;
+use iced_core::event::{self, Event};
+use iced_core::widget::{tree, Operation, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, touch, Border, Clipboard, Layout, Length, Point, Rectangle,
+    Shadow, Shell, Size, Vector, Widget,
+};
+
+/// Width of the area between the panes which may be dragged to resize them.
+const HANDLE: f32 = 8.0;
+
+/// Places `first` before `second`, giving `first` the `ratio` of the space between them.
+pub fn split<'a, Message>(
+    first: impl Into<Element<'a, Message>>,
+    second: impl Into<Element<'a, Message>>,
+    orientation: Orientation,
+    ratio: f32,
+) -> Split<'a, Message> {
+    Split::new(first, second, orientation, ratio)
+}
+
+/// How the panes of a [`Split`] are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The panes are side by side, with the first on the left.
+    Horizontal,
+    /// The panes are stacked, with the first on top.
+    Vertical,
+}
+
+impl Orientation {
+    /// The length of `size` along the orientation.
+    fn main(self, size: Size) -> f32 {
+        match self {
+            Self::Horizontal => size.width,
+            Self::Vertical => size.height,
+        }
+    }
+
+    /// The position of `point` along the orientation.
+    fn position(self, point: Point) -> f32 {
+        match self {
+            Self::Horizontal => point.x,
+            Self::Vertical => point.y,
+        }
+    }
+}
+
+/// Two panes separated by a divider which may be dragged to resize them.
+#[must_use]
+#[derive(Setters)]
+pub struct Split<'a, Message> {
+    #[setters(skip)]
+    children: Vec<Element<'a, Message>>,
+    #[setters(skip)]
+    orientation: Orientation,
+    #[setters(skip)]
+    ratio: f32,
+    #[setters(skip)]
+    min_sizes: (f32, f32),
+    #[setters(skip)]
+    on_resize: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    /// Emitted when the divider is released after being dragged.
+    #[setters(strip_option)]
+    on_release: Option<Message>,
+    /// Sets the width of the widget.
+    #[setters(into)]
+    width: Length,
+    /// Sets the height of the widget.
+    #[setters(into)]
+    height: Length,
+}
+
+impl<'a, Message> Split<'a, Message> {
+    /// Places `first` before `second`, giving `first` the `ratio` of the space between them.
+    pub fn new(
+        first: impl Into<Element<'a, Message>>,
+        second: impl Into<Element<'a, Message>>,
+        orientation: Orientation,
+        ratio: f32,
+    ) -> Self {
+        Self {
+            children: vec![first.into(), second.into()],
+            orientation,
+            ratio,
+            min_sizes: (0.0, 0.0),
+            on_resize: None,
+            on_release: None,
+            width: Length::Fill,
+            height: Length::Fill,
+        }
+    }
+
+    /// The smallest sizes of the first and second panes, which the divider cannot be dragged past.
+    pub fn min_sizes(mut self, first: f32, second: f32) -> Self {
+        self.min_sizes = (first.max(0.0), second.max(0.0));
+        self
+    }
+
+    /// Emits the new ratio of the first pane while the divider is dragged.
+    pub fn on_resize(mut self, on_resize: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// The size of the first pane, out of the `available` space, honoring the minimum sizes.
+    fn first_size(&self, ratio: f32, available: f32) -> f32 {
+        let (min_first, min_second) = self.min_sizes;
+
+        (ratio.clamp(0.0, 1.0) * available)
+            .min(available - min_second)
+            .max(min_first)
+            .min(available)
+            .max(0.0)
+    }
+
+    /// The bounds of the handle between the panes, within the widget's `bounds`.
+    ///
+    /// The handle follows the space allocated to the first pane, rather than its laid out
+    /// size, which is smaller when the pane shrinks to fit its content.
+    fn handle_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let available = (self.orientation.main(bounds.size()) - HANDLE).max(0.0);
+        let first = self.first_size(self.ratio, available);
+
+        match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + first,
+                width: HANDLE,
+                ..bounds
+            },
+            Orientation::Vertical => Rectangle {
+                y: bounds.y + first,
+                height: HANDLE,
+                ..bounds
+            },
+        }
+    }
+}
+
+/// Whether the divider is being dragged.
+#[derive(Default)]
+struct State {
+    dragging: bool,
+}
+
+impl<'a, Message: Clone> Widget<Message, Theme, Renderer> for Split<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.diff_children(self.children.as_mut_slice());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.width(self.width).height(self.height).resolve(
+            self.width,
+            self.height,
+            Size::ZERO,
+        );
+
+        let available = (self.orientation.main(size) - HANDLE).max(0.0);
+        let first = self.first_size(self.ratio, available);
+        let second = available - first;
+
+        let (first_size, second_size, second_position) = match self.orientation {
+            Orientation::Horizontal => (
+                Size::new(first, size.height),
+                Size::new(second, size.height),
+                Point::new(first + HANDLE, 0.0),
+            ),
+            Orientation::Vertical => (
+                Size::new(size.width, first),
+                Size::new(size.width, second),
+                Point::new(0.0, first + HANDLE),
+            ),
+        };
+
+        let first = self.children[0].as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, first_size),
+        );
+
+        let second = self.children[1]
+            .as_widget()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, second_size),
+            )
+            .move_to(second_position);
+
+        layout::Node::with_children(size, vec![first, second])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if self.on_resize.is_some() {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+                    if cursor.is_over(self.handle_bounds(layout.bounds())) =>
+                {
+                    state.dragging = true;
+                    return event::Status::Captured;
+                }
+
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. })
+                    if state.dragging =>
+                {
+                    let bounds = layout.bounds();
+                    let available = (self.orientation.main(bounds.size()) - HANDLE).max(0.0);
+
+                    if available > 0.0 {
+                        // Center the handle on the cursor.
+                        let offset = self.orientation.position(position)
+                            - self.orientation.position(bounds.position())
+                            - HANDLE / 2.0;
+                        let first = self.first_size(offset / available, available);
+
+                        if let Some(on_resize) = self.on_resize.as_ref() {
+                            shell.publish(on_resize(first / available));
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(
+                    touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. },
+                ) if state.dragging => {
+                    state.dragging = false;
+
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                _ => (),
+            }
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.on_resize.is_some()
+            && (state.dragging || cursor.is_over(self.handle_bounds(layout.bounds())))
+        {
+            return match self.orientation {
+                Orientation::Horizontal => mouse::Interaction::ResizingHorizontally,
+                Orientation::Vertical => mouse::Interaction::ResizingVertically,
+            };
+        }
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+
+        // Draw a divider line through the center of the handle.
+        let handle = self.handle_bounds(layout.bounds());
+        let divider = match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: handle.center_x() - 0.5,
+                width: 1.0,
+                ..handle
+            },
+            Orientation::Vertical => Rectangle {
+                y: handle.center_y() - 0.5,
+                height: 1.0,
+                ..handle
+            },
+        };
+
+        iced_core::Renderer::fill_quad(
+            renderer,
+            renderer::Quad {
+                bounds: divider,
+                border: Border::default(),
+                shadow: Shadow::default(),
+            },
+            iced_core::Color::from(theme.current_container().divider),
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        use iced_accessibility::A11yTree;
+        A11yTree::join(
+            self.children
+                .iter()
+                .zip(layout.children())
+                .zip(state.children.iter())
+                .map(|((c, c_layout), state)| c.as_widget().a11y_nodes(c_layout, state, p)),
+        )
+    }
+
+    fn drag_destinations(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        dnd_rectangles: &mut iced_core::clipboard::DndDestinationRectangles,
+    ) {
+        for ((e, layout), state) in self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(state.children.iter())
+        {
+            e.as_widget()
+                .drag_destinations(state, layout, renderer, dnd_rectangles);
+        }
+    }
+}
+
+impl<'a, Message: Clone + 'a> From<Split<'a, Message>> for Element<'a, Message> {
+    fn from(split: Split<'a, Message>) -> Self {
+        Self::new(split)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Orientation, Split, HANDLE};
+    use crate::widget::Space;
+    use iced_core::{Length, Rectangle};
+
+    fn shrink() -> Space {
+        Space::new(Length::Shrink, Length::Shrink)
+    }
+
+    #[test]
+    fn handle_follows_allocated_size_of_shrink_pane() {
+        let split = Split::<()>::new(shrink(), shrink(), Orientation::Horizontal, 0.25);
+        let bounds = Rectangle::new([10.0, 20.0].into(), [408.0, 100.0].into());
+
+        let handle = split.handle_bounds(bounds);
+        assert_eq!(handle.x, 10.0 + 100.0);
+        assert_eq!(handle.width, HANDLE);
+        assert_eq!(handle.height, 100.0);
+    }
+
+    #[test]
+    fn handle_honors_minimum_sizes() {
+        let split =
+            Split::<()>::new(shrink(), shrink(), Orientation::Vertical, 0.0).min_sizes(50.0, 0.0);
+        let bounds = Rectangle::new([0.0, 0.0].into(), [100.0, 208.0].into());
+
+        assert_eq!(split.handle_bounds(bounds).y, 50.0);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::Renderer;
+pub use iced::widget::Text;
+use std::borrow::Cow;
+
+mod preset;
+pub use preset::Preset;
+
+mod selectable;
+pub use selectable::{selectable, Selectable};
+
+/// Creates a new [`Text`] widget with the provided content.
+///
+/// [`Text`]: widget::Text
+pub fn text<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Text<'a, crate::Theme, Renderer> {
+    Text::new(text.into()).font(crate::font::default())
+}
+
+/// Available presets for text typography
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Typography {
+    Body,
+    Caption,
+    CaptionHeading,
+    Heading,
+    Monotext,
+    Title1,
+    Title2,
+    Title3,
+    Title4,
+}
+
+impl Typography {
+    /// The size, line height, and font of the preset.
+    fn preset(self) -> (f32, f32, crate::font::Font) {
+        match self {
+            Self::Title1 => (32.0, 44.0, crate::font::light()),
+            Self::Title2 => (28.0, 36.0, crate::font::default()),
+            Self::Title3 => (24.0, 32.0, crate::font::default()),
+            Self::Title4 => (20.0, 28.0, crate::font::default()),
+            Self::Heading => (14.0, 20.0, crate::font::semibold()),
+            Self::CaptionHeading => (10.0, 14.0, crate::font::semibold()),
+            Self::Body => (14.0, 20.0, crate::font::default()),
+            Self::Caption => (10.0, 14.0, crate::font::default()),
+            Self::Monotext => (14.0, 20.0, crate::font::mono()),
+        }
+    }
+}
+
+/// Text with the Title 1 typography preset.
+pub fn title1<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Title1)
+}
+
+/// Text with the Title 2 typography preset.
+pub fn title2<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Title2)
+}
+
+/// Text with the Title 3 typography preset.
+pub fn title3<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Title3)
+}
+
+/// Text with the Title 4 typography preset.
+pub fn title4<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Title4)
+}
+
+/// Text with the Heading typography preset.
+pub fn heading<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Heading)
+}
+
+/// Text with the Caption Heading typography preset.
+pub fn caption_heading<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::CaptionHeading)
+}
+
+/// Text with the Body typography preset.
+pub fn body<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Body)
+}
+
+/// Text with the Caption typography preset.
+pub fn caption<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Caption)
+}
+
+/// Text with the Monotext typography preset.
+pub fn monotext<'a>(text: impl Into<Cow<'a, str>> + 'a) -> Preset<'a> {
+    Preset::new(text, Typography::Monotext)
+}
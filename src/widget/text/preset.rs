@@ -0,0 +1,215 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Text with a typography preset, which may be made selectable.
+//!
+//! ```no_run
+//! use cosmic::widget::text;
+//!
+//! let version = text::caption("Version 1.0.0").selectable(true);
+//! ```
+
+use std::borrow::Cow;
+
+use super::{Selectable, Typography};
+use crate::{Element, Renderer, Theme};
+
+use iced::widget::Text;
+use iced_core::text::{LineHeight, Shaping, Wrapping};
+use iced_core::{alignment, Font, Length, Pixels};
+
+/// Text with a typography preset, created by helpers such as [`title1`](super::title1).
+///
+/// Displayed as a [`Text`], or as a [`Selectable`] if [`Preset::selectable`] is enabled.
+#[must_use]
+pub struct Preset<'a> {
+    content: Cow<'a, str>,
+    typography: Typography,
+    selectable: bool,
+    size: Option<Pixels>,
+    line_height: Option<LineHeight>,
+    font: Option<Font>,
+    width: Option<Length>,
+    height: Option<Length>,
+    align_x: Option<alignment::Horizontal>,
+    align_y: Option<alignment::Vertical>,
+    shaping: Option<Shaping>,
+    wrapping: Option<Wrapping>,
+    class: crate::theme::Text,
+}
+
+impl<'a> Preset<'a> {
+    /// Creates text with the size, line height, and font of a typography preset.
+    pub fn new(content: impl Into<Cow<'a, str>>, typography: Typography) -> Self {
+        Self {
+            content: content.into(),
+            typography,
+            selectable: false,
+            size: None,
+            line_height: None,
+            font: None,
+            width: None,
+            height: None,
+            align_x: None,
+            align_y: None,
+            shaping: None,
+            wrapping: None,
+            class: crate::theme::Text::default(),
+        }
+    }
+
+    /// Whether the text may be selected with the cursor, and copied to the clipboard.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Sets the size of the text, in place of the preset's.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the text, in place of the preset's.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+
+    /// Sets the font of the text, in place of the preset's.
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the text.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Sets the height of the text.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Sets the horizontal alignment of the text.
+    pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = Some(align_x.into());
+        self
+    }
+
+    /// Sets the vertical alignment of the text.
+    ///
+    /// Selectable text is always aligned to the top.
+    pub fn align_y(mut self, align_y: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = Some(align_y.into());
+        self
+    }
+
+    /// Centers the text horizontally and vertically.
+    pub fn center(self) -> Self {
+        self.align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+    }
+
+    /// Sets how the text is shaped.
+    ///
+    /// Selectable text is always shaped with [`Shaping::Advanced`].
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = Some(shaping);
+        self
+    }
+
+    /// Sets how the text is wrapped when it does not fit its width.
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = Some(wrapping);
+        self
+    }
+
+    /// Sets the style of the text.
+    pub fn class(mut self, class: impl Into<crate::theme::Text>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+impl<'a> From<Preset<'a>> for Text<'a, Theme, Renderer> {
+    fn from(preset: Preset<'a>) -> Self {
+        let (size, line_height, font) = preset.typography.preset();
+
+        let mut text = Text::new(preset.content)
+            .size(preset.size.unwrap_or(Pixels(size)))
+            .line_height(
+                preset
+                    .line_height
+                    .unwrap_or(LineHeight::Absolute(Pixels(line_height))),
+            )
+            .font(preset.font.unwrap_or(font))
+            .class(preset.class);
+
+        if let Some(width) = preset.width {
+            text = text.width(width);
+        }
+        if let Some(height) = preset.height {
+            text = text.height(height);
+        }
+        if let Some(align_x) = preset.align_x {
+            text = text.align_x(align_x);
+        }
+        if let Some(align_y) = preset.align_y {
+            text = text.align_y(align_y);
+        }
+        if let Some(shaping) = preset.shaping {
+            text = text.shaping(shaping);
+        }
+        if let Some(wrapping) = preset.wrapping {
+            text = text.wrapping(wrapping);
+        }
+
+        text
+    }
+}
+
+impl<'a> From<Preset<'a>> for Selectable<'a> {
+    fn from(preset: Preset<'a>) -> Self {
+        let mut text = Selectable::new(preset.content)
+            .typography(preset.typography)
+            .class(preset.class);
+
+        if let Some(size) = preset.size {
+            text = text.size(size);
+        }
+        if let Some(line_height) = preset.line_height {
+            text = text.line_height(line_height);
+        }
+        if let Some(font) = preset.font {
+            text = text.font(font);
+        }
+        if let Some(width) = preset.width {
+            text = text.width(width);
+        }
+        if let Some(height) = preset.height {
+            text = text.height(height);
+        }
+        if let Some(align_x) = preset.align_x {
+            text = text.align_x(align_x);
+        }
+        if let Some(wrapping) = preset.wrapping {
+            text = text.wrapping(wrapping);
+        }
+
+        text
+    }
+}
+
+impl<'a, Message: 'a> From<Preset<'a>> for Element<'a, Message> {
+    fn from(preset: Preset<'a>) -> Self {
+        if preset.selectable {
+            Selectable::from(preset).into()
+        } else {
+            Text::from(preset).into()
+        }
+    }
+}
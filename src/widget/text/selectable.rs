@@ -0,0 +1,377 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Text which may be selected with the cursor, and copied to the clipboard.
+//!
+//! ```no_run
+//! use cosmic::iced::alignment::Horizontal;
+//! use cosmic::widget::text::{self, Typography};
+//!
+//! let heading = text::selectable("About")
+//!     .typography(Typography::Title1)
+//!     .align_x(Horizontal::Center);
+//! ```
+
+use std::borrow::Cow;
+
+use super::Typography;
+use crate::{Element, Renderer, Theme};
+
+use iced_core::event::{self, Event};
+use iced_core::text::{LineHeight, Paragraph, Shaping, Text, Wrapping};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    alignment, clipboard, keyboard, layout, mouse, renderer, Border, Clipboard, Color, Font,
+    Layout, Length, Pixels, Point, Rectangle, Shadow, Shell, Size, Widget,
+};
+use iced_renderer::graphics::text::cosmic_text::{Buffer, Cursor};
+
+/// Creates text which may be selected with the cursor, and copied to the clipboard.
+pub fn selectable<'a>(content: impl Into<Cow<'a, str>>) -> Selectable<'a> {
+    Selectable::new(content)
+}
+
+/// Text which may be selected with the cursor, and copied to the clipboard.
+///
+/// The selection is highlighted with the accent color of the theme.
+#[must_use]
+pub struct Selectable<'a> {
+    content: Cow<'a, str>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    font: Font,
+    width: Length,
+    height: Length,
+    align_x: alignment::Horizontal,
+    wrapping: Wrapping,
+    class: crate::theme::Text,
+}
+
+impl<'a> Selectable<'a> {
+    /// Creates text which may be selected with the cursor, and copied to the clipboard.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            size: None,
+            line_height: LineHeight::default(),
+            font: crate::font::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            align_x: alignment::Horizontal::Left,
+            wrapping: Wrapping::default(),
+            class: crate::theme::Text::default(),
+        }
+    }
+
+    /// Applies the size, line height, and font of a typography preset.
+    pub fn typography(mut self, typography: Typography) -> Self {
+        let (size, line_height, font) = typography.preset();
+        self.size = Some(Pixels(size));
+        self.line_height = LineHeight::Absolute(Pixels(line_height));
+        self.font = font;
+        self
+    }
+
+    /// Sets the size of the text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the line height of the text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the text.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the text.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the text.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the text.
+    pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+
+    /// Sets how the text is wrapped when it does not fit its width.
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the style of the text.
+    pub fn class(mut self, class: impl Into<crate::theme::Text>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// The position of the top left corner of the paragraph within the widget.
+    fn origin(&self, bounds: Rectangle, paragraph: &crate::Paragraph) -> Point {
+        let width = paragraph.min_width();
+
+        let x = match self.align_x {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => bounds.center_x() - width / 2.0,
+            alignment::Horizontal::Right => bounds.x + bounds.width - width,
+        };
+
+        Point::new(x, bounds.y)
+    }
+}
+
+/// The local state of a [`Selectable`].
+#[derive(Default)]
+struct State {
+    paragraph: crate::Plain,
+    content: String,
+    /// The cursor where the selection began, and the cursor where it ends.
+    selection: Option<(Cursor, Cursor)>,
+    dragging: bool,
+}
+
+impl State {
+    /// The selection ordered from its start to its end, if it is not empty.
+    fn ordered_selection(&self) -> Option<(Cursor, Cursor)> {
+        let (anchor, head) = self.selection?;
+
+        match (anchor.line, anchor.index).cmp(&(head.line, head.index)) {
+            std::cmp::Ordering::Less => Some((anchor, head)),
+            std::cmp::Ordering::Greater => Some((head, anchor)),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+/// The text between two cursors of a buffer, with lines separated by newlines.
+fn selected_text(buffer: &Buffer, start: Cursor, end: Cursor) -> String {
+    let mut text = String::new();
+
+    for (nth, line) in buffer
+        .lines
+        .iter()
+        .enumerate()
+        .take(end.line + 1)
+        .skip(start.line)
+    {
+        let line = line.text();
+        let from = if nth == start.line { start.index } else { 0 };
+        let to = if nth == end.line {
+            end.index
+        } else {
+            line.len()
+        };
+
+        if nth != start.line {
+            text.push('\n');
+        }
+
+        text.push_str(line.get(from..to).unwrap_or_default());
+    }
+
+    text
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Selectable<'a> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Forget the selection if the text has changed.
+        if state.content != self.content {
+            state.content = self.content.to_string();
+            state.selection = None;
+            state.dragging = false;
+        }
+
+        let limits = limits.width(self.width).height(self.height);
+
+        state.paragraph.update(Text {
+            content: &self.content,
+            bounds: limits.max(),
+            size: self
+                .size
+                .unwrap_or_else(|| iced_core::text::Renderer::default_size(renderer)),
+            line_height: self.line_height,
+            font: self.font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: self.wrapping,
+        });
+
+        layout::Node::new(limits.resolve(self.width, self.height, state.paragraph.min_bounds()))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.selection = None;
+                    return event::Status::Ignored;
+                };
+
+                let origin = self.origin(bounds, state.paragraph.raw());
+                let hit = state
+                    .paragraph
+                    .raw()
+                    .buffer()
+                    .hit(position.x - origin.x, position.y - origin.y);
+
+                state.selection = hit.map(|hit| (hit, hit));
+                state.dragging = true;
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.dragging => {
+                let origin = self.origin(bounds, state.paragraph.raw());
+                let hit = state
+                    .paragraph
+                    .raw()
+                    .buffer()
+                    .hit(position.x - origin.x, position.y - origin.y);
+
+                if let (Some((_, head)), Some(hit)) = (state.selection.as_mut(), hit) {
+                    *head = hit;
+                }
+
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.dragging => {
+                state.dragging = false;
+                return event::Status::Captured;
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if modifiers.command() && c.as_str() == "c" => {
+                if let Some((start, end)) = state.ordered_selection() {
+                    let text = selected_text(state.paragraph.raw().buffer(), start, end);
+                    clipboard.write(clipboard::Kind::Standard, text);
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => (),
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let paragraph = state.paragraph.raw();
+        let origin = self.origin(bounds, paragraph);
+
+        if let Some((start, end)) = state.ordered_selection() {
+            let mut highlight: Color = theme.cosmic().accent_color().into();
+            highlight.a = 0.4;
+
+            for run in paragraph.buffer().layout_runs() {
+                let Some((x, width)) = run.highlight(start, end) else {
+                    continue;
+                };
+
+                iced_core::Renderer::fill_quad(
+                    renderer,
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: origin.x + x,
+                            y: origin.y + run.line_top,
+                            width,
+                            height: run.line_height,
+                        },
+                        border: Border::default(),
+                        shadow: Shadow::default(),
+                    },
+                    highlight,
+                );
+            }
+        }
+
+        let color = iced_widget::text::Catalog::style(theme, &self.class)
+            .color
+            .unwrap_or(style.text_color);
+
+        iced_core::text::Renderer::fill_paragraph(renderer, paragraph, origin, color, *viewport);
+    }
+}
+
+impl<'a, Message: 'a> From<Selectable<'a>> for Element<'a, Message> {
+    fn from(text: Selectable<'a>) -> Self {
+        Self::new(text)
+    }
+}
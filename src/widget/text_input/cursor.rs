@@ -26,6 +26,26 @@ pub enum State {
     },
 }
 
+/// A position in the value of a text input, such as the caret.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The index of the grapheme at the position.
+    pub grapheme: usize,
+    /// The offset of the position in bytes, for slicing the value.
+    pub byte: usize,
+}
+
+impl Position {
+    /// The position before the grapheme at `index` in the value.
+    #[must_use]
+    pub fn new(value: &Value, index: usize) -> Self {
+        Self {
+            grapheme: index,
+            byte: value.until(index).to_string().len(),
+        }
+    }
+}
+
 impl Default for Cursor {
     fn default() -> Self {
         Self {
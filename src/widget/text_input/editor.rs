@@ -7,11 +7,23 @@ use super::{cursor::Cursor, value::Value};
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    max_length: Option<usize>,
 }
 
 impl<'a> Editor<'a> {
     pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+        Editor {
+            value,
+            cursor,
+            max_length: None,
+        }
+    }
+
+    /// Limits the amount of graphemes which the value may contain after an edit.
+    #[must_use]
+    pub fn max_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_length = max_length;
+        self
     }
 
     #[must_use]
@@ -20,6 +32,24 @@ impl<'a> Editor<'a> {
     }
 
     pub fn insert(&mut self, character: char) {
+        if let Some(max_length) = self.max_length {
+            // Combining characters may merge with an existing grapheme, so the
+            // length can only be known after inserting into a copy of the value.
+            let mut value = self.value.clone();
+            let index = match self.cursor.selection(self.value) {
+                Some((left, right)) => {
+                    value.remove_many(left, right);
+                    left
+                }
+                None => self.cursor.end(self.value),
+            };
+            value.insert(index, character);
+
+            if value.len() > max_length {
+                return;
+            }
+        }
+
         if let Some((left, right)) = self.cursor.selection(self.value) {
             self.cursor.move_left(self.value);
             self.value.remove_many(left, right);
@@ -29,13 +59,18 @@ impl<'a> Editor<'a> {
         self.cursor.move_right(self.value);
     }
 
-    pub fn paste(&mut self, content: Value) {
-        let length = content.len();
+    pub fn paste(&mut self, mut content: Value) {
         if let Some((left, right)) = self.cursor.selection(self.value) {
             self.cursor.move_left(self.value);
             self.value.remove_many(left, right);
         }
 
+        if let Some(max_length) = self.max_length {
+            content = content.until(max_length.saturating_sub(self.value.len()));
+        }
+
+        let length = content.len();
+
         self.value.insert_many(self.cursor.end(self.value), content);
 
         self.cursor.move_right_by_amount(self.value, length);
@@ -170,6 +170,38 @@ where
         .padding(spacing)
 }
 
+/// The height of the strength meter of a [`secure_input`].
+const STRENGTH_METER_HEIGHT: f32 = 4.0;
+
+/// The strength of a password, as displayed by the strength meter of a [`secure_input`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Strength {
+    Weak,
+    Fair,
+    Strong,
+}
+
+impl Strength {
+    /// The portion of the strength meter which is filled.
+    fn fill(self) -> f32 {
+        match self {
+            Strength::Weak => 1.0 / 3.0,
+            Strength::Fair => 2.0 / 3.0,
+            Strength::Strong => 1.0,
+        }
+    }
+
+    /// The color of the filled portion of the strength meter.
+    fn color(self, theme: &crate::Theme) -> Color {
+        let cosmic = theme.cosmic();
+        Color::from(match self {
+            Strength::Weak => cosmic.destructive_color(),
+            Strength::Fair => cosmic.warning_color(),
+            Strength::Strong => cosmic.success_color(),
+        })
+    }
+}
+
 pub(crate) const SUPPORTED_TEXT_MIME_TYPES: &[&str; 6] = &[
     "text/plain;charset=utf-8",
     "text/plain;charset=UTF-8",
@@ -198,10 +230,19 @@ pub struct TextInput<'a, Message> {
     label: Option<Cow<'a, str>>,
     helper_text: Option<Cow<'a, str>>,
     error: Option<Cow<'a, str>>,
+    max_length: Option<usize>,
+    show_counter: bool,
+    strength: Option<Strength>,
+    completion: Option<Cow<'a, str>>,
+    completion_key: keyboard::key::Named,
     on_input: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
     on_toggle_edit: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    on_validate: Option<(Duration, Box<dyn Fn(String) -> Message + 'a>)>,
+    on_caret_move: Option<Box<dyn Fn(cursor::Position) -> Message + 'a>>,
+    on_selection_change:
+        Option<Box<dyn Fn(Option<std::ops::Range<cursor::Position>>) -> Message + 'a>>,
     leading_icon: Option<Element<'a, Message, crate::Theme, crate::Renderer>>,
     trailing_icon: Option<Element<'a, Message, crate::Theme, crate::Renderer>>,
     style: <crate::Theme as StyleSheet>::Style,
@@ -244,9 +285,17 @@ where
             on_paste: None,
             on_submit: None,
             on_toggle_edit: None,
+            on_validate: None,
+            on_caret_move: None,
+            on_selection_change: None,
             leading_icon: None,
             trailing_icon: None,
             error: None,
+            max_length: None,
+            show_counter: false,
+            strength: None,
+            completion: None,
+            completion_key: keyboard::key::Named::Tab,
             style: crate::theme::TextInput::default(),
             on_create_dnd_source: None,
             surface_ids: None,
@@ -267,6 +316,11 @@ where
         }
     }
 
+    /// Replaces the value displayed by the input.
+    pub(crate) fn set_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
+
     /// Sets the input to be always active.
     /// This makes it behave as if it was always focused.
     pub fn always_active(mut self) -> Self {
@@ -298,6 +352,48 @@ where
         self
     }
 
+    /// Limits the value of the [`TextInput`] to `max_length` characters.
+    ///
+    /// Characters are counted as graphemes. Input beyond the limit is rejected,
+    /// and pasted text is truncated to fit.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Displays a `length/max_length` counter below the [`TextInput`].
+    ///
+    /// Requires [`TextInput::max_length`] to be set.
+    pub fn show_counter(mut self, show_counter: bool) -> Self {
+        self.show_counter = show_counter;
+        self
+    }
+
+    /// Displays a strength meter below a [`secure_input`].
+    ///
+    /// The `strength` of the current value is evaluated once when building the widget,
+    /// and only the resulting [`Strength`] is retained.
+    pub fn strength_meter(mut self, strength: impl Fn(&str) -> Strength) -> Self {
+        self.strength = Some(strength(&self.value.to_string()));
+        self
+    }
+
+    /// Displays dimmed text after the value, which completes it when accepted.
+    ///
+    /// The completion is accepted with [`TextInput::completion_key`], or by moving the
+    /// cursor right from the end of the value, which emits the completed value to
+    /// [`TextInput::on_input`].
+    pub fn completion(mut self, completion: Option<impl Into<Cow<'a, str>>>) -> Self {
+        self.completion = completion.map(Into::into);
+        self
+    }
+
+    /// Sets the key which accepts the [`TextInput::completion`]. Defaults to `Tab`.
+    pub fn completion_key(mut self, key: keyboard::key::Named) -> Self {
+        self.completion_key = key;
+        self
+    }
+
     /// Sets the [`LineHeight`] of the [`TextInput`].
     pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
         self.line_height = line_height.into();
@@ -359,6 +455,26 @@ where
         self
     }
 
+    /// Emitted when the caret is moved, such as by typing, clicking, or the arrow keys.
+    ///
+    /// While text is selected, the caret is at the end of the selection which moves.
+    pub fn on_caret_move(
+        mut self,
+        on_caret_move: impl Fn(cursor::Position) -> Message + 'a,
+    ) -> Self {
+        self.on_caret_move = Some(Box::new(on_caret_move));
+        self
+    }
+
+    /// Emitted when the selected text changes, with `None` once nothing is selected.
+    pub fn on_selection_change(
+        mut self,
+        on_selection_change: impl Fn(Option<std::ops::Range<cursor::Position>>) -> Message + 'a,
+    ) -> Self {
+        self.on_selection_change = Some(Box::new(on_selection_change));
+        self
+    }
+
     /// Sets the message that should be produced when some text is pasted into
     /// the [`TextInput`].
     pub fn on_paste(mut self, on_paste: impl Fn(String) -> Message + 'a) -> Self {
@@ -383,6 +499,28 @@ where
         self
     }
 
+    /// Emits a message to validate the value, once it has not changed for the `debounce` duration.
+    ///
+    /// This allows validations which are too slow for every keystroke to be performed
+    /// asynchronously by the application. Use [`TextInput::validating`] while it is pending,
+    /// and [`TextInput::error`] to display its result.
+    pub fn on_validate(
+        mut self,
+        debounce: Duration,
+        on_validate: impl Fn(String) -> Message + 'a,
+    ) -> Self {
+        self.on_validate = Some((debounce, Box::new(on_validate)));
+        self
+    }
+
+    /// Displays a spinner in place of the trailing icon while a validation is pending.
+    pub fn validating(mut self, validating: bool) -> Self {
+        if validating {
+            self.trailing_icon = Some(crate::widget::spinner().into());
+        }
+        self
+    }
+
     /// Sets the end [`Icon`] of the [`TextInput`].
     pub fn trailing_icon(
         mut self,
@@ -452,9 +590,12 @@ where
             self.line_height,
             self.error.as_deref(),
             self.label.as_deref(),
-            self.helper_text.as_deref(),
+            self.helper_row(),
             self.helper_size,
             self.helper_line_height,
+            self.counter_max(),
+            self.strength,
+            self.completion.as_deref().filter(|_| !self.is_secure),
             &layout.bounds(),
             style,
         );
@@ -493,6 +634,18 @@ where
         )
     }
 
+    /// The text of the helper row, which is left empty if only the counter or strength meter is shown.
+    fn helper_row(&self) -> Option<&str> {
+        self.helper_text
+            .as_deref()
+            .or_else(|| (self.counter_max().is_some() || self.strength.is_some()).then_some(""))
+    }
+
+    /// The maximum length displayed by the counter, if it is shown.
+    fn counter_max(&self) -> Option<usize> {
+        self.max_length.filter(|_| self.show_counter)
+    }
+
     /// Get the layout node of the actual text input
     fn text_layout<'b>(&'a self, layout: Layout<'b>) -> Layout<'b> {
         if self.dnd_icon {
@@ -542,6 +695,14 @@ where
             .iter()
             .map(|l| l.text())
             .collect::<String>();
+
+        // Debounce validation of the new value.
+        if let Some((debounce, _)) = self.on_validate.as_ref() {
+            if old_value != self.value.to_string() {
+                state.validate_at = Some(Instant::now() + *debounce);
+            }
+        }
+
         if state.is_secure != self.is_secure
             || old_value != self.value.to_string()
             || state
@@ -561,7 +722,7 @@ where
                 .iter()
                 .map(|l| l.text())
                 .collect::<String>()
-                != self.helper_text.as_deref().unwrap_or_default()
+                != self.helper_row().unwrap_or_default()
         {
             state.is_secure = self.is_secure;
             state.dirty = true;
@@ -670,9 +831,10 @@ where
                 self.trailing_icon.as_ref(),
                 self.line_height,
                 self.label.as_deref(),
-                self.helper_text.as_deref(),
+                self.helper_row(),
                 self.helper_size,
                 self.helper_line_height,
+                self.strength.is_some(),
                 font,
                 tree,
             );
@@ -769,6 +931,38 @@ where
         let size = self.size.unwrap_or_else(|| renderer.default_size().0);
         let line_height = self.line_height;
 
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            if let (Some(validate_at), Some((_, on_validate))) =
+                (state.validate_at, self.on_validate.as_ref())
+            {
+                if now >= validate_at {
+                    state.validate_at = None;
+                    shell.publish((on_validate)(self.value.to_string()));
+                } else {
+                    shell.request_redraw(window::RedrawRequest::At(validate_at));
+                }
+            }
+
+            if std::mem::take(&mut state.report_cursor) {
+                let (caret, selection) = cursor_changes(
+                    &self.value,
+                    state.cursor.state(&self.value),
+                    &mut state.reported_cursor,
+                );
+
+                if let (Some(on_caret_move), Some(caret)) = (self.on_caret_move.as_ref(), caret) {
+                    shell.publish(on_caret_move(caret));
+                }
+
+                if let (Some(on_selection_change), Some(selection)) =
+                    (self.on_selection_change.as_ref(), selection)
+                {
+                    shell.publish(on_selection_change(selection));
+                }
+            }
+        }
+
         if self.is_editable {
             if let Some(ref on_edit) = self.on_toggle_edit {
                 let state = tree.state.downcast_mut::<State>();
@@ -788,7 +982,10 @@ where
                 trailing_icon_layout = Some(children.last().unwrap());
 
                 if let Some(trailing_layout) = trailing_icon_layout {
-                    if cursor_position.is_over(trailing_layout.bounds()) {
+                    // Redraws are always forwarded, so that an animated icon may advance.
+                    if cursor_position.is_over(trailing_layout.bounds())
+                        || matches!(event, Event::Window(window::Event::RedrawRequested(_)))
+                    {
                         let res = trailing_icon.as_widget_mut().on_event(
                             tree,
                             event.clone(),
@@ -807,9 +1004,10 @@ where
                 }
             }
         }
+        let is_redraw = matches!(event, Event::Window(window::Event::RedrawRequested(_)));
         let dnd_id = self.dnd_id();
         let id = Widget::id(self);
-        update(
+        let status = update(
             id,
             event,
             text_layout.children().next().unwrap(),
@@ -822,6 +1020,9 @@ where
             font,
             self.is_secure,
             self.is_editable,
+            self.max_length,
+            self.completion.as_deref().filter(|_| !self.is_secure),
+            self.completion_key,
             self.on_input.as_deref(),
             self.on_paste.as_deref(),
             &self.on_submit,
@@ -831,7 +1032,19 @@ where
             dnd_id,
             line_height,
             layout,
-        )
+        );
+
+        // The value is only updated by the next view, so changes of the cursor are reported
+        // once it has been redrawn with the value which they were made in.
+        if status == event::Status::Captured
+            && (self.on_caret_move.is_some() || self.on_selection_change.is_some())
+            && !is_redraw
+        {
+            tree.state.downcast_mut::<State>().report_cursor = true;
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
     }
 
     fn draw(
@@ -865,9 +1078,12 @@ where
             self.line_height,
             self.error.as_deref(),
             self.label.as_deref(),
-            self.helper_text.as_deref(),
+            self.helper_row(),
             self.helper_size,
             self.helper_line_height,
+            self.counter_max(),
+            self.strength,
+            self.completion.as_deref().filter(|_| !self.is_secure),
             viewport,
             style,
         );
@@ -981,6 +1197,39 @@ where
     }
 }
 
+/// The grapheme index of the caret, and the ordered range of the selection, of a cursor.
+fn caret_and_selection(state: cursor::State) -> (usize, Option<(usize, usize)>) {
+    match state {
+        cursor::State::Index(index) => (index, None),
+        cursor::State::Selection { start, end } => (end, Some((start.min(end), start.max(end)))),
+    }
+}
+
+/// The caret and selection of the cursor in the value, if they have changed since they were last
+/// reported.
+fn cursor_changes(
+    value: &Value,
+    cursor: cursor::State,
+    reported: &mut (usize, Option<(usize, usize)>),
+) -> (
+    Option<cursor::Position>,
+    Option<Option<std::ops::Range<cursor::Position>>>,
+) {
+    let (caret, selection) = caret_and_selection(cursor);
+
+    let caret_moved = (caret != reported.0).then(|| cursor::Position::new(value, caret));
+
+    let selection_changed = (selection != reported.1).then(|| {
+        selection.map(|(start, end)| {
+            cursor::Position::new(value, start)..cursor::Position::new(value, end)
+        })
+    });
+
+    *reported = (caret, selection);
+
+    (caret_moved, selection_changed)
+}
+
 /// Produces a [`Task`] that focuses the [`TextInput`] with the given [`Id`].
 pub fn focus<Message: 'static>(id: Id) -> Task<Message> {
     task::effect(Action::widget(operation::focusable::focus(id)))
@@ -1032,6 +1281,7 @@ pub fn layout<Message>(
     helper_text: Option<&str>,
     helper_text_size: f32,
     helper_text_line_height: text::LineHeight,
+    strength_meter: bool,
     font: iced_core::Font,
     tree: &mut Tree,
 ) -> layout::Node {
@@ -1174,6 +1424,14 @@ pub fn layout<Message>(
         Vector::new(0.0, y_pos)
     };
 
+    // reserve space for the strength meter above the helper text
+    let strength_meter_offset = if strength_meter {
+        STRENGTH_METER_HEIGHT + f32::from(spacing)
+    } else {
+        0.0
+    };
+    let helper_pos = helper_pos + Vector::new(0.0, strength_meter_offset);
+
     if let Some(helper_text) = helper_text {
         let limits = limits
             .width(width)
@@ -1204,7 +1462,7 @@ pub fn layout<Message>(
             size.height + node.bounds().height,
         )
     });
-    size.height += (nodes.len() - 1) as f32 * f32::from(spacing);
+    size.height += (nodes.len() - 1) as f32 * f32::from(spacing) + strength_meter_offset;
 
     let limits = limits
         .width(width)
@@ -1234,6 +1492,9 @@ pub fn update<'a, Message: 'static>(
     font: <crate::Renderer as iced_core::text::Renderer>::Font,
     is_secure: bool,
     is_editable: bool,
+    max_length: Option<usize>,
+    completion: Option<&str>,
+    completion_key: keyboard::key::Named,
     on_input: Option<&dyn Fn(String) -> Message>,
     on_paste: Option<&dyn Fn(String) -> Message>,
     on_submit: &Option<Message>,
@@ -1543,6 +1804,31 @@ where
                 focus.updated_at = Instant::now();
                 LAST_FOCUS_UPDATE.with(|x| x.set(focus.updated_at));
 
+                // Accept the completion with its key, or by moving right from the end of the value.
+                if let (Some(completion), keyboard::Key::Named(named)) = (completion, &key) {
+                    let at_end = matches!(
+                        state.cursor.state(value),
+                        cursor::State::Index(index) if index == value.len()
+                    );
+
+                    if at_end
+                        && !completion.is_empty()
+                        && (*named == completion_key
+                            || (*named == keyboard::key::Named::ArrowRight && !modifiers.shift()))
+                    {
+                        let mut editor =
+                            Editor::new(unsecured_value, &mut state.cursor).max_length(max_length);
+                        editor.paste(Value::new(completion));
+
+                        let contents = editor.contents();
+                        let value = Value::new(&contents);
+                        shell.publish((on_input)(contents));
+                        update_cache(state, &value);
+
+                        return event::Status::Captured;
+                    }
+                }
+
                 match key {
                     keyboard::Key::Named(keyboard::key::Named::Enter) => {
                         if let Some(on_submit) = on_submit.clone() {
@@ -1693,7 +1979,8 @@ where
                             Value::new(&content)
                         };
 
-                        let mut editor = Editor::new(unsecured_value, &mut state.cursor);
+                        let mut editor =
+                            Editor::new(unsecured_value, &mut state.cursor).max_length(max_length);
 
                         editor.paste(content.clone());
 
@@ -1741,7 +2028,8 @@ where
                             && !state.keyboard_modifiers.command()
                             && !modifiers.control()
                         {
-                            let mut editor = Editor::new(unsecured_value, &mut state.cursor);
+                            let mut editor = Editor::new(unsecured_value, &mut state.cursor)
+                                .max_length(max_length);
 
                             let character =
                                 text.unwrap_or_default().chars().next().unwrap_or_default();
@@ -1921,7 +2209,8 @@ where
                     return event::Status::Captured;
                 };
 
-                let mut editor = Editor::new(unsecured_value, &mut state.cursor);
+                let mut editor =
+                    Editor::new(unsecured_value, &mut state.cursor).max_length(max_length);
 
                 editor.paste(Value::new(content.as_str()));
                 let contents = editor.contents();
@@ -1978,6 +2267,9 @@ pub fn draw<'a, Message>(
     helper_text: Option<&str>,
     helper_text_size: f32,
     helper_line_height: text::LineHeight,
+    counter_max: Option<usize>,
+    strength: Option<Strength>,
+    completion: Option<&str>,
     viewport: &Rectangle,
     renderer_style: &renderer::Style,
 ) {
@@ -2291,6 +2583,33 @@ pub fn draw<'a, Message>(
             color,
             *viewport,
         );
+
+        // Display the completion after the value while the cursor is at its end.
+        if let Some(completion) = completion.filter(|_| state.is_focused.is_some()) {
+            let at_end = matches!(
+                state.cursor.state(value),
+                cursor::State::Index(index) if index == value.len()
+            );
+
+            if at_end && !text.is_empty() {
+                renderer.fill_text(
+                    Text {
+                        content: completion.to_string(),
+                        font,
+                        bounds: bounds.size(),
+                        size: iced::Pixels(size),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        line_height: text::LineHeight::default(),
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::default(),
+                    },
+                    Point::new(bounds.x + text_width, bounds.y),
+                    appearance.placeholder_color,
+                    *viewport,
+                );
+            }
+        }
     };
 
     renderer.with_layer(text_bounds, render);
@@ -2323,6 +2642,47 @@ pub fn draw<'a, Message>(
 
     // draw the helper text if it exists
     if let (Some(helper_text_layout), Some(helper_text)) = (helper_text_layout, helper_text) {
+        // draw the strength meter between the input and the helper text
+        if let Some(strength) = strength {
+            let cosmic = theme.cosmic();
+            // Layout reserves the meter's height with the same spacing on either side of it.
+            let input_bottom = bounds.y + bounds.height;
+            let gap = helper_text_layout.bounds().y - input_bottom - STRENGTH_METER_HEIGHT;
+            let track_bounds = Rectangle {
+                y: input_bottom + gap / 2.0,
+                height: STRENGTH_METER_HEIGHT,
+                ..bounds
+            };
+            let radius = cosmic.corner_radii.radius_xs;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: track_bounds,
+                    border: Border {
+                        radius: radius.into(),
+                        ..Default::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                Background::Color(cosmic.bg_divider().into()),
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: track_bounds.width * strength.fill(),
+                        ..track_bounds
+                    },
+                    border: Border {
+                        radius: radius.into(),
+                        ..Default::default()
+                    },
+                    shadow: Shadow::default(),
+                },
+                Background::Color(strength.color(theme)),
+            );
+        }
+
         renderer.fill_text(
             Text {
                 content: helper_text.to_string(), // TODO remove to_string?
@@ -2339,6 +2699,32 @@ pub fn draw<'a, Message>(
             text_color,
             *viewport,
         );
+
+        // draw the character counter at the end of the helper text row
+        if let Some(counter_max) = counter_max {
+            let counter_bounds = Rectangle {
+                y: helper_text_layout.bounds().y,
+                height: helper_text_layout.bounds().height,
+                ..bounds
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: format!("{}/{counter_max}", value.len()),
+                    size: iced::Pixels(helper_text_size),
+                    font,
+                    bounds: counter_bounds.size(),
+                    horizontal_alignment: alignment::Horizontal::Right,
+                    vertical_alignment: alignment::Vertical::Top,
+                    line_height: helper_line_height,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::default(),
+                },
+                Point::new(counter_bounds.x + counter_bounds.width, counter_bounds.y),
+                text_color,
+                *viewport,
+            );
+        }
     }
 }
 
@@ -2423,6 +2809,11 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    validate_at: Option<Instant>,
+    /// Whether the cursor may have changed since it was last reported.
+    report_cursor: bool,
+    /// The caret and selection which were last reported.
+    reported_cursor: (usize, Option<(usize, usize)>),
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -2496,6 +2887,9 @@ impl State {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            validate_at: None,
+            report_cursor: false,
+            reported_cursor: (0, None),
             dirty: false,
         }
     }
@@ -2682,3 +3076,66 @@ fn offset(text_bounds: Rectangle, value: &Value, state: &State) -> f32 {
         0.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{cursor, cursor_changes, Value};
+
+    #[test]
+    fn cursor_changes_typed_at_end() {
+        let mut reported = (3, None);
+
+        // "abc" with the caret at its end, after "d" is typed.
+        let value = Value::new("abcd");
+        let (caret, selection) = cursor_changes(&value, cursor::State::Index(4), &mut reported);
+
+        assert_eq!(
+            caret,
+            Some(cursor::Position {
+                grapheme: 4,
+                byte: 4
+            })
+        );
+        assert_eq!(selection, None);
+
+        // Unchanged cursors are not reported again.
+        let (caret, selection) = cursor_changes(&value, cursor::State::Index(4), &mut reported);
+        assert_eq!((caret, selection), (None, None));
+    }
+
+    #[test]
+    fn cursor_changes_measures_multibyte_input() {
+        let mut reported = (1, None);
+
+        // "abc" with the caret after 'a', after "é" is typed.
+        let value = Value::new("aébc");
+        let (caret, _) = cursor_changes(&value, cursor::State::Index(2), &mut reported);
+
+        assert_eq!(
+            caret,
+            Some(cursor::Position {
+                grapheme: 2,
+                byte: 3
+            })
+        );
+
+        let (_, selection) = cursor_changes(
+            &value,
+            cursor::State::Selection { start: 4, end: 1 },
+            &mut reported,
+        );
+
+        assert_eq!(
+            selection,
+            Some(Some(
+                cursor::Position {
+                    grapheme: 1,
+                    byte: 1
+                }..cursor::Position {
+                    grapheme: 4,
+                    byte: 5
+                }
+            ))
+        );
+    }
+}
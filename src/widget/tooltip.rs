@@ -0,0 +1,279 @@
+// Copyright 2024 System76 <info@system76.com>
+// SPDX-License-Identifier: MPL-2.0
+
+//! Show a tooltip beside a widget after it has been hovered for a moment.
+//!
+//! The tooltip may be any element, such as an icon beside a multi-line label.
+//!
+//! ```no_run
+//! # #[derive(Clone)]
+//! # enum Message { Save }
+//! use cosmic::widget::{button, column, text, tooltip};
+//! use std::time::Duration;
+//!
+//! let save = tooltip::delayed(
+//!     tooltip(
+//!         button::text("Save").on_press(Message::Save),
+//!         column::with_capacity(2)
+//!             .push(text::heading("Save"))
+//!             .push(text::caption("Writes the document to disk")),
+//!         tooltip::Position::Top,
+//!     ),
+//!     Duration::from_millis(500),
+//! );
+//! ```
+
+use crate::{Element, Renderer, Theme};
+use iced_core::event::{self, Event};
+use iced_core::time::{Duration, Instant};
+use iced_core::widget::{tree, Tree};
+use iced_core::{
+    layout, mouse, overlay, renderer, touch, window, Clipboard, Layout, Length, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+pub use iced::widget::tooltip::Position;
+
+pub type Tooltip<'a, Message> = iced::widget::Tooltip<'a, Message, Theme, Renderer>;
+
+/// Shows `tooltip` beside `content` while it is hovered.
+pub fn tooltip<'a, Message>(
+    content: impl Into<Element<'a, Message>>,
+    tooltip: impl Into<Element<'a, Message>>,
+    position: Position,
+) -> Tooltip<'a, Message> {
+    let xxs = crate::theme::active().cosmic().space_xxs();
+
+    Tooltip::new(content, tooltip, position)
+        .class(crate::theme::Container::Tooltip)
+        .padding(xxs)
+        .gap(1)
+}
+
+/// Shows the `tooltip` only after its content has been hovered for the `delay`.
+pub fn delayed<'a, Message>(
+    tooltip: Tooltip<'a, Message>,
+    delay: Duration,
+) -> Delayed<'a, Message> {
+    Delayed::new(tooltip, delay)
+}
+
+/// A [`Tooltip`] which appears after its content has been hovered for a delay.
+///
+/// The delay restarts whenever the cursor leaves the content.
+#[must_use]
+pub struct Delayed<'a, Message> {
+    inner: Tooltip<'a, Message>,
+    delay: Duration,
+}
+
+impl<'a, Message> Delayed<'a, Message> {
+    /// Shows the `tooltip` only after its content has been hovered for the `delay`.
+    pub fn new(tooltip: Tooltip<'a, Message>, delay: Duration) -> Self {
+        Self {
+            inner: tooltip,
+            delay,
+        }
+    }
+}
+
+/// When the cursor began hovering the content, and whether the tooltip is shown.
+#[derive(Default)]
+struct State {
+    hovered_at: Option<Instant>,
+    visible: bool,
+}
+
+impl<'a, Message> Widget<Message, Theme, Renderer> for Delayed<'a, Message> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.inner as &dyn Widget<Message, Theme, Renderer>,
+        )]
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        tree.children[0].diff(&mut self.inner as &mut dyn Widget<Message, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::<Message, Theme, Renderer>::size(&self.inner)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::<Message, Theme, Renderer>::layout(
+            &self.inner,
+            &mut tree.children[0],
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn iced_core::widget::Operation<()>,
+    ) {
+        Widget::<Message, Theme, Renderer>::operate(
+            &self.inner,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match &event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if !cursor.is_over(layout.bounds()) {
+                    *state = State::default();
+                } else if state.hovered_at.is_none() {
+                    let now = Instant::now();
+                    state.hovered_at = Some(now);
+                    state.visible = self.delay.is_zero();
+
+                    if !state.visible {
+                        shell.request_redraw(window::RedrawRequest::At(now + self.delay));
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorLeft) => {
+                *state = State::default();
+            }
+
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let (Some(hovered_at), false) = (state.hovered_at, state.visible) {
+                    let show_at = hovered_at + self.delay;
+
+                    if *now >= show_at {
+                        state.visible = true;
+                        shell.invalidate_layout();
+                    } else {
+                        shell.request_redraw(window::RedrawRequest::At(show_at));
+                    }
+                }
+            }
+
+            _ => (),
+        }
+
+        Widget::<Message, Theme, Renderer>::on_event(
+            &mut self.inner,
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::<Message, Theme, Renderer>::mouse_interaction(
+            &self.inner,
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        Widget::<Message, Theme, Renderer>::draw(
+            &self.inner,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Hide the tooltip until the content has been hovered for the delay.
+        if !self.delay.is_zero() && !tree.state.downcast_ref::<State>().visible {
+            return None;
+        }
+
+        Widget::<Message, Theme, Renderer>::overlay(
+            &mut self.inner,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation,
+        )
+    }
+
+    #[cfg(feature = "a11y")]
+    /// get the a11y nodes for the widget
+    fn a11y_nodes(
+        &self,
+        layout: Layout<'_>,
+        state: &Tree,
+        p: mouse::Cursor,
+    ) -> iced_accessibility::A11yTree {
+        Widget::<Message, Theme, Renderer>::a11y_nodes(&self.inner, layout, &state.children[0], p)
+    }
+}
+
+impl<'a, Message: 'a> From<Delayed<'a, Message>> for Element<'a, Message> {
+    fn from(tooltip: Delayed<'a, Message>) -> Self {
+        Element::new(tooltip)
+    }
+}
@@ -13,12 +13,40 @@ pub fn warning<'a, Message>(message: impl Into<Cow<'a, str>>) -> Warning<'a, Mes
     Warning {
         message: message.into(),
         on_close: None,
+        severity: Severity::Warning,
+    }
+}
+
+/// The severity of an inline message, which determines its color and icon.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Neutral information, displayed with the accent color.
+    Info,
+    /// A completed action, displayed with the success color.
+    Success,
+    /// A potential problem, displayed with the warning color.
+    #[default]
+    Warning,
+    /// A failed action, displayed with the destructive color.
+    Error,
+}
+
+impl Severity {
+    /// The name of the icon displayed beside messages of this severity.
+    pub fn icon_name(self) -> &'static str {
+        match self {
+            Self::Info => "dialog-information-symbolic",
+            Self::Success => "emblem-ok-symbolic",
+            Self::Warning => "dialog-warning-symbolic",
+            Self::Error => "dialog-error-symbolic",
+        }
     }
 }
 
 pub struct Warning<'a, Message> {
     message: Cow<'a, str>,
     on_close: Option<Message>,
+    severity: Severity,
 }
 
 impl<'a, Message: 'static + Clone> Warning<'a, Message> {
@@ -29,8 +57,17 @@ impl<'a, Message: 'static + Clone> Warning<'a, Message> {
         self
     }
 
+    /// Sets the severity of the message, which determines its color and icon.
+    #[must_use]
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     /// A custom button that has the desired default spacing and padding.
     pub fn into_widget(self) -> widget::Container<'a, Message, crate::Theme, Renderer> {
+        let severity = self.severity;
+        let severity_icon = icon::from_name(severity.icon_name()).size(16).icon();
         let label = widget::container(crate::widget::text(self.message)).width(Length::Fill);
 
         let close_button = icon::from_name("window-close-symbolic")
@@ -38,12 +75,16 @@ impl<'a, Message: 'static + Clone> Warning<'a, Message> {
             .apply(widget::button::icon)
             .on_press_maybe(self.on_close);
 
-        widget::row::with_capacity(2)
+        widget::row::with_capacity(3)
+            .push(severity_icon)
             .push(label)
             .push(close_button)
+            .spacing(8)
             .align_y(Alignment::Center)
             .apply(widget::container)
-            .class(theme::Container::custom(warning_container))
+            .class(theme::Container::custom(move |theme| {
+                severity_container(theme, severity)
+            }))
             .padding(10)
             .align_y(Alignment::Center)
             .width(Length::Fill)
@@ -58,11 +99,24 @@ impl<'a, Message: 'static + Clone> From<Warning<'a, Message>> for Element<'a, Me
 
 #[must_use]
 pub fn warning_container(theme: &Theme) -> widget::container::Style {
+    severity_container(theme, Severity::Warning)
+}
+
+/// The style of a container for messages of the given severity.
+#[must_use]
+pub fn severity_container(theme: &Theme, severity: Severity) -> widget::container::Style {
     let cosmic = theme.cosmic();
+    let component = match severity {
+        Severity::Info => &cosmic.accent,
+        Severity::Success => &cosmic.success,
+        Severity::Warning => &cosmic.warning,
+        Severity::Error => &cosmic.destructive,
+    };
+
     widget::container::Style {
-        icon_color: Some(theme.cosmic().warning.on.into()),
-        text_color: Some(theme.cosmic().warning.on.into()),
-        background: Some(Background::Color(theme.cosmic().warning_color().into())),
+        icon_color: Some(component.on.into()),
+        text_color: Some(component.on.into()),
+        background: Some(Background::Color(component.base.into())),
         border: Border {
             color: Color::TRANSPARENT,
             width: 1.0,